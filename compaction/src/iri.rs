@@ -13,7 +13,7 @@ pub struct IriConfusedWithPrefix;
 /// Compact the given term without considering any value.
 ///
 /// Calls [`compact_iri_full`] with `None` for `value`.
-pub(crate) fn compact_iri<N>(
+pub fn compact_iri<N>(
 	vocabulary: &N,
 	active_context: &Context<N::Iri, N::BlankId>,
 	var: &Term<N::Iri, N::BlankId>,
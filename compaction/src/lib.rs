@@ -26,6 +26,7 @@ mod value;
 
 pub use document::*;
 pub(crate) use iri::*;
+pub use iri::{compact_iri, IriConfusedWithPrefix};
 use node::*;
 use property::*;
 use value::*;
@@ -3,6 +3,7 @@ use crate::{Error, Options, ProcessingStack, Warning, WarningHandler};
 use contextual::WithContext;
 use iref::{Iri, IriRef};
 use json_ld_core::{
+	context::Resolution,
 	future::{BoxFuture, FutureExt},
 	warning, Context, Id, Loader, Term,
 };
@@ -226,94 +227,15 @@ where
 		Nullable::Null => Term::Null,
 		Nullable::Some(ExpandableRef::Keyword(k)) => Term::Keyword(k),
 		Nullable::Some(ExpandableRef::String(value)) => {
-			if is_keyword_like(value) {
-				return Term::Null;
-			}
-
-			if let Some(term_definition) = active_context.get(value) {
-				// If active context has a term definition for value, and the associated IRI mapping
-				// is a keyword, return that keyword.
-				if let Some(value) = term_definition.value() {
-					if value.is_keyword() {
-						return value.clone();
-					}
-				}
-
-				// If vocab is true and the active context has a term definition for value, return the
-				// associated IRI mapping.
-				if vocab {
-					return match term_definition.value() {
-						Some(value) => value.clone(),
-						None => Term::Null,
-					};
-				}
-			}
-
-			if value.find(':').map(|i| i > 0).unwrap_or(false) {
-				if let Ok(blank_id) = BlankId::new(value) {
-					return Term::Id(Id::blank(env.vocabulary.insert_blank_id(blank_id)));
-				}
-
-				if value == "_:" {
-					return Term::Id(Id::Invalid("_:".to_string()));
-				}
-
-				if let Ok(compact_iri) = CompactIri::new(value) {
-					// If active context contains a term definition for prefix having a non-null IRI
-					// mapping and the prefix flag of the term definition is true, return the result
-					// of concatenating the IRI mapping associated with prefix and suffix.
-					let prefix_key = Key::from(compact_iri.prefix().to_string());
-					if let Some(term_definition) = active_context.get_normal(&prefix_key) {
-						if term_definition.prefix {
-							if let Some(mapping) = &term_definition.value {
-								let mut result =
-									mapping.with(&*env.vocabulary).as_str().to_string();
-								result.push_str(compact_iri.suffix());
-
-								return Term::Id(Id::from_string_in(env.vocabulary, result));
-							}
-						}
-					}
-				}
-
-				if let Ok(iri) = Iri::new(value) {
-					return Term::Id(Id::iri(env.vocabulary.insert(iri)));
-				}
-			}
+			let resolved =
+				active_context.resolve_full(env.vocabulary, value, document_relative, vocab);
 
-			// If vocab is true, and active context has a vocabulary mapping, return the result of
-			// concatenating the vocabulary mapping with value.
-			if vocab {
-				match active_context.vocabulary() {
-					Some(Term::Id(mapping)) => {
-						let mut result = mapping.with(&*env.vocabulary).as_str().to_string();
-						result.push_str(value);
-
-						return Term::Id(Id::from_string_in(env.vocabulary, result));
-					}
-					Some(_) => return invalid_iri_simple(env, value.to_string()),
-					None => (),
+			match resolved.value {
+				Term::Id(Id::Invalid(value)) if resolved.resolution == Resolution::Unresolved => {
+					invalid_iri_simple(env, value)
 				}
+				value => value,
 			}
-
-			// Otherwise, if document relative is true set value to the result of resolving value
-			// against the base IRI from active context. Only the basic algorithm in section 5.2 of
-			// [RFC3986] is used; neither Syntax-Based Normalization nor Scheme-Based Normalization
-			// are performed. Characters additionally allowed in IRI references are treated in the
-			// same way that unreserved characters are treated in URI references, per section 6.5 of
-			// [RFC3987].
-			if document_relative {
-				if let Ok(iri_ref) = IriRef::new(value) {
-					if let Some(iri) =
-						super::resolve_iri(env.vocabulary, iri_ref, active_context.base_iri())
-					{
-						return Term::from(iri);
-					}
-				}
-			}
-
-			// Return value as is.
-			invalid_iri_simple(env, value.to_string())
 		}
 	}
 }
@@ -4,7 +4,7 @@ use crate::{
 use iref::IriRef;
 use json_ld_core::{
 	future::{BoxFuture, FutureExt},
-	Context, Environment, ExtractContext, Loader, ProcessingMode, Term,
+	Context, Environment, Loader, ProcessingMode, Term,
 };
 use json_ld_syntax::{self as syntax, Nullable};
 use rdf_types::{IriVocabularyMut, VocabularyMut};
@@ -143,7 +143,10 @@ where
 						// Initialize `result` as a newly-initialized active context, setting both
 						// `base_iri` and `original_base_url` to the value of `original_base_url` in
 						// active context, ...
-						result = Context::new(active_context.original_base_url().cloned());
+						result = Context::new(active_context.original_base_url().cloned())
+							.with_processed_context_cache(
+								previous_result.processed_context_cache().cloned(),
+							);
 
 						// ... and, if `propagate` is `false`, `previous_context` in `result` to the
 						// previous value of `result`.
@@ -182,15 +185,28 @@ where
 					// If the document has no top-level map with an @context entry, an invalid remote
 					// context has been detected and processing is aborted.
 					// Set loaded context to the value of that entry.
+					let cache = result.processed_context_cache().cloned();
+					let loaded_contexts = cache.as_ref().map(|_| remote_contexts.to_vec());
+
 					if remote_contexts.push(context_iri.clone()) {
+						// Reuse the result of a previous processing of this context, if any.
+						if let (Some(cache), Some(loaded_contexts)) = (&cache, &loaded_contexts) {
+							if let Some(cached) = cache.get(
+								&result,
+								&context_iri,
+								loaded_contexts,
+								options.processing_mode,
+							) {
+								result = cached;
+								continue;
+							}
+						}
+
 						let loaded_context = env
 							.loader
-							.load_with(env.vocabulary, context_iri.clone())
-							.await
-							.map_err(Error::ContextLoadingFailed)?
-							.into_document()
-							.into_ld_context()
-							.map_err(Error::ContextExtractionFailed)?;
+							.load_context_with(env.vocabulary, context_iri.clone())
+							.await?
+							.into_document();
 
 						// Set result to the result of recursively calling this algorithm, passing result
 						// for active context, loaded context for local context, the documentUrl of context
@@ -210,12 +226,23 @@ where
 							&result,
 							&loaded_context,
 							remote_contexts.clone(),
-							Some(context_iri),
+							Some(context_iri.clone()),
 							new_options,
 						)
-						.await?;
+						.await?
+						.into_processed();
+
+						if let (Some(cache), Some(loaded_contexts)) = (cache, loaded_contexts) {
+							cache.insert(
+								result,
+								context_iri,
+								loaded_contexts,
+								options.processing_mode,
+								r.clone(),
+							);
+						}
 
-						result = r.into_processed();
+						result = r;
 					}
 				}
 
@@ -251,12 +278,9 @@ where
 							// 5.6.4) Dereference import.
 							let import_context = env
 								.loader
-								.load_with(env.vocabulary, import)
-								.await
-								.map_err(Error::ContextLoadingFailed)?
-								.into_document()
-								.into_ld_context()
-								.map_err(Error::ContextExtractionFailed)?;
+								.load_context_with(env.vocabulary, import)
+								.await?
+								.into_document();
 
 							// If the dereferenced document has no top-level map with an @context
 							// entry, or if the value of @context is not a context definition
@@ -0,0 +1,354 @@
+//! Context compatibility checker.
+use crate::{Error, Options, Process};
+use json_ld_core::{
+	context::{BindingRef, Key, NormalTermDefinition},
+	Context, Loader,
+};
+use json_ld_syntax as syntax;
+use rdf_types::VocabularyMut;
+use std::collections::BTreeMap;
+
+/// Term definition field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Field {
+	/// IRI mapping.
+	Iri,
+
+	/// Reverse property flag.
+	Reverse,
+
+	/// Prefix flag.
+	Prefix,
+
+	/// Protected flag.
+	Protected,
+
+	/// Type mapping.
+	Type,
+
+	/// Container mapping.
+	Container,
+
+	/// Language mapping.
+	Language,
+
+	/// Direction mapping.
+	Direction,
+
+	/// Scoped context.
+	///
+	/// Scoped contexts are compared syntactically: a remote scoped context
+	/// changed behind the same URL is not detected.
+	Context,
+
+	/// Base URL of the scoped context.
+	BaseUrl,
+
+	/// Index mapping.
+	Index,
+
+	/// Nest value.
+	Nest,
+}
+
+impl Field {
+	/// Checks if a change of this field may change the expansion of documents
+	/// using the term.
+	///
+	/// The protected flag and nest value only matter to context processing
+	/// and compaction.
+	pub fn changes_expansion(&self) -> bool {
+		!matches!(self, Self::Protected | Self::Nest)
+	}
+}
+
+/// Context-wide setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Setting {
+	/// Base IRI (`@base`).
+	Base,
+
+	/// Vocabulary mapping (`@vocab`).
+	Vocabulary,
+
+	/// Default language (`@language`).
+	Language,
+
+	/// Default base direction (`@direction`).
+	Direction,
+
+	/// `@type` term definition.
+	Type,
+}
+
+/// Changed term definition.
+#[derive(Debug)]
+pub struct TermChange<T, B> {
+	/// Term.
+	pub term: Key,
+
+	/// Previous definition.
+	pub old: NormalTermDefinition<T, B>,
+
+	/// New definition.
+	pub new: NormalTermDefinition<T, B>,
+
+	/// Changed fields.
+	pub fields: Vec<Field>,
+}
+
+impl<T: PartialEq, B: PartialEq> TermChange<T, B> {
+	/// Checks if processing the new definition after the previous one would
+	/// raise a `ProtectedTermRedefinition` error.
+	pub fn is_protected_redefinition(&self) -> bool {
+		self.old.protected && self.old.modulo_protected_field() != self.new.modulo_protected_field()
+	}
+
+	/// Checks if the change may change the expansion of documents using the
+	/// term.
+	pub fn changes_expansion(&self) -> bool {
+		self.fields.iter().any(Field::changes_expansion)
+	}
+}
+
+/// Differences between two versions of a context.
+///
+/// Remote contexts are only compared through the term definitions they
+/// produce. Scoped contexts are not processed, so a remote scoped context
+/// changed behind the same URL goes unnoticed.
+#[derive(Debug)]
+pub struct ContextDiff<T, B> {
+	/// Terms only defined by the new version.
+	pub added: Vec<(Key, NormalTermDefinition<T, B>)>,
+
+	/// Terms only defined by the previous version.
+	pub removed: Vec<(Key, NormalTermDefinition<T, B>)>,
+
+	/// Terms defined differently by both versions.
+	pub changed: Vec<TermChange<T, B>>,
+
+	/// Changed context-wide settings.
+	pub settings: Vec<Setting>,
+}
+
+impl<T, B> ContextDiff<T, B> {
+	/// Compares two processed contexts.
+	///
+	/// Terms are listed in lexicographic order.
+	pub fn new(old: &Context<T, B>, new: &Context<T, B>) -> Self
+	where
+		T: Clone + PartialEq,
+		B: Clone + PartialEq,
+	{
+		let old_terms = normal_definitions(old);
+		let mut new_terms = normal_definitions(new);
+
+		let mut removed = Vec::new();
+		let mut changed = Vec::new();
+		for (term, old_definition) in old_terms {
+			match new_terms.remove(term) {
+				Some(new_definition) => {
+					let fields = changed_fields(old_definition, new_definition);
+					if !fields.is_empty() {
+						changed.push(TermChange {
+							term: term.clone(),
+							old: old_definition.clone(),
+							new: new_definition.clone(),
+							fields,
+						})
+					}
+				}
+				None => removed.push((term.clone(), old_definition.clone())),
+			}
+		}
+
+		let added = new_terms
+			.into_iter()
+			.map(|(term, definition)| (term.clone(), definition.clone()))
+			.collect();
+
+		let mut settings = Vec::new();
+		if old.base_iri() != new.base_iri() {
+			settings.push(Setting::Base)
+		}
+
+		if old.vocabulary() != new.vocabulary() {
+			settings.push(Setting::Vocabulary)
+		}
+
+		if old.default_language() != new.default_language() {
+			settings.push(Setting::Language)
+		}
+
+		if old.default_base_direction() != new.default_base_direction() {
+			settings.push(Setting::Direction)
+		}
+
+		if old.get_type() != new.get_type() {
+			settings.push(Setting::Type)
+		}
+
+		Self {
+			added,
+			removed,
+			changed,
+			settings,
+		}
+	}
+
+	/// Checks if both versions are identical.
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty()
+			&& self.removed.is_empty()
+			&& self.changed.is_empty()
+			&& self.settings.is_empty()
+	}
+
+	/// Returns the changed terms that would raise a
+	/// `ProtectedTermRedefinition` error.
+	pub fn protected_redefinitions(&self) -> impl Iterator<Item = &TermChange<T, B>>
+	where
+		T: PartialEq,
+		B: PartialEq,
+	{
+		self.changed
+			.iter()
+			.filter(|change| change.is_protected_redefinition())
+	}
+
+	/// Checks if the new version may break consumers of the previous one.
+	///
+	/// This is the case if a term is removed, if a protected term is
+	/// redefined, or if the expansion of documents may change. Added terms
+	/// are not considered breaking.
+	pub fn is_breaking(&self) -> bool
+	where
+		T: PartialEq,
+		B: PartialEq,
+	{
+		!self.removed.is_empty()
+			|| !self.settings.is_empty()
+			|| self
+				.changed
+				.iter()
+				.any(|change| change.is_protected_redefinition() || change.changes_expansion())
+	}
+}
+
+/// Processes two versions of a context and compares them.
+///
+/// Both versions are processed against an empty active context, using the
+/// given base URL and options.
+pub async fn compare<N, L>(
+	vocabulary: &mut N,
+	loader: &mut L,
+	old: &syntax::context::Context,
+	new: &syntax::context::Context,
+	base_url: Option<N::Iri>,
+	options: Options,
+) -> Result<ContextDiff<N::Iri, N::BlankId>, Error<L::Error>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone + PartialEq,
+	N::BlankId: Clone + PartialEq,
+	L: Loader<N::Iri>,
+	//
+	N: Send + Sync,
+	N::Iri: Send + Sync,
+	N::BlankId: Send + Sync,
+	L: Send + Sync,
+	L::Error: Send,
+{
+	let active_context = Context::default();
+
+	let old = old
+		.process_full(
+			vocabulary,
+			&active_context,
+			loader,
+			base_url.clone(),
+			options,
+			(),
+		)
+		.await?
+		.into_processed();
+
+	let new = new
+		.process_full(vocabulary, &active_context, loader, base_url, options, ())
+		.await?
+		.into_processed();
+
+	Ok(ContextDiff::new(&old, &new))
+}
+
+/// Returns the normal term definitions of the given context, ordered by term.
+fn normal_definitions<T, B>(
+	context: &Context<T, B>,
+) -> BTreeMap<&Key, &NormalTermDefinition<T, B>> {
+	context
+		.definitions()
+		.iter()
+		.filter_map(|binding| match binding {
+			BindingRef::Normal(term, definition) => Some((term, definition)),
+			BindingRef::Type(_) => None,
+		})
+		.collect()
+}
+
+/// Returns the fields that differ between two term definitions.
+fn changed_fields<T: PartialEq, B: PartialEq>(
+	old: &NormalTermDefinition<T, B>,
+	new: &NormalTermDefinition<T, B>,
+) -> Vec<Field> {
+	let mut fields = Vec::new();
+
+	if old.value != new.value {
+		fields.push(Field::Iri)
+	}
+
+	if old.reverse_property != new.reverse_property {
+		fields.push(Field::Reverse)
+	}
+
+	if old.prefix != new.prefix {
+		fields.push(Field::Prefix)
+	}
+
+	if old.protected != new.protected {
+		fields.push(Field::Protected)
+	}
+
+	if old.typ != new.typ {
+		fields.push(Field::Type)
+	}
+
+	if old.container != new.container {
+		fields.push(Field::Container)
+	}
+
+	if old.language != new.language {
+		fields.push(Field::Language)
+	}
+
+	if old.direction != new.direction {
+		fields.push(Field::Direction)
+	}
+
+	if old.context != new.context {
+		fields.push(Field::Context)
+	}
+
+	if old.base_url != new.base_url {
+		fields.push(Field::BaseUrl)
+	}
+
+	if old.index != new.index {
+		fields.push(Field::Index)
+	}
+
+	if old.nest != new.nest {
+		fields.push(Field::Nest)
+	}
+
+	fields
+}
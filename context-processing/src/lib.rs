@@ -3,12 +3,13 @@ pub use json_ld_core::{
 	future::{BoxFuture, FutureExt},
 	warning, Context, ProcessingMode,
 };
-use json_ld_core::{ExtractContextError, Loader};
+use json_ld_core::{ContextLoadError, ExtractContextError, Loader};
 use json_ld_syntax::ErrorCode;
 use rdf_types::VocabularyMut;
 use std::fmt;
 
 pub mod algorithm;
+pub mod compatibility;
 mod processed;
 mod stack;
 
@@ -137,6 +138,15 @@ impl<E> Error<E> {
 	}
 }
 
+impl<E> From<ContextLoadError<E>> for Error<E> {
+	fn from(e: ContextLoadError<E>) -> Self {
+		match e {
+			ContextLoadError::LoadingDocumentFailed(e) => Self::ContextLoadingFailed(e),
+			ContextLoadError::ContextExtractionFailed(e) => Self::ContextExtractionFailed(e),
+		}
+	}
+}
+
 /// Result of context processing functions.
 pub type ProcessingResult<'a, T, B, E> = Result<Processed<'a, T, B>, Error<E>>;
 
@@ -63,6 +63,21 @@ impl<I> ProcessingStack<I> {
 		}
 	}
 
+	/// Returns the URLs in the stack, from the most recently loaded.
+	pub fn to_vec(&self) -> Vec<I>
+	where
+		I: Clone,
+	{
+		let mut result = Vec::new();
+		let mut node = self.head.as_deref();
+		while let Some(n) = node {
+			result.push(n.url.clone());
+			node = n.previous.as_deref();
+		}
+
+		result
+	}
+
 	/// Push a new URL to the stack, unless it is already in the stack.
 	///
 	/// Returns `true` if the URL was successfully added or
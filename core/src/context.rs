@@ -1,12 +1,15 @@
 //! Context processing algorithm and related types.
+mod cache;
 mod definition;
+mod inline;
 pub mod inverse;
+mod resolve;
 
-use crate::{Direction, LenientLanguageTag, LenientLanguageTagBuf, Term};
+use crate::{ContextLoadError, Direction, LenientLanguageTag, LenientLanguageTagBuf, Loader, Term};
 use contextual::WithContext;
 use json_ld_syntax::{KeywordType, Nullable};
 use once_cell::sync::OnceCell;
-use rdf_types::Vocabulary;
+use rdf_types::{Vocabulary, VocabularyMut};
 use std::borrow::Borrow;
 use std::hash::Hash;
 
@@ -15,8 +18,10 @@ pub use json_ld_syntax::context::{
 	term_definition::Nest,
 };
 
+pub use cache::*;
 pub use definition::*;
 pub use inverse::InverseContext;
+pub use resolve::*;
 
 /// JSON-LD context.
 pub struct Context<T, B> {
@@ -28,6 +33,7 @@ pub struct Context<T, B> {
 	previous_context: Option<Box<Self>>,
 	definitions: Definitions<T, B>,
 	inverse: OnceCell<InverseContext<T, B>>,
+	processed_context_cache: Option<ProcessedContextCache<T, B>>,
 }
 
 impl<T, B> Default for Context<T, B> {
@@ -41,6 +47,7 @@ impl<T, B> Default for Context<T, B> {
 			previous_context: None,
 			definitions: Definitions::default(),
 			inverse: OnceCell::default(),
+			processed_context_cache: None,
 		}
 	}
 }
@@ -62,6 +69,7 @@ impl<T, B> Context<T, B> {
 			previous_context: None,
 			definitions: Definitions::default(),
 			inverse: OnceCell::default(),
+			processed_context_cache: None,
 		}
 	}
 
@@ -215,6 +223,114 @@ impl<T, B> Context<T, B> {
 		self.previous_context = Some(Box::new(previous))
 	}
 
+	/// Returns the processed remote context cache attached to this context,
+	/// if any.
+	pub fn processed_context_cache(&self) -> Option<&ProcessedContextCache<T, B>> {
+		self.processed_context_cache.as_ref()
+	}
+
+	/// Attaches a processed remote context cache to this context.
+	///
+	/// The cache is shared with every context derived from this one.
+	pub fn set_processed_context_cache(&mut self, cache: Option<ProcessedContextCache<T, B>>) {
+		self.processed_context_cache = cache
+	}
+
+	/// Attaches a processed remote context cache to this context.
+	pub fn with_processed_context_cache(
+		mut self,
+		cache: Option<ProcessedContextCache<T, B>>,
+	) -> Self {
+		self.set_processed_context_cache(cache);
+		self
+	}
+
+	/// Converts this context into an equivalent, self-contained syntactic
+	/// context.
+	///
+	/// Remote and imported contexts are merged into the term definitions when
+	/// a context is processed. The remote contexts referenced or imported by
+	/// scoped contexts are loaded with `loader` and inlined, relative
+	/// references being resolved against the base URL of their term
+	/// definition. The result can then be processed without loading any
+	/// remote context. `@base` is only emitted if it differs from the
+	/// original base URL.
+	///
+	/// A scoped context referencing a remote context that contains it keeps
+	/// the reference, resolved, since inlining it would never end.
+	pub async fn to_syntax_context<N, L>(
+		&self,
+		vocabulary: &mut N,
+		loader: &mut L,
+	) -> Result<json_ld_syntax::context::Context, ContextLoadError<L::Error>>
+	where
+		N: VocabularyMut<Iri = T, BlankId = B>,
+		T: Clone + PartialEq,
+		B: Clone,
+		L: Loader<T>,
+		//
+		N: Send + Sync,
+		T: Send,
+		L: Send,
+	{
+		let mut definitions = Definitions::default();
+		for binding in &self.definitions {
+			match binding {
+				BindingRef::Normal(key, definition) => {
+					let mut definition = definition.clone();
+					if let Some(context) = definition.context.take() {
+						let base_url = definition
+							.base_url
+							.as_ref()
+							.map(|url| vocabulary.iri(url).unwrap().to_owned());
+						let context = inline::inline_context(
+							vocabulary,
+							loader,
+							*context,
+							base_url,
+							&mut inline::Stack::default(),
+						)
+						.await?;
+						definition.context = Some(Box::new(context))
+					}
+
+					definitions.insert_normal(key.clone(), definition);
+				}
+				BindingRef::Type(definition) => {
+					definitions.insert_type(definition.clone());
+				}
+			}
+		}
+
+		let mut definition = Self {
+			original_base_url: None,
+			base_iri: None,
+			vocabulary: self.vocabulary.clone(),
+			default_language: self.default_language.clone(),
+			default_base_direction: self.default_base_direction,
+			previous_context: None,
+			definitions,
+			inverse: OnceCell::default(),
+			processed_context_cache: None,
+		}
+		.into_syntax_definition(&*vocabulary);
+
+		if self.base_iri != self.original_base_url {
+			definition.base = Some(match &self.base_iri {
+				Some(iri) => Nullable::Some(vocabulary.iri(iri).unwrap().to_owned().into()),
+				None => Nullable::Null,
+			})
+		}
+
+		if self.previous_context.is_some() {
+			definition.propagate = Some(false)
+		}
+
+		Ok(json_ld_syntax::context::Context::One(
+			json_ld_syntax::ContextEntry::Definition(definition),
+		))
+	}
+
 	/// Converts this context into its syntactic definition.
 	pub fn into_syntax_definition(
 		self,
@@ -285,6 +401,7 @@ impl<T: Clone, B: Clone> Clone for Context<T, B> {
 			previous_context: self.previous_context.clone(),
 			definitions: self.definitions.clone(),
 			inverse: OnceCell::default(),
+			processed_context_cache: self.processed_context_cache.clone(),
 		}
 	}
 }
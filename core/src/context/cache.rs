@@ -0,0 +1,342 @@
+//! Processed remote context cache.
+use super::{BindingRef, Context};
+use crate::ProcessingMode;
+use hashlink::LinkedHashMap;
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Cache statistics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessedContextCacheStats {
+	/// Number of remote contexts served from the cache.
+	pub hits: u64,
+
+	/// Number of remote contexts that had to be processed.
+	pub misses: u64,
+
+	/// Number of processed contexts removed from the cache because it was
+	/// full.
+	pub evictions: u64,
+}
+
+/// Cache entry.
+struct Entry<T, B> {
+	/// Active context the remote context has been processed against.
+	active_context: Context<T, B>,
+
+	/// Remote context URL.
+	url: T,
+
+	/// Remote contexts already loaded when the remote context was processed.
+	remote_contexts: Vec<T>,
+
+	/// Processing mode.
+	processing_mode: ProcessingMode,
+
+	/// Processing result.
+	processed: Context<T, B>,
+}
+
+impl<T: PartialEq, B: PartialEq> Entry<T, B> {
+	fn matches(
+		&self,
+		active_context: &Context<T, B>,
+		url: &T,
+		remote_contexts: &[T],
+		processing_mode: ProcessingMode,
+	) -> bool {
+		self.url == *url
+			&& self.processing_mode == processing_mode
+			&& self.remote_contexts == remote_contexts
+			&& same_context(&self.active_context, active_context)
+	}
+}
+
+struct State<T, B> {
+	/// Entries, by key fingerprint, from the least recently used.
+	entries: LinkedHashMap<u64, Entry<T, B>>,
+	stats: ProcessedContextCacheStats,
+}
+
+/// Cache key function.
+type KeyFn<T, B> = fn(&Context<T, B>, &T, &[T], ProcessingMode) -> u64;
+
+struct Inner<T, B> {
+	state: Mutex<State<T, B>>,
+	capacity: usize,
+	key: KeyFn<T, B>,
+}
+
+/// Processed remote context cache.
+///
+/// Memoizes the result of processing a remote context (given by its URL)
+/// against an active context, so that documents referencing the same remote
+/// contexts do not run the term definitions again. The base URL and options
+/// of the processed remote context are entirely determined by its URL and
+/// the processing mode.
+///
+/// Entries are found by a fingerprint of the active context, and the cache
+/// holds at most [`Self::capacity`] entries, evicting the least recently
+/// used one first. Remote contexts are identified by URL only: a context
+/// changed behind the same URL is not processed again until its entry is
+/// evicted or the cache is cleared.
+///
+/// The cache is attached to an active context with
+/// [`Context::with_processed_context_cache`], and shared with every context
+/// derived from it. Cloning the cache is cheap, and clones share the same
+/// entries, so the same cache can be used from multiple threads.
+///
+/// Warnings raised while processing a context are not reported again when
+/// the context is served from the cache.
+pub struct ProcessedContextCache<T, B>(Arc<Inner<T, B>>);
+
+impl<T: Hash, B: Hash> ProcessedContextCache<T, B> {
+	/// Default capacity.
+	pub const DEFAULT_CAPACITY: usize = 256;
+
+	/// Creates a new empty cache with the default capacity.
+	pub fn new() -> Self {
+		Self::with_capacity(Self::DEFAULT_CAPACITY)
+	}
+
+	/// Creates a new empty cache holding at most `capacity` processed
+	/// contexts.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self(Arc::new(Inner {
+			state: Mutex::new(State {
+				entries: LinkedHashMap::new(),
+				stats: ProcessedContextCacheStats::default(),
+			}),
+			capacity,
+			key: key::<T, B>,
+		}))
+	}
+}
+
+impl<T, B> ProcessedContextCache<T, B> {
+	fn state(&self) -> MutexGuard<State<T, B>> {
+		self.0.state.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	/// Returns the maximum number of cached contexts.
+	pub fn capacity(&self) -> usize {
+		self.0.capacity
+	}
+
+	/// Returns the number of cached contexts.
+	pub fn len(&self) -> usize {
+		self.state().entries.len()
+	}
+
+	/// Checks if the cache is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Removes every cached context.
+	///
+	/// Statistics are preserved.
+	pub fn clear(&self) {
+		self.state().entries.clear()
+	}
+
+	/// Returns the cache statistics.
+	pub fn stats(&self) -> ProcessedContextCacheStats {
+		self.state().stats
+	}
+
+	/// Returns the result of processing the remote context `url` against
+	/// `active_context`, if cached.
+	///
+	/// `remote_contexts` lists the remote contexts already loaded, which are
+	/// ignored if referenced again by the remote context. The returned
+	/// context is attached to this cache.
+	pub fn get(
+		&self,
+		active_context: &Context<T, B>,
+		url: &T,
+		remote_contexts: &[T],
+		processing_mode: ProcessingMode,
+	) -> Option<Context<T, B>>
+	where
+		T: Clone + PartialEq,
+		B: Clone + PartialEq,
+	{
+		let key = (self.0.key)(active_context, url, remote_contexts, processing_mode);
+
+		let mut state = self.state();
+		let result = state
+			.entries
+			.to_back(&key)
+			.filter(|entry| entry.matches(active_context, url, remote_contexts, processing_mode))
+			.map(|entry| entry.processed.clone());
+
+		match &result {
+			Some(_) => state.stats.hits += 1,
+			None => state.stats.misses += 1,
+		}
+
+		drop(state);
+		result.map(|processed| processed.with_processed_context_cache(Some(self.clone())))
+	}
+
+	/// Stores the result of processing the remote context `url` against
+	/// `active_context`.
+	pub fn insert(
+		&self,
+		active_context: Context<T, B>,
+		url: T,
+		remote_contexts: Vec<T>,
+		processing_mode: ProcessingMode,
+		processed: Context<T, B>,
+	) {
+		if self.0.capacity == 0 {
+			return;
+		}
+
+		let key = (self.0.key)(&active_context, &url, &remote_contexts, processing_mode);
+
+		let mut state = self.state();
+		if !state.entries.contains_key(&key) {
+			while state.entries.len() >= self.0.capacity {
+				state.entries.pop_front();
+				state.stats.evictions += 1;
+			}
+		}
+
+		// Contexts are detached from the cache to avoid reference cycles.
+		state.entries.insert(
+			key,
+			Entry {
+				active_context: active_context.detached(),
+				url,
+				remote_contexts,
+				processing_mode,
+				processed: processed.detached(),
+			},
+		);
+	}
+}
+
+impl<T: Hash, B: Hash> Default for ProcessedContextCache<T, B> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, B> Clone for ProcessedContextCache<T, B> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+/// Type-erased processed remote context cache.
+///
+/// Wraps a [`ProcessedContextCache`] so that it can be stored independently
+/// of its IRI and blank node identifier types, for instance in the processor
+/// options. Cloning the handle is cheap, and clones share the same cache.
+#[derive(Clone)]
+pub struct AnyProcessedContextCache(Arc<dyn Any + Send + Sync>);
+
+impl AnyProcessedContextCache {
+	/// Wraps the given cache.
+	pub fn new<T: 'static + Send, B: 'static + Send>(cache: ProcessedContextCache<T, B>) -> Self {
+		Self(Arc::new(cache))
+	}
+
+	/// Returns the wrapped cache, if it uses the given IRI and blank node
+	/// identifier types.
+	pub fn downcast<T: 'static, B: 'static>(&self) -> Option<ProcessedContextCache<T, B>> {
+		self.0.downcast_ref().cloned()
+	}
+}
+
+impl<T: 'static + Send, B: 'static + Send> From<ProcessedContextCache<T, B>>
+	for AnyProcessedContextCache
+{
+	fn from(cache: ProcessedContextCache<T, B>) -> Self {
+		Self::new(cache)
+	}
+}
+
+impl<T, B> Context<T, B> {
+	/// Removes the processed context cache from this context and its previous
+	/// contexts.
+	fn detached(mut self) -> Self {
+		self.processed_context_cache = None;
+		self.previous_context = self
+			.previous_context
+			.map(|previous| Box::new(previous.detached()));
+		self
+	}
+}
+
+/// Computes the cache key of a remote context.
+fn key<T: Hash, B: Hash>(
+	active_context: &Context<T, B>,
+	url: &T,
+	remote_contexts: &[T],
+	processing_mode: ProcessingMode,
+) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	fingerprint(active_context).hash(&mut hasher);
+	url.hash(&mut hasher);
+	remote_contexts.hash(&mut hasher);
+	processing_mode.hash(&mut hasher);
+	hasher.finish()
+}
+
+/// Computes a fingerprint of the given context.
+///
+/// Only the main fields of term definitions are taken into account:
+/// contexts with the same fingerprint must still be compared with
+/// [`same_context`].
+fn fingerprint<T: Hash, B: Hash>(context: &Context<T, B>) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	context.original_base_url.hash(&mut hasher);
+	context.base_iri.hash(&mut hasher);
+	context.vocabulary.hash(&mut hasher);
+	context.default_language.hash(&mut hasher);
+	context.default_base_direction.hash(&mut hasher);
+	context
+		.previous_context
+		.as_deref()
+		.map(fingerprint)
+		.hash(&mut hasher);
+
+	// Term definitions are not ordered.
+	let mut definitions = 0u64;
+	for binding in &context.definitions {
+		let mut hasher = DefaultHasher::new();
+		match binding {
+			BindingRef::Normal(key, definition) => {
+				key.hash(&mut hasher);
+				definition.value.hash(&mut hasher);
+				definition.prefix.hash(&mut hasher);
+				definition.protected.hash(&mut hasher);
+				definition.reverse_property.hash(&mut hasher);
+				definition.base_url.hash(&mut hasher);
+				definition.container.hash(&mut hasher);
+				definition.typ.hash(&mut hasher);
+			}
+			BindingRef::Type(definition) => definition.protected.hash(&mut hasher),
+		}
+
+		definitions = definitions.wrapping_add(hasher.finish())
+	}
+
+	definitions.hash(&mut hasher);
+	hasher.finish()
+}
+
+/// Checks that both contexts are equal, including their term definitions.
+fn same_context<T: PartialEq, B: PartialEq>(a: &Context<T, B>, b: &Context<T, B>) -> bool {
+	a == b
+		&& a.definitions == b.definitions
+		&& match (a.previous_context(), b.previous_context()) {
+			(Some(a), Some(b)) => same_context(a, b),
+			_ => true,
+		}
+}
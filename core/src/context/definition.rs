@@ -70,7 +70,7 @@ impl<'a, T, B> BindingRef<'a, T, B> {
 }
 
 /// Context term definitions.
-#[derive(Clone)]
+#[derive(PartialEq, Eq, Clone)]
 pub struct Definitions<T, B> {
 	normal: HashMap<Key, NormalTermDefinition<T, B>>,
 	type_: Option<TypeTermDefinition>,
@@ -508,7 +508,7 @@ impl<'a, T, B> Clone for TermDefinitionRef<'a, T, B> {
 impl<'a, T, B> Copy for TermDefinitionRef<'a, T, B> {}
 
 // A term definition.
-#[derive(PartialEq, Eq, Clone)]
+#[derive(PartialEq, Eq, Clone, Debug)]
 pub struct NormalTermDefinition<T, B> {
 	// IRI mapping.
 	pub value: Option<Term<T, B>>,
@@ -0,0 +1,210 @@
+//! Remote context inlining.
+use crate::future::{BoxFuture, FutureExt};
+use crate::{ContextLoadError, Loader};
+use iref::{Iri, IriBuf, IriRef};
+use json_ld_syntax::context::{definition::Bindings, Context, Definition, TermDefinition};
+use json_ld_syntax::{ContextEntry, Nullable};
+use rdf_types::IriVocabularyMut;
+
+/// Remote contexts being inlined.
+#[derive(Default)]
+pub(crate) struct Stack {
+	/// URLs of the remote contexts being inlined, from the outermost.
+	urls: Vec<IriBuf>,
+
+	/// Index of the first remote context of the current context processing.
+	///
+	/// Scoped contexts are processed on their own, so they start a new chain
+	/// of remote contexts.
+	chain_start: usize,
+}
+
+/// Inlines the remote contexts referenced by `context`.
+///
+/// References are resolved against `base_url`, then loaded with `loader` and
+/// replaced by the loaded context entries, recursively. Contexts imported
+/// with `@import` are merged into the importing definition, and scoped
+/// contexts are inlined as well.
+///
+/// A reference to a remote context already loaded by the current context
+/// processing is removed, as the processing algorithm would ignore it. A
+/// scoped context referencing a remote context that is still being inlined
+/// keeps the (resolved) reference, since inlining it would never end.
+pub(crate) fn inline_context<'a, I, N, L>(
+	vocabulary: &'a mut N,
+	loader: &'a mut L,
+	context: Context,
+	base_url: Option<IriBuf>,
+	stack: &'a mut Stack,
+) -> BoxFuture<'a, Result<Context, ContextLoadError<L::Error>>>
+where
+	N: IriVocabularyMut<Iri = I>,
+	L: Loader<I>,
+	//
+	N: Send + Sync,
+	I: 'a + Send,
+	L: Send,
+{
+	async move {
+		let mut entries = Vec::new();
+		for entry in context {
+			match entry {
+				ContextEntry::Null => entries.push(ContextEntry::Null),
+				ContextEntry::IriRef(iri_ref) => {
+					let url = match resolve(iri_ref.as_iri_ref(), base_url.as_deref()) {
+						Some(url) => url,
+						None => {
+							entries.push(ContextEntry::IriRef(iri_ref));
+							continue;
+						}
+					};
+
+					if stack.urls[stack.chain_start..].contains(&url) {
+						continue;
+					}
+
+					if stack.urls.contains(&url) {
+						entries.push(ContextEntry::IriRef(url.into()));
+						continue;
+					}
+
+					let id = vocabulary.insert(url.as_iri());
+					let loaded = loader
+						.load_context_with(vocabulary, id)
+						.await?
+						.into_document();
+
+					stack.urls.push(url.clone());
+					let inlined =
+						inline_context(vocabulary, loader, loaded, Some(url), stack).await;
+					stack.urls.pop();
+
+					// `@base` is ignored in remote contexts.
+					entries.extend(inlined?.into_iter().map(|entry| match entry {
+						ContextEntry::Definition(mut definition) => {
+							definition.base = None;
+							ContextEntry::Definition(definition)
+						}
+						entry => entry,
+					}))
+				}
+				ContextEntry::Definition(definition) => {
+					let definition = inline_definition(
+						vocabulary,
+						loader,
+						definition,
+						base_url.as_deref(),
+						stack,
+					)
+					.await?;
+					entries.push(ContextEntry::Definition(definition))
+				}
+			}
+		}
+
+		if entries.len() == 1 {
+			Ok(Context::One(entries.pop().unwrap()))
+		} else {
+			Ok(Context::Many(entries))
+		}
+	}
+	.boxed()
+}
+
+/// Inlines the imported context and scoped contexts of `definition`.
+async fn inline_definition<I, N, L>(
+	vocabulary: &mut N,
+	loader: &mut L,
+	mut definition: Definition,
+	base_url: Option<&Iri>,
+	stack: &mut Stack,
+) -> Result<Definition, ContextLoadError<L::Error>>
+where
+	N: IriVocabularyMut<Iri = I>,
+	L: Loader<I>,
+	//
+	N: Send + Sync,
+	I: Send,
+	L: Send,
+{
+	if let Some(import) = definition.import.take() {
+		match resolve(import.as_iri_ref(), base_url) {
+			Some(url) => {
+				let id = vocabulary.insert(url.as_iri());
+				match loader
+					.load_context_with(vocabulary, id)
+					.await?
+					.into_document()
+				{
+					Context::One(ContextEntry::Definition(imported))
+						if imported.import.is_none() =>
+					{
+						definition = merge(imported, definition)
+					}
+					// Invalid imports are left to the context processing algorithm.
+					_ => definition.import = Some(url.into()),
+				}
+			}
+			None => definition.import = Some(import),
+		}
+	}
+
+	let mut bindings = Bindings::new();
+	for (key, binding) in definition.bindings {
+		let binding = match binding {
+			Nullable::Some(TermDefinition::Expanded(mut expanded)) => {
+				if let Some(context) = expanded.context.take() {
+					let chain_start = std::mem::replace(&mut stack.chain_start, stack.urls.len());
+					let inlined = inline_context(
+						vocabulary,
+						loader,
+						*context,
+						base_url.map(Iri::to_owned),
+						stack,
+					)
+					.await;
+					stack.chain_start = chain_start;
+					expanded.context = Some(Box::new(inlined?))
+				}
+
+				Nullable::Some(TermDefinition::Expanded(expanded))
+			}
+			binding => binding,
+		};
+
+		bindings.insert(key, binding);
+	}
+
+	definition.bindings = bindings;
+	Ok(definition)
+}
+
+/// Merges `definition` into the `imported` definition, replacing common
+/// entries with those from `definition`.
+fn merge(imported: Definition, definition: Definition) -> Definition {
+	let mut bindings = imported.bindings;
+	for (key, binding) in definition.bindings {
+		bindings.insert(key, binding);
+	}
+
+	Definition {
+		base: definition.base.or(imported.base),
+		import: None,
+		language: definition.language.or(imported.language),
+		direction: definition.direction.or(imported.direction),
+		propagate: definition.propagate.or(imported.propagate),
+		protected: definition.protected.or(imported.protected),
+		type_: definition.type_.or(imported.type_),
+		version: definition.version.or(imported.version),
+		vocab: definition.vocab.or(imported.vocab),
+		bindings,
+	}
+}
+
+/// Resolves the given context reference against `base_url`.
+fn resolve(iri_ref: &IriRef, base_url: Option<&Iri>) -> Option<IriBuf> {
+	match base_url {
+		Some(base_url) => Some(iri_ref.resolved(base_url)),
+		None => iri_ref.as_iri().map(Iri::to_owned),
+	}
+}
@@ -0,0 +1,238 @@
+//! Term resolution.
+use super::{BindingRef, Context, Key, NormalTermDefinition};
+use crate::{Id, Term};
+use contextual::WithContext;
+use iref::{Iri, IriRef};
+use json_ld_syntax::{is_keyword_like, CompactIri, Keyword};
+use rdf_types::{BlankId, VocabularyMut};
+
+/// How a term has been resolved by [`Context::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+	/// The term is a keyword.
+	Keyword,
+
+	/// The term is defined by the context.
+	Definition,
+
+	/// The term is a blank node identifier.
+	BlankId,
+
+	/// The term is a compact IRI whose prefix is defined by the context.
+	CompactIri,
+
+	/// The term is an absolute IRI.
+	Iri,
+
+	/// The term has been appended to the `@vocab` mapping.
+	Vocabulary,
+
+	/// The term is an IRI reference resolved against the base IRI.
+	BaseIri,
+
+	/// The term could not be resolved.
+	///
+	/// It is either keyword-like, or not an IRI.
+	Unresolved,
+}
+
+/// Term resolved against a context.
+pub struct ResolvedTerm<'a, T, B> {
+	/// Value the term expands to, as a property or type.
+	pub value: Term<T, B>,
+
+	/// How the term has been resolved.
+	pub resolution: Resolution,
+
+	/// Definition of the term, if any.
+	///
+	/// It provides the `@type`, `@container` and scoped context applying to
+	/// the term.
+	pub definition: Option<&'a NormalTermDefinition<T, B>>,
+
+	/// Definition of the prefix, if the term is a compact IRI.
+	pub prefix: Option<&'a NormalTermDefinition<T, B>>,
+}
+
+impl<'a, T, B> ResolvedTerm<'a, T, B> {
+	fn new(value: Term<T, B>, resolution: Resolution) -> Self {
+		Self {
+			value,
+			resolution,
+			definition: None,
+			prefix: None,
+		}
+	}
+
+	/// Checks if the term definition is protected.
+	pub fn is_protected(&self) -> bool {
+		self.definition.map(|d| d.protected).unwrap_or(false)
+	}
+}
+
+impl<T, B> Context<T, B> {
+	/// Resolves the given term or compact IRI, as done by the IRI expansion
+	/// algorithm for properties and types.
+	///
+	/// Term definitions are looked up first, then compact IRIs prefixes and
+	/// finally the `@vocab` mapping.
+	pub fn resolve<'a>(
+		&'a self,
+		vocabulary: &mut impl VocabularyMut<Iri = T, BlankId = B>,
+		term: &str,
+	) -> ResolvedTerm<'a, T, B>
+	where
+		T: Clone,
+		B: Clone,
+	{
+		match Keyword::try_from(term) {
+			Ok(keyword) => ResolvedTerm::new(Term::Keyword(keyword), Resolution::Keyword),
+			Err(_) => self.resolve_full(vocabulary, term, false, true),
+		}
+	}
+
+	/// Resolves the given string with the IRI expansion algorithm.
+	///
+	/// If `vocab` is true, term definitions and the `@vocab` mapping are used.
+	/// If `document_relative` is true, the string can be resolved against the
+	/// base IRI. Keyword-like strings are resolved to [`Term::Null`], and
+	/// strings that are not IRIs to [`Id::Invalid`], with the
+	/// [`Resolution::Unresolved`] resolution.
+	pub fn resolve_full<'a>(
+		&'a self,
+		vocabulary: &mut impl VocabularyMut<Iri = T, BlankId = B>,
+		value: &str,
+		document_relative: bool,
+		vocab: bool,
+	) -> ResolvedTerm<'a, T, B>
+	where
+		T: Clone,
+		B: Clone,
+	{
+		if is_keyword_like(value) {
+			return ResolvedTerm::new(Term::Null, Resolution::Unresolved);
+		}
+
+		if let Some(definition) = self.get_normal(value) {
+			// Keyword mappings are returned even if `vocab` is false.
+			let is_keyword = definition.value.as_ref().map_or(false, Term::is_keyword);
+			if vocab || is_keyword {
+				return ResolvedTerm {
+					value: definition.value.clone().unwrap_or(Term::Null),
+					resolution: Resolution::Definition,
+					definition: Some(definition),
+					prefix: None,
+				};
+			}
+		}
+
+		if value.find(':').map(|i| i > 0).unwrap_or(false) {
+			if let Ok(blank_id) = BlankId::new(value) {
+				return ResolvedTerm::new(
+					Term::Id(Id::blank(vocabulary.insert_blank_id(blank_id))),
+					Resolution::BlankId,
+				);
+			}
+
+			if value == "_:" {
+				return ResolvedTerm::new(
+					Term::Id(Id::Invalid(value.to_string())),
+					Resolution::BlankId,
+				);
+			}
+
+			if let Ok(compact_iri) = CompactIri::new(value) {
+				if let Some(prefix) = self.get_normal(compact_iri.prefix()) {
+					if prefix.prefix {
+						if let Some(mapping) = &prefix.value {
+							let mut result = mapping.with(&*vocabulary).as_str().to_string();
+							result.push_str(compact_iri.suffix());
+
+							return ResolvedTerm {
+								value: Term::Id(Id::from_string_in(vocabulary, result)),
+								resolution: Resolution::CompactIri,
+								definition: None,
+								prefix: Some(prefix),
+							};
+						}
+					}
+				}
+			}
+
+			if let Ok(iri) = Iri::new(value) {
+				return ResolvedTerm::new(
+					Term::Id(Id::iri(vocabulary.insert(iri))),
+					Resolution::Iri,
+				);
+			}
+		}
+
+		if vocab {
+			match self.vocabulary() {
+				Some(Term::Id(mapping)) => {
+					let mut result = mapping.with(&*vocabulary).as_str().to_string();
+					result.push_str(value);
+
+					return ResolvedTerm::new(
+						Term::Id(Id::from_string_in(vocabulary, result)),
+						Resolution::Vocabulary,
+					);
+				}
+				Some(_) => {
+					return ResolvedTerm::new(
+						Term::Id(Id::Invalid(value.to_string())),
+						Resolution::Unresolved,
+					)
+				}
+				None => (),
+			}
+		}
+
+		if document_relative {
+			if let Ok(iri_ref) = IriRef::new(value) {
+				let iri = match self.base_iri() {
+					Some(base_iri) => {
+						let iri = iri_ref.resolved(vocabulary.iri(base_iri).unwrap());
+						Some(vocabulary.insert(iri.as_iri()))
+					}
+					None => iri_ref.as_iri().map(|iri| vocabulary.insert(iri)),
+				};
+
+				if let Some(iri) = iri {
+					return ResolvedTerm::new(Term::Id(Id::iri(iri)), Resolution::BaseIri);
+				}
+			}
+		}
+
+		ResolvedTerm::new(
+			Term::Id(Id::Invalid(value.to_string())),
+			Resolution::Unresolved,
+		)
+	}
+
+	/// Returns every term mapping to the given value, shortest first.
+	pub fn terms_for(&self, value: &Term<T, B>) -> Vec<&Key>
+	where
+		T: PartialEq,
+		B: PartialEq,
+	{
+		let mut terms: Vec<_> = self
+			.definitions()
+			.iter()
+			.filter_map(|binding| match binding {
+				BindingRef::Normal(key, definition) if definition.value.as_ref() == Some(value) => {
+					Some(key)
+				}
+				_ => None,
+			})
+			.collect();
+
+		terms.sort_by(|a, b| {
+			a.len()
+				.cmp(&b.len())
+				.then_with(|| a.as_str().cmp(b.as_str()))
+		});
+
+		terms
+	}
+}
@@ -1,7 +1,10 @@
 use crate::object::{InvalidExpandedJson, TryFromJson, TryFromJsonObject};
+use crate::source::Position;
 use json_ld_syntax::{IntoJson, IntoJsonWithContext};
 use rdf_types::VocabularyMut;
+use std::cmp::Ordering;
 use std::convert::{TryFrom, TryInto};
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 
 /// Indexed objects.
@@ -10,13 +13,20 @@ use std::ops::{Deref, DerefMut};
 /// This type is a wrapper around any kind of indexable data.
 ///
 /// It is a pointer type that `Deref` into the underlying value.
-#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+///
+/// It may also record the position of the value in the input document it was
+/// expanded from (see [`Self::position`]). This position is not considered
+/// when comparing or hashing indexed values.
+#[derive(Clone, Debug)]
 pub struct Indexed<T> {
 	/// Index.
 	index: Option<String>,
 
 	/// Value.
 	value: T,
+
+	/// Position of the value in the source document.
+	position: Option<Position>,
 }
 
 impl<T> Indexed<T> {
@@ -28,7 +38,11 @@ impl<T> Indexed<T> {
 	/// Create a new (maybe) indexed value.
 	#[inline(always)]
 	pub fn new(value: T, index: Option<String>) -> Self {
-		Indexed { value, index }
+		Indexed {
+			value,
+			index,
+			position: None,
+		}
 	}
 
 	/// Get a reference to the inner value.
@@ -62,6 +76,22 @@ impl<T> Indexed<T> {
 		self.index = index
 	}
 
+	/// Returns the position of the value in the input document it was
+	/// expanded from, if known.
+	///
+	/// The position is only recorded when the input document is expanded
+	/// along with its code map.
+	#[inline(always)]
+	pub fn position(&self) -> Option<Position> {
+		self.position
+	}
+
+	/// Sets the position of the value in the input document.
+	#[inline(always)]
+	pub fn set_position(&mut self, position: Option<Position>) {
+		self.position = position
+	}
+
 	/// Turn this indexed value into its components: inner value and index.
 	#[inline(always)]
 	pub fn into_parts(self) -> (T, Option<String>) {
@@ -74,21 +104,37 @@ impl<T> Indexed<T> {
 	where
 		F: FnOnce(T) -> U,
 	{
-		Indexed::new(f(self.value), self.index)
+		Indexed {
+			value: f(self.value),
+			index: self.index,
+			position: self.position,
+		}
 	}
 
 	/// Cast the inner value.
 	#[inline(always)]
 	pub fn cast<U: From<T>>(self) -> Indexed<U> {
-		Indexed::new(self.value.into(), self.index)
+		Indexed {
+			value: self.value.into(),
+			index: self.index,
+			position: self.position,
+		}
 	}
 
 	/// Try to cast the inner value.
 	#[inline(always)]
 	pub fn try_cast<U: TryFrom<T>>(self) -> Result<Indexed<U>, Indexed<U::Error>> {
 		match self.value.try_into() {
-			Ok(value) => Ok(Indexed::new(value, self.index)),
-			Err(e) => Err(Indexed::new(e, self.index)),
+			Ok(value) => Ok(Indexed {
+				value,
+				index: self.index,
+				position: self.position,
+			}),
+			Err(e) => Err(Indexed {
+				value: e,
+				index: self.index,
+				position: self.position,
+			}),
 		}
 	}
 }
@@ -126,6 +172,38 @@ impl<T, B, O: TryFromJsonObject<T, B>> TryFromJsonObject<T, B> for Indexed<O> {
 	}
 }
 
+impl<T: PartialEq> PartialEq for Indexed<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.index == other.index && self.value == other.value
+	}
+}
+
+impl<T: Eq> Eq for Indexed<T> {}
+
+impl<T: Hash> Hash for Indexed<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.index.hash(state);
+		self.value.hash(state)
+	}
+}
+
+impl<T: PartialOrd> PartialOrd for Indexed<T> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		match self.index.partial_cmp(&other.index) {
+			Some(Ordering::Equal) => self.value.partial_cmp(&other.value),
+			ord => ord,
+		}
+	}
+}
+
+impl<T: Ord> Ord for Indexed<T> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.index
+			.cmp(&other.index)
+			.then_with(|| self.value.cmp(&other.value))
+	}
+}
+
 impl<T> From<T> for Indexed<T> {
 	#[inline(always)]
 	fn from(value: T) -> Indexed<T> {
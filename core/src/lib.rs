@@ -17,6 +17,7 @@ pub mod print;
 pub mod quad;
 pub mod rdf;
 mod serialization;
+pub mod source;
 mod term;
 mod ty;
 pub mod utils;
@@ -1,4 +1,4 @@
-use crate::future::BoxFuture;
+use crate::future::{BoxFuture, FutureExt};
 use hashbrown::HashSet;
 use iref::{Iri, IriBuf};
 use mime::Mime;
@@ -6,19 +6,42 @@ use rdf_types::{IriVocabulary, IriVocabularyMut};
 use static_iref::iri;
 use std::borrow::Cow;
 
+pub mod cache;
 pub mod chain;
+pub mod data;
+pub mod dynamic;
+pub mod fallback;
+pub mod file;
 pub mod fs;
+pub mod html;
+pub mod map;
 pub mod none;
+pub mod policy;
+pub mod preloaded;
 
+pub use cache::{CacheStats, CachingLoader};
 pub use chain::ChainLoader;
+pub use data::DataLoader;
+pub use dynamic::{BoxedLoader, DynLoader};
+pub use fallback::FallbackLoader;
+pub use file::FileLoader;
 pub use fs::FsLoader;
+pub use map::MapLoader;
 pub use none::NoLoader;
+pub use policy::PolicyLoader;
+pub use preloaded::{PreloadedLoader, StaticLoader};
+
+#[cfg(feature = "archive")]
+pub mod archive;
+
+#[cfg(feature = "archive")]
+pub use archive::ArchiveLoader;
 
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
 
 #[cfg(feature = "reqwest")]
-pub use self::reqwest::ReqwestLoader;
+pub use self::reqwest::{BlockingReqwestLoader, ReqwestLoader};
 
 pub type LoadingResult<I, E> = Result<RemoteDocument<I>, E>;
 
@@ -107,6 +130,16 @@ pub enum ContextLoadError<E> {
 	ContextExtractionFailed(#[from] ExtractContextError),
 }
 
+impl<E> ContextLoadError<E> {
+	/// Maps the document loading error.
+	pub fn map<F>(self, f: impl FnOnce(E) -> F) -> ContextLoadError<F> {
+		match self {
+			Self::LoadingDocumentFailed(e) => ContextLoadError::LoadingDocumentFailed(f(e)),
+			Self::ContextExtractionFailed(e) => ContextLoadError::ContextExtractionFailed(e),
+		}
+	}
+}
+
 impl<I> RemoteContextReference<I> {
 	/// Loads the remote context with the given `vocabulary` and `loader`.
 	///
@@ -124,11 +157,7 @@ impl<I> RemoteContextReference<I> {
 		I: Send,
 	{
 		match self {
-			Self::Iri(r) => Ok(loader
-				.load_with(vocabulary, r)
-				.await
-				.map_err(ContextLoadError::LoadingDocumentFailed)?
-				.try_map(|d| d.into_ld_context())?),
+			Self::Iri(r) => loader.load_context_with(vocabulary, r).await,
 			Self::Loaded(doc) => Ok(doc),
 		}
 	}
@@ -153,11 +182,7 @@ impl<I> RemoteContextReference<I> {
 	{
 		match self {
 			Self::Iri(r) => Ok(Cow::Owned(
-				loader
-					.load_with(vocabulary, r.clone())
-					.await
-					.map_err(ContextLoadError::LoadingDocumentFailed)?
-					.try_map(|d| d.into_ld_context())?,
+				loader.load_context_with(vocabulary, r.clone()).await?,
 			)),
 			Self::Loaded(doc) => Ok(Cow::Borrowed(doc)),
 		}
@@ -172,6 +197,11 @@ pub struct RemoteDocument<I = IriBuf, T = json_syntax::Value> {
 	/// The final URL of the loaded document, after eventual redirection.
 	url: Option<I>,
 
+	/// Base URL of the document, if it differs from its URL.
+	///
+	/// This is the case of HTML documents with a `<base>` element.
+	base_url: Option<I>,
+
 	/// The HTTP `Content-Type` header value of the loaded document, exclusive
 	/// of any optional parameters.
 	content_type: Option<Mime>,
@@ -228,6 +258,7 @@ impl<I, T> RemoteDocument<I, T> {
 	) -> Self {
 		Self {
 			url,
+			base_url: None,
 			content_type,
 			context_url,
 			profile,
@@ -239,6 +270,7 @@ impl<I, T> RemoteDocument<I, T> {
 	pub fn map<U>(self, f: impl Fn(T) -> U) -> RemoteDocument<I, U> {
 		RemoteDocument {
 			url: self.url,
+			base_url: self.base_url,
 			content_type: self.content_type,
 			context_url: self.context_url,
 			profile: self.profile,
@@ -250,6 +282,7 @@ impl<I, T> RemoteDocument<I, T> {
 	pub fn try_map<U, E>(self, f: impl Fn(T) -> Result<U, E>) -> Result<RemoteDocument<I, U>, E> {
 		Ok(RemoteDocument {
 			url: self.url,
+			base_url: self.base_url,
 			content_type: self.content_type,
 			context_url: self.context_url,
 			profile: self.profile,
@@ -262,6 +295,23 @@ impl<I, T> RemoteDocument<I, T> {
 		self.url.as_ref()
 	}
 
+	/// Returns the base URL of the document, used to resolve its relative
+	/// IRI references.
+	///
+	/// This is the URL of the document, unless another base URL has been set
+	/// with [`Self::set_base_url`] (for instance from the `<base>` element of
+	/// an HTML document).
+	pub fn base_url(&self) -> Option<&I> {
+		self.base_url.as_ref().or(self.url.as_ref())
+	}
+
+	/// Sets the base URL of the document.
+	///
+	/// If `None`, the URL of the document is used as base URL.
+	pub fn set_base_url(&mut self, base_url: Option<I>) {
+		self.base_url = base_url
+	}
+
 	/// Returns the HTTP `Content-Type` header value of the loaded document,
 	/// exclusive of any optional parameters.
 	pub fn content_type(&self) -> Option<&Mime> {
@@ -308,6 +358,59 @@ impl<I, T> RemoteDocument<I, T> {
 	}
 }
 
+impl<I> RemoteDocument<I> {
+	/// Creates a new remote document from the JSON-LD content embedded in
+	/// the given HTML document.
+	///
+	/// See [`html::extract`] for how the script elements are selected. The
+	/// URL of the resulting document is the given `url`. Its
+	/// [base URL](Self::base_url) is the base IRI of the HTML document, which
+	/// accounts for any `<base href>` element.
+	pub fn from_html_with(
+		vocabulary: &mut impl IriVocabularyMut<Iri = I>,
+		url: Option<I>,
+		content_type: Option<Mime>,
+		content: &str,
+		options: &html::Options,
+	) -> Result<Self, html::Error> {
+		let document_url = url.as_ref().map(|url| vocabulary.iri(url).unwrap());
+		let extracted = html::extract(content, document_url, options)?;
+
+		let base_url = extracted
+			.base_url
+			.filter(|base_url| Some(base_url.as_iri()) != document_url)
+			.map(|base_url| vocabulary.insert(base_url.as_iri()));
+
+		let mut document = Self::new(
+			url,
+			Some(content_type.unwrap_or(mime::TEXT_HTML)),
+			extracted.document,
+		);
+		document.set_base_url(base_url);
+		Ok(document)
+	}
+}
+
+impl RemoteDocument {
+	/// Creates a new remote document from the JSON-LD content embedded in
+	/// the given HTML document.
+	///
+	/// See [`Self::from_html_with`].
+	pub fn from_html(
+		url: Option<IriBuf>,
+		content: &str,
+		options: &html::Options,
+	) -> Result<Self, html::Error> {
+		Self::from_html_with(
+			rdf_types::vocabulary::no_vocabulary_mut(),
+			url,
+			None,
+			content,
+			options,
+		)
+	}
+}
+
 /// Standard `profile` parameter values defined for the `application/ld+json`.
 ///
 /// See: <https://www.w3.org/TR/json-ld11/#iana-considerations>
@@ -428,58 +531,52 @@ pub trait Loader<I = IriBuf> {
 		self.load_with(rdf_types::vocabulary::no_vocabulary_mut(), url)
 			.await
 	}
-}
 
-// /// Context document loader.
-// ///
-// /// This is a subclass of loaders able to extract a local context from a loaded
-// /// JSON-LD document.
-// ///
-// /// It is implemented for any loader where the output type implements
-// /// [`ExtractContext`].
-// pub trait ContextLoader<I = IriBuf> {
-// 	/// Error type.
-// 	type ContextError;
-
-// 	/// Loads the context behind the given IRI, using the given vocabulary.
-// 	fn load_context_with<'a>(
-// 		&'a mut self,
-// 		vocabulary: &'a mut (impl Send + Sync + IriVocabularyMut<Iri = I>),
-// 		url: I,
-// 	) -> BoxFuture<'a, LoadingResult<I, M, json_ld_syntax::context::Context, Self::ContextError>>
-// 	where
-// 		I: 'a,
-// 		M: 'a;
-
-// 	/// Loads the context behind the given IRI.
-// 	fn load_context<'a>(
-// 		&'a mut self,
-// 		url: I,
-// 	) -> BoxFuture<'a, LoadingResult<I, M, json_ld_syntax::context::Context, Self::ContextError>>
-// 	where
-// 		I: 'a,
-// 		M: 'a,
-// 		(): IriVocabulary<Iri = I>,
-// 	{
-// 		self.load_context_with(rdf_types::vocabulary::no_vocabulary_mut(), url)
-// 	}
-// }
-
-// /// Context extraction method.
-// ///
-// /// Implemented by documents containing a JSON-LD context definition, providing
-// /// a method to extract this context.
-// pub trait ExtractContext: Sized {
-// 	/// Error type.
-// 	///
-// 	/// May be raised if the inner context is missing or invalid.
-// 	type Error;
-
-// 	/// Extract the context definition.
-// 	fn extract_context(
-// 		self,
-// 	) -> Result<json_ld_syntax::context::Context, Self::Error>;
-// }
+	/// Loads the local context defined by the document behind the given IRI,
+	/// using the given vocabulary.
+	///
+	/// This is used by the context processing algorithm to dereference remote
+	/// contexts. The default implementation loads the document with
+	/// [`Self::load_with`] and extracts its `@context` entry with
+	/// [`ExtractContext::into_ld_context`]. Loaders able to provide
+	/// already-parsed contexts (for instance from a cache) can override it to
+	/// skip the extraction.
+	fn load_context_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Self::Error>>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		let document = self.load_with(vocabulary, url);
+		async move {
+			Ok(document
+				.await
+				.map_err(ContextLoadError::LoadingDocumentFailed)?
+				.try_map(|d| d.into_ld_context())?)
+		}
+		.boxed()
+	}
+
+	/// Loads the local context defined by the document behind the given IRI.
+	#[allow(async_fn_in_trait)]
+	async fn load_context(
+		&mut self,
+		url: I,
+	) -> Result<RemoteContext<I>, ContextLoadError<Self::Error>>
+	where
+		(): IriVocabulary<Iri = I>,
+		//
+		I: Send,
+	{
+		self.load_context_with(rdf_types::vocabulary::no_vocabulary_mut(), url)
+			.await
+	}
+}
 
 /// Context extraction error.
 #[derive(Debug, thiserror::Error)]
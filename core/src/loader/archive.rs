@@ -0,0 +1,253 @@
+use super::{fs::content_type, Loader, RemoteDocument};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use json_syntax::Parse;
+use rdf_types::{vocabulary::IriIndex, IriVocabulary, IriVocabularyMut};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+
+/// Loading error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// No mount point found for the given IRI.
+	#[error("no mount point")]
+	NoMountPoint,
+
+	/// The archive format cannot be deduced from the file name.
+	#[error("unknown archive format for `{0}`")]
+	UnknownFormat(PathBuf),
+
+	/// No entry found in the archive.
+	#[error("no entry `{0}` in archive")]
+	NotFound(String),
+
+	/// IO error.
+	#[error(transparent)]
+	IO(std::io::Error),
+
+	/// Zip archive error.
+	#[error(transparent)]
+	Zip(zip::result::ZipError),
+
+	/// Parse error.
+	#[error("parse error: {0}")]
+	Parse(json_syntax::parse::Error),
+}
+
+/// Archive format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+	/// Uncompressed tar archive (`.tar`).
+	Tar,
+
+	/// Gzip-compressed tar archive (`.tar.gz` or `.tgz`).
+	TarGz,
+
+	/// Zip archive (`.zip`).
+	Zip,
+}
+
+impl Format {
+	/// Deduces the archive format from the given file name.
+	pub fn from_path(path: &Path) -> Option<Self> {
+		let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+		if name.ends_with(".tar") {
+			Some(Self::Tar)
+		} else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+			Some(Self::TarGz)
+		} else if name.ends_with(".zip") {
+			Some(Self::Zip)
+		} else {
+			None
+		}
+	}
+}
+
+/// Mounted archive.
+struct Archive {
+	path: PathBuf,
+	format: Format,
+}
+
+impl Archive {
+	/// Reads the content of the given entry.
+	fn read(&self, name: &str) -> Result<String, Error> {
+		let file = File::open(&self.path).map_err(Error::IO)?;
+		let mut contents = String::new();
+
+		match self.format {
+			Format::Tar => read_tar_entry(BufReader::new(file), name, &mut contents)?,
+			Format::TarGz => read_tar_entry(
+				flate2::read::GzDecoder::new(BufReader::new(file)),
+				name,
+				&mut contents,
+			)?,
+			Format::Zip => {
+				let mut archive = zip::ZipArchive::new(BufReader::new(file)).map_err(Error::Zip)?;
+				let mut entry = archive.by_name(name).map_err(|e| match e {
+					zip::result::ZipError::FileNotFound => Error::NotFound(name.to_owned()),
+					e => Error::Zip(e),
+				})?;
+				entry.read_to_string(&mut contents).map_err(Error::IO)?;
+			}
+		}
+
+		Ok(contents)
+	}
+}
+
+/// Reads the content of the given tar entry, scanning the archive until it
+/// is found.
+fn read_tar_entry(reader: impl Read, name: &str, contents: &mut String) -> Result<(), Error> {
+	let mut archive = tar::Archive::new(reader);
+	for entry in archive.entries().map_err(Error::IO)? {
+		let mut entry = entry.map_err(Error::IO)?;
+		if entry.header().entry_type().is_file()
+			&& entry_name(&entry.path().map_err(Error::IO)?) == name
+		{
+			entry.read_to_string(contents).map_err(Error::IO)?;
+			return Ok(());
+		}
+	}
+
+	Err(Error::NotFound(name.to_owned()))
+}
+
+/// Normalizes an archive entry path into a `/`-separated name.
+fn entry_name(path: &Path) -> String {
+	let mut name = String::new();
+	for component in path.components() {
+		if let Component::Normal(segment) = component {
+			if !name.is_empty() {
+				name.push('/')
+			}
+
+			name.push_str(&segment.to_string_lossy())
+		}
+	}
+
+	name
+}
+
+/// Archive loader.
+///
+/// This is a special JSON-LD document loader that can load documents from
+/// `.tar`, `.tar.gz` or `.zip` archives by attaching an archive to specific
+/// URLs, the same way the [`FsLoader`](super::FsLoader) attaches directories.
+///
+/// Archives are only opened when a document is loaded, and only the requested
+/// entry is read. Parsed documents are cached, so each entry is read at most
+/// once.
+pub struct ArchiveLoader<I = IriIndex> {
+	mount_points: Vec<(I, Archive)>,
+	cache: HashMap<(usize, String), json_syntax::Value>,
+}
+
+impl<I> Default for ArchiveLoader<I> {
+	fn default() -> Self {
+		Self {
+			mount_points: Vec::new(),
+			cache: HashMap::new(),
+		}
+	}
+}
+
+impl<I> ArchiveLoader<I> {
+	/// Creates a new archive loader.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Bind the given IRI prefix to the given archive.
+	///
+	/// The archive format is deduced from the file name.
+	/// Any document with an IRI matching the given prefix will be loaded from
+	/// the referenced archive.
+	pub fn mount<P: AsRef<Path>>(&mut self, url: I, path: P) -> Result<(), Error> {
+		let path = path.as_ref();
+		let format =
+			Format::from_path(path).ok_or_else(|| Error::UnknownFormat(path.to_owned()))?;
+		self.mount_with_format(url, path, format);
+		Ok(())
+	}
+
+	/// Bind the given IRI prefix to the given archive with the given format.
+	pub fn mount_with_format<P: AsRef<Path>>(&mut self, url: I, path: P, format: Format) {
+		self.mount_points.push((
+			url,
+			Archive {
+				path: path.as_ref().into(),
+				format,
+			},
+		))
+	}
+
+	/// Returns the index of the mount point and the archive entry name
+	/// associated to the given `url` if any.
+	///
+	/// The mount point with the longest matching IRI prefix is used.
+	fn entry(&self, vocabulary: &impl IriVocabulary<Iri = I>, url: &I) -> Option<(usize, String)> {
+		let url = vocabulary.iri(url).unwrap();
+		let mut result: Option<(usize, usize, String)> = None;
+
+		for (i, (target_url, _)) in self.mount_points.iter().enumerate() {
+			let target_url = vocabulary.iri(target_url).unwrap();
+			let prefix_len = target_url.as_str().len();
+
+			if matches!(result, Some((len, _, _)) if len >= prefix_len) {
+				continue;
+			}
+
+			if let Some((suffix, _, _)) = url.as_iri_ref().suffix(target_url.as_iri_ref()) {
+				let name = suffix
+					.as_path()
+					.segments()
+					.map(|seg| seg.as_str())
+					.collect::<Vec<_>>()
+					.join("/");
+
+				result = Some((prefix_len, i, name))
+			}
+		}
+
+		result.map(|(_, i, name)| (i, name))
+	}
+}
+
+impl<I: Send> Loader<I> for ArchiveLoader<I> {
+	type Error = Error;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			let (i, name) = self.entry(vocabulary, &url).ok_or(Error::NoMountPoint)?;
+			let content_type = content_type(Path::new(&name));
+
+			let key = (i, name);
+			let doc = match self.cache.get(&key) {
+				Some(doc) => doc.clone(),
+				None => {
+					let contents = self.mount_points[i].1.read(&key.1)?;
+					let (doc, _) =
+						json_syntax::Value::parse_str(&contents).map_err(Error::Parse)?;
+					self.cache.insert(key, doc.clone());
+					doc
+				}
+			};
+
+			Ok(RemoteDocument::new(Some(url), Some(content_type), doc))
+		}
+		.boxed()
+	}
+}
@@ -0,0 +1,270 @@
+use super::{ContextLoadError, ExtractContext, Loader, RemoteContext, RemoteDocument};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use hashlink::LinkedHashMap;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// Cache statistics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+	/// Number of documents served from the cache.
+	pub hits: u64,
+
+	/// Number of documents that had to be loaded by the inner loader.
+	pub misses: u64,
+
+	/// Number of documents removed from the cache because it was full.
+	pub evictions: u64,
+
+	/// Number of documents removed from the cache because they expired.
+	pub expirations: u64,
+}
+
+/// Cache entry.
+///
+/// Holds the document, the context, or both, depending on how the IRI has
+/// been loaded.
+struct Entry<I> {
+	/// Document, if it has been loaded as a document.
+	document: Option<RemoteDocument<I>>,
+
+	/// Context, if it has been loaded as a context or extracted from the
+	/// document.
+	context: Option<RemoteContext<I>>,
+
+	/// Time at which the document or context was last loaded by the inner
+	/// loader.
+	loaded_at: Instant,
+}
+
+/// In-memory caching loader.
+///
+/// Wraps any [`Loader`] and memoizes the loaded documents by IRI, so that a
+/// document is loaded only once by the inner loader. Errors are not cached.
+/// Contexts are loaded with the inner loader's
+/// [`Loader::load_context_with`] and cached as well, so that they are parsed
+/// only once. A context requested for a cached document is extracted from
+/// that document and counted as a hit, but a document requested for a cached
+/// context is always a miss, since the context does not hold the document.
+///
+/// The cache can be bounded with [`Self::with_capacity`], in which case the
+/// least recently used document is evicted first, and entries can be given
+/// a time to live with [`Self::with_ttl`].
+pub struct CachingLoader<L, I> {
+	inner: L,
+	entries: LinkedHashMap<I, Entry<I>>,
+	capacity: Option<usize>,
+	ttl: Option<Duration>,
+	stats: CacheStats,
+}
+
+impl<L, I> CachingLoader<L, I> {
+	/// Creates a new unbounded cache around the given loader.
+	pub fn new(inner: L) -> Self {
+		Self {
+			inner,
+			entries: LinkedHashMap::new(),
+			capacity: None,
+			ttl: None,
+			stats: CacheStats::default(),
+		}
+	}
+
+	/// Bounds the number of cached documents.
+	///
+	/// When the cache is full, the least recently used document is evicted.
+	pub fn with_capacity(mut self, capacity: usize) -> Self {
+		self.capacity = Some(capacity);
+		self
+	}
+
+	/// Sets the time to live of cached documents.
+	///
+	/// Expired documents are loaded again by the inner loader.
+	pub fn with_ttl(mut self, ttl: Duration) -> Self {
+		self.ttl = Some(ttl);
+		self
+	}
+
+	/// Returns the inner loader.
+	pub fn inner(&self) -> &L {
+		&self.inner
+	}
+
+	/// Returns a mutable reference to the inner loader.
+	pub fn inner_mut(&mut self) -> &mut L {
+		&mut self.inner
+	}
+
+	/// Drops the cache and returns the inner loader.
+	pub fn into_inner(self) -> L {
+		self.inner
+	}
+
+	/// Returns the cache statistics.
+	pub fn stats(&self) -> CacheStats {
+		self.stats
+	}
+
+	/// Returns the number of cached documents, including expired ones that
+	/// have not been removed yet.
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Checks if the cache is empty.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Removes every cached document.
+	///
+	/// Statistics are preserved.
+	pub fn clear(&mut self) {
+		self.entries.clear()
+	}
+
+	fn is_expired(&self, entry: &Entry<I>) -> bool {
+		self.ttl
+			.map(|ttl| entry.loaded_at.elapsed() >= ttl)
+			.unwrap_or(false)
+	}
+}
+
+impl<L, I: Eq + Hash> CachingLoader<L, I> {
+	/// Checks if the given IRI is cached and not expired.
+	pub fn contains(&self, url: &I) -> bool {
+		self.entries
+			.get(url)
+			.map(|entry| !self.is_expired(entry))
+			.unwrap_or(false)
+	}
+
+	/// Removes the given IRI from the cache, returning the cached document if
+	/// any.
+	pub fn remove(&mut self, url: &I) -> Option<RemoteDocument<I>> {
+		self.entries.remove(url).and_then(|entry| entry.document)
+	}
+}
+
+impl<L, I: Clone + Eq + Hash> CachingLoader<L, I> {
+	/// Returns the cached entry for the given IRI, if any, marking it as
+	/// the most recently used.
+	fn get(&mut self, url: &I) -> Option<&mut Entry<I>> {
+		if self.is_expired(self.entries.get(url)?) {
+			self.entries.remove(url);
+			self.stats.expirations += 1;
+			return None;
+		}
+
+		self.entries.to_back(url)
+	}
+
+	/// Returns the entry for the given IRI, inserting an empty one if
+	/// needed, marking it as the most recently used.
+	///
+	/// Returns `None` if nothing can be cached.
+	fn entry(&mut self, url: I) -> Option<&mut Entry<I>> {
+		if !self.entries.contains_key(&url) {
+			if let Some(capacity) = self.capacity {
+				if capacity == 0 {
+					return None;
+				}
+
+				while self.entries.len() >= capacity {
+					self.entries.pop_front();
+					self.stats.evictions += 1;
+				}
+			}
+
+			self.entries.insert(
+				url.clone(),
+				Entry {
+					document: None,
+					context: None,
+					loaded_at: Instant::now(),
+				},
+			);
+		}
+
+		self.entries.to_back(&url)
+	}
+}
+
+impl<L, I> Loader<I> for CachingLoader<L, I>
+where
+	L: Loader<I> + Send,
+	I: Clone + Eq + Hash + Send + Sync,
+{
+	type Error = L::Error;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Self::Error>>
+	where
+		V: rdf_types::IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			if let Some(document) = self.get(&url).and_then(|entry| entry.document.clone()) {
+				self.stats.hits += 1;
+				return Ok(document);
+			}
+
+			self.stats.misses += 1;
+			let document = self.inner.load_with(vocabulary, url.clone()).await?;
+			if let Some(entry) = self.entry(url) {
+				entry.document = Some(document.clone());
+				entry.loaded_at = Instant::now()
+			}
+
+			Ok(document)
+		}
+		.boxed()
+	}
+
+	fn load_context_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Self::Error>>>
+	where
+		V: rdf_types::IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			if let Some(entry) = self.get(&url) {
+				if entry.context.is_none() {
+					if let Some(document) = &entry.document {
+						entry.context = Some(document.clone().try_map(|d| d.into_ld_context())?);
+					}
+				}
+
+				if let Some(context) = entry.context.clone() {
+					self.stats.hits += 1;
+					return Ok(context);
+				}
+			}
+
+			self.stats.misses += 1;
+			let context = self
+				.inner
+				.load_context_with(vocabulary, url.clone())
+				.await?;
+			if let Some(entry) = self.entry(url) {
+				entry.context = Some(context.clone());
+				entry.loaded_at = Instant::now()
+			}
+
+			Ok(context)
+		}
+		.boxed()
+	}
+}
@@ -3,7 +3,7 @@ use std::fmt;
 use crate::future::{BoxFuture, FutureExt};
 use crate::LoadingResult;
 
-use super::Loader;
+use super::{ContextLoadError, Loader, RemoteContext};
 
 /// * [`ChainLoader`]: loads document from the first loader, otherwise falls back to the second one.
 ///
@@ -53,6 +53,33 @@ where
 		}
 		.boxed()
 	}
+
+	fn load_context_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Self::Error>>>
+	where
+		V: rdf_types::IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			match self.0.load_context_with(vocabulary, url.clone()).await {
+				Err(ContextLoadError::LoadingDocumentFailed(err1)) => self
+					.1
+					.load_context_with(vocabulary, url)
+					.await
+					.map_err(|e| e.map(|err2| Error(err1, err2))),
+				Err(ContextLoadError::ContextExtractionFailed(e)) => {
+					Err(ContextLoadError::ContextExtractionFailed(e))
+				}
+				Ok(context) => Ok(context),
+			}
+		}
+		.boxed()
+	}
 }
 
 /// Either-or error.
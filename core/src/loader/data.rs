@@ -0,0 +1,170 @@
+use super::{Loader, RemoteDocument};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use base64::Engine;
+use json_syntax::Parse;
+use mime::Mime;
+use rdf_types::IriVocabularyMut;
+
+/// Loading error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The IRI is not a `data:` URL.
+	#[error("not a `data:` URL")]
+	NotDataUrl,
+
+	/// The URL has no `,` separating the media type from the data.
+	#[error("missing data separator")]
+	MissingSeparator,
+
+	/// The media type is not a JSON media type.
+	#[error("invalid content type `{0}`")]
+	InvalidContentType(String),
+
+	/// Invalid base64 data.
+	#[error("invalid base64 data: {0}")]
+	Base64(base64::DecodeError),
+
+	/// The data is not valid UTF-8.
+	#[error("invalid data encoding")]
+	InvalidEncoding,
+
+	/// Parse error.
+	#[error("parse error: {0}")]
+	Parse(json_syntax::parse::Error),
+}
+
+/// `data:` URL loader.
+///
+/// Loads documents embedded in [RFC 2397](https://www.rfc-editor.org/rfc/rfc2397)
+/// `data:` URLs, such as `data:application/ld+json;base64,eyJAY29udGV4dCI6e319`
+/// or `data:application/json,%7B%22@context%22:%7B%7D%7D`.
+///
+/// The media type, if any, must be a JSON media type (`application/json`,
+/// `application/ld+json` or any `+json` type). Other IRIs are rejected with
+/// [`Error::NotDataUrl`], so this loader can be combined with other loaders
+/// using a [`ChainLoader`](super::ChainLoader).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DataLoader;
+
+impl DataLoader {
+	/// Creates a new `data:` URL loader.
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+/// Decoded `data:` URL.
+struct DataUrl {
+	media_type: Option<Mime>,
+	data: Vec<u8>,
+}
+
+impl DataUrl {
+	fn parse(url: &str) -> Result<Self, Error> {
+		let url = match url.get(..5) {
+			Some(scheme) if scheme.eq_ignore_ascii_case("data:") => &url[5..],
+			_ => return Err(Error::NotDataUrl),
+		};
+
+		let (header, data) = url.split_once(',').ok_or(Error::MissingSeparator)?;
+		let header = String::from_utf8_lossy(&percent_decode(header)).into_owned();
+
+		let split = header.len().saturating_sub(";base64".len());
+		let (media_type, base64) = match header.get(split..) {
+			Some(suffix) if suffix.eq_ignore_ascii_case(";base64") => (&header[..split], true),
+			_ => (header.as_str(), false),
+		};
+
+		let media_type = if media_type.is_empty() || media_type.starts_with(';') {
+			None
+		} else {
+			let mime: Mime = media_type
+				.parse()
+				.map_err(|_| Error::InvalidContentType(media_type.to_owned()))?;
+
+			let is_json = mime.type_() == mime::APPLICATION
+				&& (mime.subtype() == mime::JSON || mime.suffix() == Some(mime::JSON));
+
+			if !is_json {
+				return Err(Error::InvalidContentType(media_type.to_owned()));
+			}
+
+			Some(mime)
+		};
+
+		let data = percent_decode(data);
+		let data = if base64 {
+			let data: Vec<u8> = data
+				.into_iter()
+				.filter(|b| !b.is_ascii_whitespace())
+				.collect();
+			base64::engine::general_purpose::STANDARD
+				.decode(data)
+				.map_err(Error::Base64)?
+		} else {
+			data
+		};
+
+		Ok(Self { media_type, data })
+	}
+}
+
+/// Decodes the percent-encoded bytes of the given string.
+///
+/// Invalid percent-encoded sequences are left as is.
+pub(crate) fn percent_decode(s: &str) -> Vec<u8> {
+	let bytes = s.as_bytes();
+	let mut result = Vec::with_capacity(bytes.len());
+
+	let mut i = 0;
+	while i < bytes.len() {
+		if bytes[i] == b'%' {
+			if let Some(b) = bytes
+				.get(i + 1..i + 3)
+				.filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+				.and_then(|hex| std::str::from_utf8(hex).ok())
+				.and_then(|hex| u8::from_str_radix(hex, 16).ok())
+			{
+				result.push(b);
+				i += 3;
+				continue;
+			}
+		}
+
+		result.push(bytes[i]);
+		i += 1
+	}
+
+	result
+}
+
+impl<I: Send> Loader<I> for DataLoader {
+	type Error = Error;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			let data_url = DataUrl::parse(vocabulary.iri(&url).unwrap().as_str())?;
+			let content =
+				std::str::from_utf8(&data_url.data).map_err(|_| Error::InvalidEncoding)?;
+			let (doc, _) = json_syntax::Value::parse_str(content).map_err(Error::Parse)?;
+
+			Ok(RemoteDocument::new(
+				Some(url),
+				Some(data_url.media_type.unwrap_or(mime::APPLICATION_JSON)),
+				doc,
+			))
+		}
+		.boxed()
+	}
+}
@@ -0,0 +1,200 @@
+use super::{ContextLoadError, Loader, RemoteContext};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use iref::Iri;
+use rdf_types::{vocabulary::IriIndex, IriVocabulary, IriVocabularyMut};
+use std::fmt;
+
+/// Boxed loading error.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Type-erased loading error.
+#[derive(Debug)]
+pub struct Error(BoxError);
+
+impl Error {
+	/// Wraps the given loading error.
+	pub fn new(error: impl 'static + std::error::Error + Send + Sync) -> Self {
+		Self(Box::new(error))
+	}
+
+	/// Returns the inner error.
+	pub fn into_inner(self) -> BoxError {
+		self.0
+	}
+
+	/// Attempts to downcast the inner error to a concrete error type.
+	pub fn downcast_ref<E: 'static + std::error::Error>(&self) -> Option<&E> {
+		self.0.downcast_ref()
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		self.0.source()
+	}
+}
+
+/// Object-safe document loader.
+///
+/// Companion trait of [`Loader`] where the vocabulary type `V` is a
+/// parameter of the trait rather than of the loading method, so that it can
+/// be used as a `dyn DynLoader<I, V>` trait object. It is implemented for
+/// every [`Loader`] whose error type implements [`std::error::Error`].
+///
+/// Use [`BoxedLoader`] to get back a [`Loader`] from a trait object.
+pub trait DynLoader<I, V>: Send {
+	/// Loads the document behind the given IRI, using the given vocabulary.
+	fn load_dyn<'a>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Error>>
+	where
+		I: 'a;
+
+	/// Loads the local context defined by the document behind the given IRI,
+	/// using the given vocabulary.
+	///
+	/// See [`Loader::load_context_with`].
+	fn load_context_dyn<'a>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Error>>>
+	where
+		I: 'a;
+}
+
+impl<I, V, L> DynLoader<I, V> for L
+where
+	L: Loader<I> + Send,
+	L::Error: 'static + std::error::Error + Send + Sync,
+	V: IriVocabularyMut<Iri = I> + Send + Sync,
+	I: Send,
+{
+	fn load_dyn<'a>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Error>>
+	where
+		I: 'a,
+	{
+		async move { self.load_with(vocabulary, url).await.map_err(Error::new) }.boxed()
+	}
+
+	fn load_context_dyn<'a>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Error>>>
+	where
+		I: 'a,
+	{
+		async move {
+			self.load_context_with(vocabulary, url)
+				.await
+				.map_err(|e| e.map(Error::new))
+		}
+		.boxed()
+	}
+}
+
+/// Type-erased IRI vocabulary.
+///
+/// Vocabulary type used by [`BoxedLoader`] to call its inner
+/// [`DynLoader`] with any vocabulary.
+pub struct DynVocabulary<'v, I>(&'v mut (dyn IriVocabularyMut<Iri = I> + Send + Sync + 'v));
+
+impl<'v, I> DynVocabulary<'v, I> {
+	/// Erases the type of the given vocabulary.
+	pub fn new(vocabulary: &'v mut (impl IriVocabularyMut<Iri = I> + Send + Sync + 'v)) -> Self {
+		Self(vocabulary)
+	}
+}
+
+impl<'v, I> IriVocabulary for DynVocabulary<'v, I> {
+	type Iri = I;
+
+	fn iri<'i>(&'i self, id: &'i I) -> Option<&'i Iri> {
+		self.0.iri(id)
+	}
+
+	fn get(&self, iri: &Iri) -> Option<I> {
+		self.0.get(iri)
+	}
+}
+
+impl<'v, I> IriVocabularyMut for DynVocabulary<'v, I> {
+	fn insert(&mut self, iri: &Iri) -> I {
+		self.0.insert(iri)
+	}
+}
+
+/// Boxed dynamic loader.
+///
+/// Wraps any loader behind a trait object, so that loaders can be selected at
+/// runtime (for instance from a configuration file) without carrying their
+/// type around. The boxed loader itself implements [`Loader`], with a
+/// type-erased [`Error`].
+///
+/// Boxed loaders must be `Send` and `Sync`, as required by the
+/// [`JsonLdProcessor`](https://docs.rs/json-ld) methods.
+pub struct BoxedLoader<I = IriIndex>(Box<dyn for<'v> DynLoader<I, DynVocabulary<'v, I>> + Sync>);
+
+impl<I> BoxedLoader<I> {
+	/// Boxes the given loader.
+	pub fn new<L>(loader: L) -> Self
+	where
+		L: 'static + Sync + for<'v> DynLoader<I, DynVocabulary<'v, I>>,
+	{
+		Self(Box::new(loader))
+	}
+}
+
+impl<I: Send> Loader<I> for BoxedLoader<I> {
+	type Error = Error;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			let mut vocabulary = DynVocabulary::new(vocabulary);
+			self.0.load_dyn(&mut vocabulary, url).await
+		}
+		.boxed()
+	}
+
+	fn load_context_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Error>>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			let mut vocabulary = DynVocabulary::new(vocabulary);
+			self.0.load_context_dyn(&mut vocabulary, url).await
+		}
+		.boxed()
+	}
+}
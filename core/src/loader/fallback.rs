@@ -0,0 +1,294 @@
+use super::{ContextLoadError, Loader, RemoteContext};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use contextual::{DisplayWithContext, WithContext};
+use rdf_types::IriVocabularyMut;
+use std::fmt;
+
+/// Failed loading attempt.
+#[derive(Debug)]
+pub struct Attempt<E> {
+	/// Position of the loader in the fallback list.
+	pub index: usize,
+
+	/// Type name of the loader.
+	pub loader: &'static str,
+
+	/// Loading error.
+	pub error: E,
+}
+
+impl<E> Attempt<E> {
+	fn new<L>(index: usize, error: E) -> Self {
+		Self {
+			index,
+			loader: std::any::type_name::<L>(),
+			error,
+		}
+	}
+}
+
+impl<E: fmt::Display> fmt::Display for Attempt<E> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "[{}] `{}`: {}", self.index, self.loader, self.error)
+	}
+}
+
+impl<E: DisplayWithContext<N>, N> DisplayWithContext<N> for Attempt<E> {
+	fn fmt_with(&self, vocabulary: &N, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"[{}] `{}`: {}",
+			self.index,
+			self.loader,
+			self.error.with(vocabulary)
+		)
+	}
+}
+
+/// Error returned when every loader failed.
+///
+/// Lists every attempt in order, with the loader that was tried and why it
+/// failed: a `Vec` of [`Attempt`]s for a `Vec` of loaders, and a tuple of
+/// [`Attempt`]s for a tuple of loaders.
+///
+/// Errors whose message depends on a vocabulary (such as errors reporting
+/// an [`IriIndex`](rdf_types::vocabulary::IriIndex)) are displayed with
+/// [`DisplayWithContext`].
+#[derive(Debug)]
+pub struct Error<A>(A);
+
+impl<A> Error<A> {
+	/// Returns the failed attempts, in order.
+	pub fn attempts(&self) -> &A {
+		&self.0
+	}
+
+	/// Turns this error into the list of failed attempts.
+	pub fn into_attempts(self) -> A {
+		self.0
+	}
+}
+
+fn fmt_attempts(f: &mut fmt::Formatter, attempts: &[&dyn fmt::Display]) -> fmt::Result {
+	if attempts.is_empty() {
+		return write!(f, "no loader to try");
+	}
+
+	write!(f, "all loaders failed: ")?;
+	for (i, attempt) in attempts.iter().enumerate() {
+		if i > 0 {
+			write!(f, "; ")?;
+		}
+
+		attempt.fmt(f)?;
+	}
+
+	Ok(())
+}
+
+impl<E: fmt::Display> fmt::Display for Error<Vec<Attempt<E>>> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let attempts: Vec<_> = self.0.iter().map(|a| a as &dyn fmt::Display).collect();
+		fmt_attempts(f, &attempts)
+	}
+}
+
+impl<E: DisplayWithContext<N>, N> DisplayWithContext<N> for Error<Vec<Attempt<E>>> {
+	fn fmt_with(&self, vocabulary: &N, f: &mut fmt::Formatter) -> fmt::Result {
+		let attempts: Vec<_> = self.0.iter().map(|a| a.with(vocabulary)).collect();
+		let attempts: Vec<_> = attempts.iter().map(|a| a as &dyn fmt::Display).collect();
+		fmt_attempts(f, &attempts)
+	}
+}
+
+impl<E: std::error::Error> std::error::Error for Error<Vec<Attempt<E>>> {}
+
+/// Fallback loader.
+///
+/// Tries each loader in order, until one of them succeeds.
+/// If every loader fails, the returned [`Error`] lists the error of each
+/// attempt.
+///
+/// Loaders are given either as a tuple (of up to 8 loaders of any type), or
+/// as a `Vec` of loaders of the same type. Unlike the
+/// [`ChainLoader`](super::ChainLoader), any number of loaders can be combined
+/// without nesting.
+pub struct FallbackLoader<T>(T);
+
+impl<T> FallbackLoader<T> {
+	/// Creates a new fallback loader.
+	pub fn new(loaders: T) -> Self {
+		Self(loaders)
+	}
+
+	/// Returns a reference to the inner loaders.
+	pub fn loaders(&self) -> &T {
+		&self.0
+	}
+
+	/// Returns a mutable reference to the inner loaders.
+	pub fn loaders_mut(&mut self) -> &mut T {
+		&mut self.0
+	}
+
+	/// Returns the inner loaders.
+	pub fn into_loaders(self) -> T {
+		self.0
+	}
+}
+
+impl<I, L> Loader<I> for FallbackLoader<Vec<L>>
+where
+	I: Clone + Send + Sync,
+	L: Loader<I> + Send,
+	L::Error: Send,
+{
+	type Error = Error<Vec<Attempt<L::Error>>>;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Self::Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			let mut attempts = Vec::new();
+
+			for (i, loader) in self.0.iter_mut().enumerate() {
+				match loader.load_with(vocabulary, url.clone()).await {
+					Ok(doc) => return Ok(doc),
+					Err(e) => attempts.push(Attempt::new::<L>(i, e)),
+				}
+			}
+
+			Err(Error(attempts))
+		}
+		.boxed()
+	}
+
+	fn load_context_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Self::Error>>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			let mut attempts = Vec::new();
+
+			for (i, loader) in self.0.iter_mut().enumerate() {
+				match loader.load_context_with(vocabulary, url.clone()).await {
+					Err(ContextLoadError::LoadingDocumentFailed(e)) => {
+						attempts.push(Attempt::new::<L>(i, e))
+					}
+					Err(ContextLoadError::ContextExtractionFailed(e)) => {
+						return Err(ContextLoadError::ContextExtractionFailed(e))
+					}
+					Ok(context) => return Ok(context),
+				}
+			}
+
+			Err(ContextLoadError::LoadingDocumentFailed(Error(attempts)))
+		}
+		.boxed()
+	}
+}
+
+macro_rules! fallback_tuple {
+	($($l:ident: $n:tt),*) => {
+		impl<I, $($l),*> Loader<I> for FallbackLoader<($($l,)*)>
+		where
+			I: Clone + Send + Sync,
+			$(
+				$l: Loader<I> + Send,
+				<$l as Loader<I>>::Error: Send,
+			)*
+		{
+			type Error = Error<($(Attempt<<$l as Loader<I>>::Error>,)*)>;
+
+			fn load_with<'a, V>(
+				&'a mut self,
+				vocabulary: &'a mut V,
+				url: I,
+			) -> BoxFuture<'a, LoadingResult<I, Self::Error>>
+			where
+				V: IriVocabularyMut<Iri = I>,
+				//
+				V: Send + Sync,
+				I: 'a + Send,
+			{
+				async move {
+					Err(Error(($(
+						match (self.0).$n.load_with(vocabulary, url.clone()).await {
+							Ok(doc) => return Ok(doc),
+							Err(e) => Attempt::new::<$l>($n, e),
+						},
+					)*)))
+				}
+				.boxed()
+			}
+
+			fn load_context_with<'a, V>(
+				&'a mut self,
+				vocabulary: &'a mut V,
+				url: I,
+			) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Self::Error>>>
+			where
+				V: IriVocabularyMut<Iri = I>,
+				//
+				V: Send + Sync,
+				I: 'a + Send,
+			{
+				async move {
+					Err(ContextLoadError::LoadingDocumentFailed(Error(($(
+						match (self.0).$n.load_context_with(vocabulary, url.clone()).await {
+							Err(ContextLoadError::LoadingDocumentFailed(e)) => {
+								Attempt::new::<$l>($n, e)
+							}
+							Err(ContextLoadError::ContextExtractionFailed(e)) => {
+								return Err(ContextLoadError::ContextExtractionFailed(e))
+							}
+							Ok(context) => return Ok(context),
+						},
+					)*))))
+				}
+				.boxed()
+			}
+		}
+
+		impl<$($l: fmt::Display),*> fmt::Display for Error<($(Attempt<$l>,)*)> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt_attempts(f, &[$(&(self.0).$n as &dyn fmt::Display),*])
+			}
+		}
+
+		impl<N, $($l: DisplayWithContext<N>),*> DisplayWithContext<N>
+			for Error<($(Attempt<$l>,)*)>
+		{
+			fn fmt_with(&self, vocabulary: &N, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt_attempts(f, &[$(&(self.0).$n.with(vocabulary) as &dyn fmt::Display),*])
+			}
+		}
+
+		impl<$($l: std::error::Error),*> std::error::Error for Error<($(Attempt<$l>,)*)> {}
+	};
+}
+
+fallback_tuple!(L0: 0);
+fallback_tuple!(L0: 0, L1: 1);
+fallback_tuple!(L0: 0, L1: 1, L2: 2);
+fallback_tuple!(L0: 0, L1: 1, L2: 2, L3: 3);
+fallback_tuple!(L0: 0, L1: 1, L2: 2, L3: 3, L4: 4);
+fallback_tuple!(L0: 0, L1: 1, L2: 2, L3: 3, L4: 4, L5: 5);
+fallback_tuple!(L0: 0, L1: 1, L2: 2, L3: 3, L4: 4, L5: 5, L6: 6);
+fallback_tuple!(L0: 0, L1: 1, L2: 2, L3: 3, L4: 4, L5: 5, L6: 6, L7: 7);
@@ -0,0 +1,149 @@
+use super::{data::percent_decode, Loader, RemoteDocument};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use iref::Iri;
+use json_syntax::Parse;
+use rdf_types::IriVocabularyMut;
+use std::path::{Path, PathBuf};
+
+/// Loading error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The IRI is not a `file:` URL.
+	#[error("not a `file:` URL")]
+	NotFileUrl,
+
+	/// The URL refers to a file on another host.
+	#[error("remote host `{0}`")]
+	RemoteHost(String),
+
+	/// The URL path cannot be converted into a local file path.
+	#[error("invalid file path")]
+	InvalidPath,
+
+	/// The file is outside of the root directory.
+	#[error("`{0}` is outside of the root directory")]
+	OutsideRoot(PathBuf),
+
+	/// IO error.
+	#[error(transparent)]
+	IO(std::io::Error),
+
+	/// Parse error.
+	#[error("parse error: {0}")]
+	Parse(json_syntax::parse::Error),
+}
+
+/// `file:` URL loader.
+///
+/// Loads documents from the local file system, directly from their `file:`
+/// URL (e.g. `file:///etc/json-ld/context.jsonld`), without requiring any
+/// mount point unlike the [`FsLoader`](super::FsLoader).
+///
+/// The loader can be restricted to a root directory with
+/// [`FileLoader::with_root`]: files outside of this directory, including
+/// through symbolic links, are rejected with [`Error::OutsideRoot`].
+///
+/// Other IRIs are rejected with [`Error::NotFileUrl`], so this loader can be
+/// combined with other loaders using a [`ChainLoader`](super::ChainLoader).
+#[derive(Debug, Default, Clone)]
+pub struct FileLoader {
+	root: Option<PathBuf>,
+}
+
+impl FileLoader {
+	/// Creates a new loader with access to the whole file system.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a new loader only allowed to load files inside the given
+	/// root directory.
+	pub fn with_root(root: impl Into<PathBuf>) -> Self {
+		Self {
+			root: Some(root.into()),
+		}
+	}
+
+	/// Returns the root directory, if any.
+	pub fn root(&self) -> Option<&Path> {
+		self.root.as_deref()
+	}
+
+	/// Returns the local file path of the given `file:` URL.
+	///
+	/// The root directory is not checked.
+	pub fn filepath(url: &Iri) -> Result<PathBuf, Error> {
+		if !url.scheme().as_str().eq_ignore_ascii_case("file") {
+			return Err(Error::NotFileUrl);
+		}
+
+		if let Some(authority) = url.authority() {
+			let host = authority.host().as_str();
+			if !host.is_empty() && !host.eq_ignore_ascii_case("localhost") {
+				return Err(Error::RemoteHost(host.to_owned()));
+			}
+		}
+
+		let mut path = PathBuf::from("/");
+		for segment in url.path().segments() {
+			let segment = String::from_utf8(percent_decode(segment.as_str()))
+				.map_err(|_| Error::InvalidPath)?;
+
+			if segment.contains(['/', '\\', '\0']) {
+				return Err(Error::InvalidPath);
+			}
+
+			path.push(segment)
+		}
+
+		Ok(path)
+	}
+
+	/// Checks that the given file is inside the root directory, and returns
+	/// its canonical path.
+	fn jail(&self, path: PathBuf) -> Result<PathBuf, Error> {
+		match &self.root {
+			Some(root) => {
+				let root = root.canonicalize().map_err(Error::IO)?;
+				let canonical = path.canonicalize().map_err(Error::IO)?;
+
+				if canonical.starts_with(root) {
+					Ok(canonical)
+				} else {
+					Err(Error::OutsideRoot(path))
+				}
+			}
+			None => Ok(path),
+		}
+	}
+}
+
+impl<I: Send> Loader<I> for FileLoader {
+	type Error = Error;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			let path = self.jail(Self::filepath(vocabulary.iri(&url).unwrap())?)?;
+			let contents = std::fs::read_to_string(path).map_err(Error::IO)?;
+			let (doc, _) = json_syntax::Value::parse_str(&contents).map_err(Error::Parse)?;
+
+			Ok(RemoteDocument::new(
+				Some(url),
+				Some("application/ld+json".parse().unwrap()),
+				doc,
+			))
+		}
+		.boxed()
+	}
+}
@@ -1,8 +1,9 @@
-use super::{Loader, RemoteDocument};
+use super::{html, Loader, RemoteDocument};
 use crate::future::{BoxFuture, FutureExt};
 use crate::LoadingResult;
 use json_syntax::Parse;
-use rdf_types::{vocabulary::IriIndex, IriVocabulary};
+use mime::Mime;
+use rdf_types::{vocabulary::IriIndex, IriVocabulary, IriVocabularyMut};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, Read};
@@ -22,6 +23,10 @@ pub enum Error {
 	/// Parse error.
 	#[error("parse error: {0}")]
 	Parse(json_syntax::parse::Error),
+
+	/// HTML extraction error.
+	#[error(transparent)]
+	Html(html::Error),
 }
 
 /// File-system loader.
@@ -29,10 +34,23 @@ pub enum Error {
 /// This is a special JSON-LD document loader that can load document from the file system by
 /// attaching a directory to specific URLs.
 ///
+/// When an IRI matches several mount points, the longest IRI prefix is used.
+/// If the mapped file does not exist, the loader probes the configured
+/// extensions (`.jsonld` and `.json` by default), so that
+/// `https://example.org/ctx` can be loaded from `ctx.jsonld`. Directories are
+/// resolved to their index file (`index.jsonld` or `index.json` by default).
+/// The content type of the loaded document is inferred from the file
+/// extension.
+///
 /// Loaded documents are not cached: a new file system read is made each time
 /// an URL is loaded even if it has already been queried before.
+/// Wrap the loader in a [`CachingLoader`](super::CachingLoader) to memoize
+/// loaded documents.
 pub struct FsLoader<I = IriIndex> {
 	mount_points: HashMap<PathBuf, I>,
+	html: Option<html::Options>,
+	extensions: Vec<String>,
+	index_files: Vec<String>,
 }
 
 impl<I> FsLoader<I> {
@@ -45,24 +63,88 @@ impl<I> FsLoader<I> {
 		self.mount_points.insert(path.as_ref().into(), url);
 	}
 
+	/// Sets the HTML extraction options.
+	///
+	/// If set, files with an `html`, `htm` or `xhtml` extension are loaded as
+	/// HTML documents and their embedded JSON-LD content is extracted.
+	/// Otherwise every file is parsed as JSON.
+	pub fn set_html_options(&mut self, options: Option<html::Options>) {
+		self.html = options
+	}
+
+	/// Sets the extensions probed, in order, when the file mapped to an IRI
+	/// does not exist.
+	///
+	/// Extensions are given without the leading dot.
+	/// Defaults to `jsonld` and `json`.
+	pub fn set_extensions<E: Into<String>>(&mut self, extensions: impl IntoIterator<Item = E>) {
+		self.extensions = extensions.into_iter().map(Into::into).collect()
+	}
+
+	/// Sets the index files probed, in order, when an IRI is mapped to a
+	/// directory.
+	///
+	/// Defaults to `index.jsonld` and `index.json`.
+	pub fn set_index_files<F: Into<String>>(&mut self, index_files: impl IntoIterator<Item = F>) {
+		self.index_files = index_files.into_iter().map(Into::into).collect()
+	}
+
 	/// Returns the local file path associated to the given `url` if any.
+	///
+	/// The mount point with the longest matching IRI prefix is used.
+	/// The returned path is not resolved: see [`Self::resolve`].
 	pub fn filepath(&self, vocabulary: &impl IriVocabulary<Iri = I>, url: &I) -> Option<PathBuf> {
 		let url = vocabulary.iri(url).unwrap();
+		let mut result: Option<(usize, PathBuf)> = None;
+
 		for (path, target_url) in &self.mount_points {
-			if let Some((suffix, _, _)) = url
-				.as_iri_ref()
-				.suffix(vocabulary.iri(target_url).unwrap().as_iri_ref())
-			{
+			let target_url = vocabulary.iri(target_url).unwrap();
+			let prefix_len = target_url.as_str().len();
+
+			if matches!(result, Some((len, _)) if len >= prefix_len) {
+				continue;
+			}
+
+			if let Some((suffix, _, _)) = url.as_iri_ref().suffix(target_url.as_iri_ref()) {
 				let mut filepath = path.clone();
 				for seg in suffix.as_path().segments() {
 					filepath.push(seg.as_str())
 				}
 
-				return Some(filepath);
+				result = Some((prefix_len, filepath))
 			}
 		}
 
-		None
+		result.map(|(_, filepath)| filepath)
+	}
+
+	/// Resolves the given local file path to the file to load.
+	///
+	/// Directories are resolved to their first existing index file.
+	/// Missing files are resolved by appending the first extension for which
+	/// a file exists.
+	/// If nothing is found, the path is returned unchanged.
+	pub fn resolve(&self, path: PathBuf) -> PathBuf {
+		if path.is_dir() {
+			self.index_files
+				.iter()
+				.map(|index| path.join(index))
+				.find(|candidate| candidate.is_file())
+				.unwrap_or(path)
+		} else if path.is_file() {
+			path
+		} else {
+			self.extensions
+				.iter()
+				.map(|ext| {
+					let mut candidate = path.clone().into_os_string();
+					candidate.push(".");
+					candidate.push(ext);
+					PathBuf::from(candidate)
+				})
+				.find(|candidate| candidate.is_file())
+				.unwrap_or(path)
+		}
 	}
 }
 
@@ -75,7 +157,7 @@ impl<I: Send> Loader<I> for FsLoader<I> {
 		url: I,
 	) -> BoxFuture<'a, LoadingResult<I, Error>>
 	where
-		V: IriVocabulary<Iri = I>,
+		V: IriVocabularyMut<Iri = I>,
 		//
 		V: Send + Sync,
 		I: 'a + Send,
@@ -83,19 +165,32 @@ impl<I: Send> Loader<I> for FsLoader<I> {
 		async move {
 			match self.filepath(vocabulary, &url) {
 				Some(filepath) => {
-					let file = File::open(filepath).map_err(Error::IO)?;
+					let filepath = self.resolve(filepath);
+					let file = File::open(&filepath).map_err(Error::IO)?;
 					let mut buf_reader = BufReader::new(file);
 					let mut contents = String::new();
 					buf_reader
 						.read_to_string(&mut contents)
 						.map_err(Error::IO)?;
+
+					let content_type = content_type(&filepath);
+
+					if let Some(options) = &self.html {
+						if is_html(&filepath) {
+							return RemoteDocument::from_html_with(
+								vocabulary,
+								Some(url),
+								Some(content_type),
+								&contents,
+								options,
+							)
+							.map_err(Error::Html);
+						}
+					}
+
 					let (doc, _) =
 						json_syntax::Value::parse_str(&contents).map_err(Error::Parse)?;
-					Ok(RemoteDocument::new(
-						Some(url),
-						Some("application/ld+json".parse().unwrap()),
-						doc,
-					))
+					Ok(RemoteDocument::new(Some(url), Some(content_type), doc))
 				}
 				None => Err(Error::NoMountPoint),
 			}
@@ -108,6 +203,9 @@ impl<I> Default for FsLoader<I> {
 	fn default() -> Self {
 		Self {
 			mount_points: HashMap::new(),
+			html: None,
+			extensions: vec!["jsonld".to_string(), "json".to_string()],
+			index_files: vec!["index.jsonld".to_string(), "index.json".to_string()],
 		}
 	}
 }
@@ -118,3 +216,22 @@ impl<I> FsLoader<I> {
 		Self::default()
 	}
 }
+
+fn is_html(path: &Path) -> bool {
+	matches!(
+		path.extension().and_then(|e| e.to_str()),
+		Some("html" | "htm" | "xhtml")
+	)
+}
+
+/// Infers the content type of the given file from its extension.
+///
+/// Defaults to `application/ld+json`.
+pub(super) fn content_type(path: &Path) -> Mime {
+	match path.extension().and_then(|e| e.to_str()) {
+		Some("json") => mime::APPLICATION_JSON,
+		Some("html" | "htm") => mime::TEXT_HTML,
+		Some("xhtml") => "application/xhtml+xml".parse().unwrap(),
+		_ => "application/ld+json".parse().unwrap(),
+	}
+}
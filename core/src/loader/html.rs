@@ -0,0 +1,414 @@
+//! JSON-LD embedded in HTML documents.
+//!
+//! Implements the [Processing JSON-LD embedded in HTML][1] rules of the
+//! JSON-LD 1.1 API: JSON-LD content is extracted from the
+//! `<script type="application/ld+json">` elements of an HTML document.
+//!
+//! [1]: https://www.w3.org/TR/json-ld11-api/#process-html
+use iref::{Iri, IriBuf, IriRef};
+use json_ld_syntax::ErrorCode;
+use json_syntax::Parse;
+
+/// JSON-LD script element media type.
+const JSON_LD_MEDIA_TYPE: &str = "application/ld+json";
+
+/// HTML extraction options.
+#[derive(Debug, Default, Clone)]
+pub struct Options {
+	/// Extract every JSON-LD script element of the document instead of only
+	/// the first one.
+	///
+	/// The content of each script is combined into a single array.
+	/// This option is ignored when the document URL has a fragment
+	/// identifier.
+	pub extract_all_scripts: bool,
+
+	/// Preferred script profile.
+	///
+	/// Script elements whose `type` attribute has a `profile` parameter
+	/// matching this IRI are selected before any other JSON-LD script.
+	pub profile: Option<IriBuf>,
+}
+
+/// HTML extraction error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The document has no JSON-LD script element.
+	#[error("no JSON-LD script element found")]
+	NoScript,
+
+	/// No script element with the fragment identifier of the document URL.
+	#[error("no script element with id `{0}`")]
+	ScriptNotFound(String),
+
+	/// The script element designated by the fragment identifier is not a
+	/// JSON-LD script element.
+	#[error("script element `{0}` is not a JSON-LD script")]
+	InvalidScriptType(String),
+
+	/// The content of a script element is not valid JSON.
+	#[error("invalid script element: {0}")]
+	InvalidScriptElement(json_syntax::parse::Error),
+}
+
+impl Error {
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::NoScript | Self::ScriptNotFound(_) | Self::InvalidScriptType(_) => {
+				ErrorCode::LoadingDocumentFailed
+			}
+			Self::InvalidScriptElement(_) => ErrorCode::InvalidScriptElement,
+		}
+	}
+}
+
+/// JSON-LD content extracted from an HTML document.
+#[derive(Debug, Clone)]
+pub struct Extracted {
+	/// Base IRI of the document.
+	///
+	/// This is the `href` attribute of the first `<base>` element of the
+	/// document resolved against the document URL, or the document URL
+	/// itself if there is no such element.
+	pub base_url: Option<IriBuf>,
+
+	/// Extracted JSON-LD document.
+	pub document: json_syntax::Value,
+}
+
+/// Extracts the JSON-LD content of the given HTML document.
+///
+/// The `url` is the document URL. If it has a fragment identifier, the
+/// script element with the matching `id` attribute is selected.
+pub fn extract(content: &str, url: Option<&Iri>, options: &Options) -> Result<Extracted, Error> {
+	let html = Html::scan(content);
+
+	let base_url = match html.base_href {
+		Some(href) => match url {
+			Some(url) => IriRef::new(&href)
+				.ok()
+				.map(|r| r.resolved(url))
+				.or_else(|| Some(url.to_owned())),
+			None => Iri::new(&href).ok().map(Iri::to_owned),
+		},
+		None => url.map(Iri::to_owned),
+	};
+
+	let fragment = url.and_then(|url| url.fragment()).map(|f| f.as_str());
+
+	let document = match fragment {
+		Some(id) => {
+			let script = html
+				.scripts
+				.iter()
+				.find(|s| s.id.as_deref() == Some(id))
+				.ok_or_else(|| Error::ScriptNotFound(id.to_owned()))?;
+
+			if !script.is_json_ld() {
+				return Err(Error::InvalidScriptType(id.to_owned()));
+			}
+
+			script.parse()?
+		}
+		None => {
+			if options.extract_all_scripts {
+				let mut items = Vec::new();
+
+				for script in html.scripts.iter().filter(|s| s.is_json_ld()) {
+					match script.parse()? {
+						json_syntax::Value::Array(array) => items.extend(array),
+						value => items.push(value),
+					}
+				}
+
+				json_syntax::Value::Array(items)
+			} else {
+				let script = options
+					.profile
+					.as_ref()
+					.and_then(|profile| {
+						html.scripts
+							.iter()
+							.find(|s| s.is_json_ld() && s.has_profile(profile.as_str()))
+					})
+					.or_else(|| html.scripts.iter().find(|s| s.is_json_ld()))
+					.ok_or(Error::NoScript)?;
+
+				script.parse()?
+			}
+		}
+	};
+
+	Ok(Extracted { base_url, document })
+}
+
+/// Script element.
+struct Script<'a> {
+	id: Option<String>,
+	ty: Option<String>,
+	content: &'a str,
+}
+
+impl Script<'_> {
+	/// Returns the media type and parameters of the `type` attribute.
+	fn media_type(&self) -> Option<(&str, impl Iterator<Item = (&str, &str)>)> {
+		let ty = self.ty.as_deref()?;
+		let mut parts = ty.split(';');
+		let media_type = parts.next().unwrap_or_default().trim();
+		let params = parts.filter_map(|p| {
+			let (key, value) = p.split_once('=')?;
+			Some((key.trim(), value.trim().trim_matches('"')))
+		});
+
+		Some((media_type, params))
+	}
+
+	fn is_json_ld(&self) -> bool {
+		self.media_type()
+			.map(|(m, _)| m.eq_ignore_ascii_case(JSON_LD_MEDIA_TYPE))
+			.unwrap_or(false)
+	}
+
+	fn has_profile(&self, profile: &str) -> bool {
+		self.media_type()
+			.map(|(_, mut params)| {
+				params.any(|(key, value)| {
+					key.eq_ignore_ascii_case("profile")
+						&& value.split_whitespace().any(|p| p == profile)
+				})
+			})
+			.unwrap_or(false)
+	}
+
+	fn parse(&self) -> Result<json_syntax::Value, Error> {
+		json_syntax::Value::parse_str(self.content.trim())
+			.map(|(value, _)| value)
+			.map_err(Error::InvalidScriptElement)
+	}
+}
+
+/// Relevant parts of an HTML document.
+#[derive(Default)]
+struct Html<'a> {
+	base_href: Option<String>,
+	scripts: Vec<Script<'a>>,
+}
+
+impl<'a> Html<'a> {
+	/// Scans the given HTML document for `<base>` and `<script>` elements.
+	fn scan(content: &'a str) -> Self {
+		let mut result = Self::default();
+		let bytes = content.as_bytes();
+		let mut i = 0;
+
+		while let Some(offset) = content[i..].find('<') {
+			i += offset;
+
+			if content[i..].starts_with("<!--") {
+				i = match content[i + 4..].find("-->") {
+					Some(end) => i + 4 + end + 3,
+					None => content.len(),
+				};
+				continue;
+			}
+
+			let closing = bytes.get(i + 1) == Some(&b'/');
+			let name_start = if closing { i + 2 } else { i + 1 };
+
+			if !bytes.get(name_start).map_or(false, u8::is_ascii_alphabetic) {
+				i += 1;
+				continue;
+			}
+
+			let name_end = bytes[name_start..]
+				.iter()
+				.position(|b| b.is_ascii_whitespace() || *b == b'>' || *b == b'/')
+				.map_or(content.len(), |p| name_start + p);
+			let name = content[name_start..name_end].to_ascii_lowercase();
+			let (attributes, end) = parse_attributes(content, name_end);
+			i = end;
+
+			if closing {
+				continue;
+			}
+
+			match name.as_str() {
+				"base" => {
+					if result.base_href.is_none() {
+						result.base_href = attribute(&attributes, "href").map(str::to_owned);
+					}
+				}
+				"script" | "style" => {
+					let text_end = find_end_tag(content, i, &name).unwrap_or(content.len());
+
+					if name == "script" {
+						result.scripts.push(Script {
+							id: attribute(&attributes, "id").map(str::to_owned),
+							ty: attribute(&attributes, "type").map(str::to_owned),
+							content: &content[i..text_end],
+						})
+					}
+
+					i = text_end;
+				}
+				_ => (),
+			}
+		}
+
+		result
+	}
+}
+
+fn attribute<'s>(attributes: &'s [(String, String)], name: &str) -> Option<&'s str> {
+	attributes
+		.iter()
+		.find(|(key, _)| key == name)
+		.map(|(_, value)| value.as_str())
+}
+
+/// Parses the attributes of a tag, starting at `i`.
+///
+/// Returns the attributes along with the position following the end of the
+/// tag.
+fn parse_attributes(content: &str, mut i: usize) -> (Vec<(String, String)>, usize) {
+	let bytes = content.as_bytes();
+	let mut attributes = Vec::new();
+
+	loop {
+		while bytes
+			.get(i)
+			.map_or(false, |b| b.is_ascii_whitespace() || *b == b'/')
+		{
+			i += 1
+		}
+
+		match bytes.get(i) {
+			None => break (attributes, i),
+			Some(b'>') => break (attributes, i + 1),
+			Some(_) => {
+				let key_start = i;
+				while bytes
+					.get(i)
+					.map_or(false, |b| !b.is_ascii_whitespace() && !b"=>/".contains(b))
+				{
+					i += 1
+				}
+				let key = content[key_start..i].to_ascii_lowercase();
+
+				while bytes.get(i).map_or(false, u8::is_ascii_whitespace) {
+					i += 1
+				}
+
+				let value = if bytes.get(i) == Some(&b'=') {
+					i += 1;
+					while bytes.get(i).map_or(false, u8::is_ascii_whitespace) {
+						i += 1
+					}
+
+					match bytes.get(i) {
+						Some(&quote) if quote == b'"' || quote == b'\'' => {
+							let value_start = i + 1;
+							let value_end = bytes[value_start..]
+								.iter()
+								.position(|b| *b == quote)
+								.map_or(content.len(), |p| value_start + p);
+							i = (value_end + 1).min(content.len());
+							decode_entities(&content[value_start..value_end])
+						}
+						_ => {
+							let value_start = i;
+							while bytes
+								.get(i)
+								.map_or(false, |b| !b.is_ascii_whitespace() && *b != b'>')
+							{
+								i += 1
+							}
+							decode_entities(&content[value_start..i])
+						}
+					}
+				} else {
+					String::new()
+				};
+
+				if key_start == i {
+					i += 1
+				} else {
+					attributes.push((key, value))
+				}
+			}
+		}
+	}
+}
+
+/// Decodes the character references of an attribute value.
+///
+/// Only numeric references and the most common named references are
+/// supported. Unknown references are kept as is.
+fn decode_entities(value: &str) -> String {
+	let mut result = String::with_capacity(value.len());
+	let mut rest = value;
+
+	while let Some(start) = rest.find('&') {
+		result.push_str(&rest[..start]);
+		rest = &rest[start..];
+
+		let decoded = rest.find(';').and_then(|end| {
+			let c = match &rest[1..end] {
+				"amp" => Some('&'),
+				"lt" => Some('<'),
+				"gt" => Some('>'),
+				"quot" => Some('"'),
+				"apos" => Some('\''),
+				name => match name.strip_prefix('#') {
+					Some(n) => match n.strip_prefix(['x', 'X']) {
+						Some(hex) => u32::from_str_radix(hex, 16).ok(),
+						None => n.parse().ok(),
+					}
+					.and_then(char::from_u32),
+					None => None,
+				},
+			}?;
+
+			Some((c, end + 1))
+		});
+
+		match decoded {
+			Some((c, len)) => {
+				result.push(c);
+				rest = &rest[len..]
+			}
+			None => {
+				result.push('&');
+				rest = &rest[1..]
+			}
+		}
+	}
+
+	result.push_str(rest);
+	result
+}
+
+/// Finds the first ASCII case-insensitive occurrence of `needle` in
+/// `content`, starting at `from`.
+fn find_ignore_case(content: &str, from: usize, needle: &str) -> Option<usize> {
+	content.as_bytes()[from..]
+		.windows(needle.len())
+		.position(|w| w.eq_ignore_ascii_case(needle.as_bytes()))
+		.map(|p| from + p)
+}
+
+/// Finds the end tag of the `name` element, starting at `from`.
+///
+/// The tag name must be followed by a whitespace, `/` or `>`, so that
+/// `</scripts>` does not end a `script` element.
+fn find_end_tag(content: &str, mut from: usize, name: &str) -> Option<usize> {
+	let needle = format!("</{name}");
+
+	loop {
+		let start = find_ignore_case(content, from, &needle)?;
+		match content.as_bytes().get(start + needle.len()) {
+			Some(b) if !b.is_ascii_whitespace() && *b != b'/' && *b != b'>' => from = start + 1,
+			_ => break Some(start),
+		}
+	}
+}
@@ -0,0 +1,154 @@
+use super::{Loader, RemoteDocument};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use contextual::{DisplayWithContext, WithContext};
+use rdf_types::IriVocabularyMut;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// Error raised when no document is registered for an IRI.
+#[derive(Debug, thiserror::Error)]
+#[error("no document registered for `{0}`")]
+pub struct NotFound<I>(pub I);
+
+impl<I: DisplayWithContext<N>, N> DisplayWithContext<N> for NotFound<I> {
+	fn fmt_with(&self, vocabulary: &N, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"no document registered for `{}`",
+			self.0.with(vocabulary)
+		)
+	}
+}
+
+/// In-memory loader.
+///
+/// Serves documents registered in memory, indexed by IRI. This is useful
+/// for tests and sandboxed environments where remote contexts must be
+/// provided without network or file system access.
+///
+/// Loading an IRI with no registered document fails with [`NotFound`].
+#[derive(Debug, Clone)]
+pub struct MapLoader<I> {
+	documents: HashMap<I, RemoteDocument<I>>,
+}
+
+impl<I> Default for MapLoader<I> {
+	fn default() -> Self {
+		Self {
+			documents: HashMap::new(),
+		}
+	}
+}
+
+impl<I> MapLoader<I> {
+	/// Creates a new empty loader.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of registered documents.
+	pub fn len(&self) -> usize {
+		self.documents.len()
+	}
+
+	/// Checks if no document is registered.
+	pub fn is_empty(&self) -> bool {
+		self.documents.is_empty()
+	}
+}
+
+impl<I: Clone + Eq + Hash> MapLoader<I> {
+	/// Registers the given JSON document for the given IRI, with the
+	/// `application/ld+json` content type.
+	///
+	/// Returns the document previously registered for this IRI, if any.
+	pub fn insert(&mut self, url: I, document: json_syntax::Value) -> Option<RemoteDocument<I>> {
+		let document = RemoteDocument::new(
+			Some(url.clone()),
+			Some("application/ld+json".parse().unwrap()),
+			document,
+		);
+
+		self.documents.insert(url, document)
+	}
+
+	/// Registers the given remote document under its own URL, preserving its
+	/// content type, context URL and profile.
+	///
+	/// Returns the document previously registered for this URL, if any, or
+	/// gives the document back if it has no URL. Use
+	/// [`Self::insert_remote_at`] to register it under an explicit IRI.
+	pub fn insert_remote(
+		&mut self,
+		document: RemoteDocument<I>,
+	) -> Result<Option<RemoteDocument<I>>, RemoteDocument<I>> {
+		match document.url().cloned() {
+			Some(url) => Ok(self.documents.insert(url, document)),
+			None => Err(document),
+		}
+	}
+
+	/// Registers the given remote document under the given IRI, preserving
+	/// its content type, context URL and profile.
+	///
+	/// Returns the document previously registered for this IRI, if any.
+	pub fn insert_remote_at(
+		&mut self,
+		url: I,
+		document: RemoteDocument<I>,
+	) -> Option<RemoteDocument<I>> {
+		self.documents.insert(url, document)
+	}
+
+	/// Returns the document registered for the given IRI, if any.
+	pub fn get(&self, url: &I) -> Option<&RemoteDocument<I>> {
+		self.documents.get(url)
+	}
+
+	/// Removes the document registered for the given IRI.
+	pub fn remove(&mut self, url: &I) -> Option<RemoteDocument<I>> {
+		self.documents.remove(url)
+	}
+}
+
+impl<I: Clone + Eq + Hash> FromIterator<(I, json_syntax::Value)> for MapLoader<I> {
+	fn from_iter<T: IntoIterator<Item = (I, json_syntax::Value)>>(iter: T) -> Self {
+		let mut result = Self::new();
+		result.extend(iter);
+		result
+	}
+}
+
+impl<I: Clone + Eq + Hash> Extend<(I, json_syntax::Value)> for MapLoader<I> {
+	fn extend<T: IntoIterator<Item = (I, json_syntax::Value)>>(&mut self, iter: T) {
+		for (url, document) in iter {
+			self.insert(url, document);
+		}
+	}
+}
+
+impl<I: Clone + Eq + Hash + Send + Sync> Loader<I> for MapLoader<I> {
+	type Error = NotFound<I>;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		_vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Self::Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			match self.documents.get(&url) {
+				Some(document) => Ok(document.clone()),
+				None => Err(NotFound(url)),
+			}
+		}
+		.boxed()
+	}
+}
@@ -0,0 +1,598 @@
+use super::{ContextLoadError, Loader, RemoteContext};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use contextual::{DisplayWithContext, WithContext};
+use iref::Iri;
+use rdf_types::{IriVocabulary, IriVocabularyMut};
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// Range of IP addresses, in CIDR notation (e.g. `10.0.0.0/8`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpRange {
+	addr: IpAddr,
+	prefix_len: u8,
+}
+
+impl IpRange {
+	/// Creates a new range from its first address and prefix length.
+	///
+	/// Returns `None` if the prefix length is larger than the address.
+	pub fn new(addr: IpAddr, prefix_len: u8) -> Option<Self> {
+		let max = match addr {
+			IpAddr::V4(_) => 32,
+			IpAddr::V6(_) => 128,
+		};
+
+		if prefix_len <= max {
+			Some(Self { addr, prefix_len })
+		} else {
+			None
+		}
+	}
+
+	/// Checks if the given address is in this range.
+	///
+	/// IPv4-mapped IPv6 addresses are considered to be in IPv4 ranges.
+	pub fn contains(&self, addr: IpAddr) -> bool {
+		let addr = match addr {
+			IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(addr),
+			addr => addr,
+		};
+
+		match (self.addr, addr) {
+			(IpAddr::V4(range), IpAddr::V4(addr)) => {
+				self.prefix_len == 0
+					|| (u32::from(range) ^ u32::from(addr)) >> (32 - self.prefix_len) == 0
+			}
+			(IpAddr::V6(range), IpAddr::V6(addr)) => {
+				self.prefix_len == 0
+					|| (u128::from(range) ^ u128::from(addr)) >> (128 - self.prefix_len) == 0
+			}
+			_ => false,
+		}
+	}
+}
+
+impl From<IpAddr> for IpRange {
+	fn from(addr: IpAddr) -> Self {
+		let prefix_len = match addr {
+			IpAddr::V4(_) => 32,
+			IpAddr::V6(_) => 128,
+		};
+
+		Self { addr, prefix_len }
+	}
+}
+
+/// Invalid IP range syntax.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid IP range `{0}`")]
+pub struct InvalidIpRange(pub String);
+
+impl FromStr for IpRange {
+	type Err = InvalidIpRange;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || InvalidIpRange(s.to_owned());
+		match s.split_once('/') {
+			Some((addr, prefix_len)) => {
+				let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+				let prefix_len: u8 = prefix_len.parse().map_err(|_| invalid())?;
+				Self::new(addr, prefix_len).ok_or_else(invalid)
+			}
+			None => s.parse::<IpAddr>().map(Self::from).map_err(|_| invalid()),
+		}
+	}
+}
+
+impl fmt::Display for IpRange {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}/{}", self.addr, self.prefix_len)
+	}
+}
+
+/// Loopback, private, link-local, unspecified, multicast and broadcast
+/// address ranges, along with the IPv6 ranges embedding IPv4 addresses.
+const PRIVATE_NETWORKS: [(IpAddr, u8); 15] = [
+	(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 8),
+	(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8),
+	(IpAddr::V4(Ipv4Addr::new(100, 64, 0, 0)), 10),
+	(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 0)), 8),
+	(IpAddr::V4(Ipv4Addr::new(169, 254, 0, 0)), 16),
+	(IpAddr::V4(Ipv4Addr::new(172, 16, 0, 0)), 12),
+	(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 0)), 16),
+	(IpAddr::V4(Ipv4Addr::new(224, 0, 0, 0)), 4),
+	(IpAddr::V4(Ipv4Addr::BROADCAST), 32),
+	// IPv4-compatible, including the unspecified and loopback addresses.
+	(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 96),
+	// NAT64.
+	(
+		IpAddr::V6(Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0, 0)),
+		96,
+	),
+	// 6to4.
+	(IpAddr::V6(Ipv6Addr::new(0x2002, 0, 0, 0, 0, 0, 0, 0)), 16),
+	(IpAddr::V6(Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 0)), 7),
+	(IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0)), 10),
+	(IpAddr::V6(Ipv6Addr::new(0xff00, 0, 0, 0, 0, 0, 0, 0)), 8),
+];
+
+/// Host name pattern.
+///
+/// Either an exact host name, or a domain suffix prefixed with `*.` matching
+/// any subdomain (e.g. `*.example.com` matches `www.example.com` but not
+/// `example.com`). Comparison is case-insensitive.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HostPattern(String);
+
+impl HostPattern {
+	/// Creates a new host pattern.
+	pub fn new(pattern: &str) -> Self {
+		Self(pattern.to_ascii_lowercase())
+	}
+
+	/// Checks if the given (lowercase) host matches this pattern.
+	fn matches(&self, host: &str) -> bool {
+		match self.0.strip_prefix("*.") {
+			Some(suffix) => host
+				.strip_suffix(suffix)
+				.map_or(false, |sub| sub.ends_with('.') && sub.len() > 1),
+			None => self.0 == host,
+		}
+	}
+}
+
+impl<'a> From<&'a str> for HostPattern {
+	fn from(pattern: &'a str) -> Self {
+		Self::new(pattern)
+	}
+}
+
+/// URL policy.
+///
+/// Decides which URLs a [`PolicyLoader`] is allowed to load. For each
+/// criterion (scheme, host, IP address), denylists take precedence over
+/// allowlists, and an empty allowlist allows everything.
+///
+/// [`Policy::check`] only applies IP ranges to URLs whose host is an IP
+/// address literal: host names are not resolved. A `ReqwestLoader` given
+/// the policy with its `policy` option also applies the IP ranges to the
+/// resolved addresses. Otherwise, use a host allowlist to restrict which names can be queried.
+#[derive(Debug, Clone, Default)]
+pub struct Policy {
+	/// Allowed schemes.
+	pub allowed_schemes: HashSet<String>,
+
+	/// Denied schemes.
+	pub denied_schemes: HashSet<String>,
+
+	/// Allowed hosts.
+	pub allowed_hosts: Vec<HostPattern>,
+
+	/// Denied hosts.
+	pub denied_hosts: Vec<HostPattern>,
+
+	/// Allowed IP ranges.
+	pub allowed_ip_ranges: Vec<IpRange>,
+
+	/// Denied IP ranges.
+	pub denied_ip_ranges: Vec<IpRange>,
+
+	/// Maximum number of distinct URLs loaded.
+	pub max_loads: Option<usize>,
+}
+
+impl Policy {
+	/// Creates a policy allowing everything.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a policy suited to load documents from the public web:
+	/// only `http` and `https` URLs are allowed, and `localhost` as well as
+	/// non-public addresses are denied (see
+	/// [`Self::deny_private_networks`]).
+	///
+	/// Host names resolving to denied addresses are only refused by loaders
+	/// resolving them through the policy, such as a `ReqwestLoader` with the
+	/// policy as its `policy` option.
+	pub fn web() -> Self {
+		Self::new()
+			.allow_scheme("http")
+			.allow_scheme("https")
+			.deny_private_networks()
+	}
+
+	/// Allows the given scheme.
+	pub fn allow_scheme(mut self, scheme: &str) -> Self {
+		self.allowed_schemes.insert(scheme.to_ascii_lowercase());
+		self
+	}
+
+	/// Denies the given scheme.
+	pub fn deny_scheme(mut self, scheme: &str) -> Self {
+		self.denied_schemes.insert(scheme.to_ascii_lowercase());
+		self
+	}
+
+	/// Allows the hosts matching the given pattern.
+	pub fn allow_host(mut self, pattern: impl Into<HostPattern>) -> Self {
+		self.allowed_hosts.push(pattern.into());
+		self
+	}
+
+	/// Denies the hosts matching the given pattern.
+	pub fn deny_host(mut self, pattern: impl Into<HostPattern>) -> Self {
+		self.denied_hosts.push(pattern.into());
+		self
+	}
+
+	/// Allows the given IP range.
+	pub fn allow_ip_range(mut self, range: IpRange) -> Self {
+		self.allowed_ip_ranges.push(range);
+		self
+	}
+
+	/// Denies the given IP range.
+	pub fn deny_ip_range(mut self, range: IpRange) -> Self {
+		self.denied_ip_ranges.push(range);
+		self
+	}
+
+	/// Denies `localhost` and the loopback, private, link-local,
+	/// unspecified, multicast and broadcast address ranges.
+	///
+	/// The IPv4-compatible (`::/96`), NAT64 (`64:ff9b::/96`) and 6to4
+	/// (`2002::/16`) ranges, whose addresses embed an IPv4 address, are
+	/// denied as well.
+	pub fn deny_private_networks(mut self) -> Self {
+		self = self.deny_host("localhost").deny_host("*.localhost");
+		for (addr, prefix_len) in PRIVATE_NETWORKS {
+			self = self.deny_ip_range(IpRange { addr, prefix_len })
+		}
+		self
+	}
+
+	/// Sets the maximum number of distinct URLs loaded.
+	pub fn with_max_loads(self, max_loads: usize) -> Self {
+		Self {
+			max_loads: Some(max_loads),
+			..self
+		}
+	}
+
+	/// Checks that the given URL is allowed by this policy.
+	pub fn check(&self, url: &Iri) -> Result<(), Refusal> {
+		let scheme = url.scheme().as_str().to_ascii_lowercase();
+		if self.denied_schemes.contains(&scheme)
+			|| (!self.allowed_schemes.is_empty() && !self.allowed_schemes.contains(&scheme))
+		{
+			return Err(Refusal::Scheme(scheme));
+		}
+
+		let host = url.authority().map(|authority| {
+			decode_host(authority.host().as_str())
+				.trim_end_matches('.')
+				.to_ascii_lowercase()
+		});
+
+		match &host {
+			Some(host) => {
+				if self.denied_hosts.iter().any(|p| p.matches(host))
+					|| (!self.allowed_hosts.is_empty()
+						&& !self.allowed_hosts.iter().any(|p| p.matches(host)))
+				{
+					return Err(Refusal::Host(host.clone()));
+				}
+			}
+			None => {
+				if !self.allowed_hosts.is_empty() {
+					return Err(Refusal::MissingHost);
+				}
+			}
+		}
+
+		match host.as_deref().and_then(parse_host_addr) {
+			Some(addr) => self.check_addr(addr),
+			None => Ok(()),
+		}
+	}
+
+	/// Checks that the given IP address is allowed by this policy.
+	///
+	/// IPv6 addresses embedding an IPv4 address (IPv4-compatible, NAT64 and
+	/// 6to4 addresses) are also refused if the embedded address is denied.
+	pub fn check_addr(&self, addr: IpAddr) -> Result<(), Refusal> {
+		let denied = |addr| self.denied_ip_ranges.iter().any(|r| r.contains(addr));
+		let embedded = match addr {
+			IpAddr::V6(v6) => embedded_ipv4(v6).map(IpAddr::V4),
+			IpAddr::V4(_) => None,
+		};
+
+		if denied(addr)
+			|| embedded.map_or(false, denied)
+			|| (!self.allowed_ip_ranges.is_empty()
+				&& !self.allowed_ip_ranges.iter().any(|r| r.contains(addr)))
+		{
+			Err(Refusal::Address(addr))
+		} else {
+			Ok(())
+		}
+	}
+}
+
+/// Decodes the percent-encoded octets of the given URL host.
+///
+/// Hosts are decoded before being parsed as IP address or matched against
+/// host patterns, as HTTP clients do before resolving them.
+fn decode_host(host: &str) -> String {
+	let bytes = host.as_bytes();
+	let mut decoded = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+	while i < bytes.len() {
+		match bytes.get(i..i + 3) {
+			Some([b'%', hi, lo]) if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {
+				let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap();
+				decoded.push(u8::from_str_radix(hex, 16).unwrap());
+				i += 3
+			}
+			_ => {
+				decoded.push(bytes[i]);
+				i += 1
+			}
+		}
+	}
+
+	String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Returns the IPv4 address embedded in the given IPv4-compatible
+/// (`::/96`), NAT64 (`64:ff9b::/96`) or 6to4 (`2002::/16`) address.
+///
+/// IPv4-mapped addresses are handled by [`IpRange::contains`].
+fn embedded_ipv4(addr: Ipv6Addr) -> Option<Ipv4Addr> {
+	match addr.segments() {
+		[0x2002, hi, lo, ..] => Some(Ipv4Addr::from((u32::from(hi) << 16) | u32::from(lo))),
+		[0, 0, 0, 0, 0, 0, ..] | [0x64, 0xff9b, 0, 0, 0, 0, ..] => {
+			Some(Ipv4Addr::from(u128::from(addr) as u32))
+		}
+		_ => None,
+	}
+}
+
+/// Parses the given URL host as an IP address.
+///
+/// IPv4 addresses are parsed the way HTTP clients do, accepting shortened,
+/// octal and hexadecimal forms (e.g. `127.1`, `0x7f000001`), so that they
+/// cannot be used to get around the policy.
+fn parse_host_addr(host: &str) -> Option<IpAddr> {
+	if let Some(v6) = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+		return v6.parse().ok().map(IpAddr::V6);
+	}
+
+	let parts: Vec<&str> = host.split('.').collect();
+	if parts.len() > 4 {
+		return None;
+	}
+
+	let mut numbers = Vec::with_capacity(parts.len());
+	for part in parts {
+		let n = if let Some(hex) = part.strip_prefix("0x") {
+			if hex.is_empty() {
+				0
+			} else {
+				u32::from_str_radix(hex, 16).ok()?
+			}
+		} else if part.len() > 1 && part.starts_with('0') {
+			u32::from_str_radix(&part[1..], 8).ok()?
+		} else {
+			part.parse().ok()?
+		};
+
+		numbers.push(n)
+	}
+
+	let (last, init) = numbers.split_last()?;
+	if init.iter().any(|n| *n > 255) || (*last as u64) >= 1u64 << (8 * (5 - numbers.len())) {
+		return None;
+	}
+
+	let mut addr = *last;
+	for (i, n) in init.iter().enumerate() {
+		addr |= n << (8 * (3 - i))
+	}
+
+	Some(IpAddr::V4(Ipv4Addr::from(addr)))
+}
+
+/// Reason why a URL is refused by a [`Policy`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Refusal {
+	/// The URL scheme is not allowed.
+	#[error("scheme `{0}` is not allowed")]
+	Scheme(String),
+
+	/// The URL host is not allowed.
+	#[error("host `{0}` is not allowed")]
+	Host(String),
+
+	/// The URL has no host, but only some hosts are allowed.
+	#[error("URL has no host")]
+	MissingHost,
+
+	/// The URL host is an IP address that is not allowed.
+	#[error("address `{0}` is not allowed")]
+	Address(IpAddr),
+
+	/// The maximum number of distinct loaded URLs is reached.
+	#[error("too many loads (maximum is {0})")]
+	TooManyLoads(usize),
+}
+
+/// Policy loader error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error<I, E> {
+	/// The URL is refused by the policy.
+	#[error("refused to load `{0}`: {1}")]
+	Refused(I, Refusal),
+
+	/// The inner loader failed.
+	#[error(transparent)]
+	Load(E),
+}
+
+impl<I: DisplayWithContext<N>, E: fmt::Display, N> DisplayWithContext<N> for Error<I, E> {
+	fn fmt_with(&self, vocabulary: &N, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Refused(i, reason) => {
+				write!(f, "refused to load `{}`: {reason}", i.with(vocabulary))
+			}
+			Self::Load(e) => e.fmt(f),
+		}
+	}
+}
+
+/// Loader enforcing a URL [`Policy`].
+///
+/// Every URL is checked against the policy before being given to the inner
+/// loader. Refused URLs fail with [`Error::Refused`] without reaching the
+/// inner loader. This protects against server-side request forgery when
+/// expanding untrusted documents, whose `@context` may point anywhere.
+///
+/// Redirections followed by the inner loader are not seen by this loader.
+/// Give the policy to the inner loader as well when it supports it, such as
+/// the `policy` option of a `ReqwestLoader`.
+///
+/// The number of distinct URLs loaded is counted from the creation of the
+/// loader, or the last call to [`PolicyLoader::reset`], so that a single
+/// processing run cannot trigger an unbounded number of queries.
+pub struct PolicyLoader<L, I> {
+	inner: L,
+	policy: Policy,
+	loaded: HashSet<I>,
+}
+
+impl<L, I> PolicyLoader<L, I> {
+	/// Wraps the given loader, enforcing the given policy.
+	pub fn new(inner: L, policy: Policy) -> Self {
+		Self {
+			inner,
+			policy,
+			loaded: HashSet::new(),
+		}
+	}
+
+	/// Returns the policy.
+	pub fn policy(&self) -> &Policy {
+		&self.policy
+	}
+
+	/// Returns a reference to the inner loader.
+	pub fn inner(&self) -> &L {
+		&self.inner
+	}
+
+	/// Returns a mutable reference to the inner loader.
+	pub fn inner_mut(&mut self) -> &mut L {
+		&mut self.inner
+	}
+
+	/// Unwraps the inner loader.
+	pub fn into_inner(self) -> L {
+		self.inner
+	}
+
+	/// Returns the number of distinct URLs loaded so far.
+	pub fn load_count(&self) -> usize {
+		self.loaded.len()
+	}
+
+	/// Resets the count of loaded URLs, to start a new processing run.
+	pub fn reset(&mut self) {
+		self.loaded.clear()
+	}
+}
+
+impl<L, I: Clone + Eq + Hash> PolicyLoader<L, I> {
+	/// Checks that the given URL can be loaded, counting it as loaded.
+	fn admit(&mut self, vocabulary: &impl IriVocabulary<Iri = I>, url: &I) -> Result<(), Refusal> {
+		if let Err(reason) = self.policy.check(vocabulary.iri(url).unwrap()) {
+			log::warn!("refused to load {}: {reason}", vocabulary.iri(url).unwrap());
+			return Err(reason);
+		}
+
+		if !self.loaded.contains(url) {
+			if let Some(max) = self.policy.max_loads {
+				if self.loaded.len() >= max {
+					return Err(Refusal::TooManyLoads(max));
+				}
+			}
+
+			self.loaded.insert(url.clone());
+		}
+
+		Ok(())
+	}
+}
+
+impl<L, I> Loader<I> for PolicyLoader<L, I>
+where
+	L: Loader<I> + Send,
+	I: Clone + Eq + Hash + Send + Sync,
+{
+	type Error = Error<I, L::Error>;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Self::Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			if let Err(reason) = self.admit(&*vocabulary, &url) {
+				return Err(Error::Refused(url, reason));
+			}
+
+			self.inner
+				.load_with(vocabulary, url)
+				.await
+				.map_err(Error::Load)
+		}
+		.boxed()
+	}
+
+	fn load_context_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, Result<RemoteContext<I>, ContextLoadError<Self::Error>>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			if let Err(reason) = self.admit(&*vocabulary, &url) {
+				return Err(ContextLoadError::LoadingDocumentFailed(Error::Refused(
+					url, reason,
+				)));
+			}
+
+			self.inner
+				.load_context_with(vocabulary, url)
+				.await
+				.map_err(|e| e.map(Error::Load))
+		}
+		.boxed()
+	}
+}
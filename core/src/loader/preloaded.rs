@@ -0,0 +1,114 @@
+use super::{Loader, RemoteDocument};
+use crate::future::{BoxFuture, FutureExt};
+use crate::LoadingResult;
+use contextual::{DisplayWithContext, WithContext};
+use json_syntax::Parse;
+use rdf_types::IriVocabularyMut;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Loading error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error<I> {
+	/// No document is embedded for the given IRI.
+	#[error("no static document for `{0}`")]
+	NotFound(I),
+
+	/// The embedded document is not valid JSON.
+	#[error("parse error: {0}")]
+	Parse(json_syntax::parse::Error),
+}
+
+impl<I: DisplayWithContext<N>, N> DisplayWithContext<N> for Error<I> {
+	fn fmt_with(&self, vocabulary: &N, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::NotFound(i) => write!(f, "no static document for `{}`", i.with(vocabulary)),
+			Self::Parse(e) => write!(f, "parse error: {e}"),
+		}
+	}
+}
+
+/// Static document loader.
+///
+/// Loads documents embedded in the binary, indexed by IRI. Documents are
+/// stored as JSON source and parsed each time they are loaded.
+///
+/// Unknown IRIs are rejected with [`Error::NotFound`], so this loader can be
+/// combined with a [`ChainLoader`](super::ChainLoader) to fall back to
+/// another loader, for instance a [`ReqwestLoader`](super::ReqwestLoader).
+#[derive(Debug, Default, Clone)]
+pub struct StaticLoader {
+	documents: HashMap<&'static str, &'static str>,
+}
+
+/// Alias for [`StaticLoader`].
+pub type PreloadedLoader = StaticLoader;
+
+impl StaticLoader {
+	/// Creates a new empty static loader.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Embeds the given JSON `content` for the given IRI.
+	///
+	/// Returns the previous content associated to the IRI, if any.
+	pub fn insert(&mut self, iri: &'static str, content: &'static str) -> Option<&'static str> {
+		self.documents.insert(iri, content)
+	}
+
+	/// Checks if a document is embedded for the given IRI.
+	pub fn contains(&self, iri: &str) -> bool {
+		self.documents.contains_key(iri)
+	}
+
+	/// Returns an iterator over the IRIs of the embedded documents.
+	pub fn iris(&self) -> impl Iterator<Item = &'static str> + '_ {
+		self.documents.keys().copied()
+	}
+}
+
+impl Extend<(&'static str, &'static str)> for StaticLoader {
+	fn extend<T: IntoIterator<Item = (&'static str, &'static str)>>(&mut self, iter: T) {
+		self.documents.extend(iter)
+	}
+}
+
+impl FromIterator<(&'static str, &'static str)> for StaticLoader {
+	fn from_iter<T: IntoIterator<Item = (&'static str, &'static str)>>(iter: T) -> Self {
+		Self {
+			documents: iter.into_iter().collect(),
+		}
+	}
+}
+
+impl<I: Send> Loader<I> for StaticLoader {
+	type Error = Error<I>;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Self::Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		async move {
+			match self.documents.get(vocabulary.iri(&url).unwrap().as_str()) {
+				Some(content) => {
+					let (doc, _) = json_syntax::Value::parse_str(content).map_err(Error::Parse)?;
+					Ok(RemoteDocument::new(
+						Some(url),
+						Some("application/ld+json".parse().unwrap()),
+						doc,
+					))
+				}
+				None => Err(Error::NotFound(url)),
+			}
+		}
+		.boxed()
+	}
+}
@@ -3,23 +3,41 @@ use crate::future::{BoxFuture, FutureExt};
 use crate::LoadingResult;
 use crate::Profile;
 
-use super::{Loader, RemoteDocument};
+use super::{
+	html,
+	policy::{Policy, Refusal},
+	Loader, RemoteDocument,
+};
 use hashbrown::HashSet;
-use iref::Iri;
+use iref::{Iri, IriBuf, IriRef};
 use json_syntax::Parse;
 use once_cell::sync::OnceCell;
 use rdf_types::{vocabulary::IriIndex, IriVocabulary, IriVocabularyMut};
 use reqwest::{
-	header::{ACCEPT, CONTENT_TYPE, LINK, LOCATION},
-	StatusCode,
+	header::{
+		HeaderMap, ACCEPT, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+		LINK, LOCATION,
+	},
+	redirect, RequestBuilder, StatusCode,
+};
+use std::{
+	future::Future, hash::Hash, path::PathBuf, string::FromUtf8Error, sync::Arc, time::Duration,
 };
-use std::{hash::Hash, string::FromUtf8Error};
 
+mod blocking;
+mod cache;
 mod content_type;
 mod link;
+mod resolve;
 
+use cache::*;
 use content_type::*;
 use link::*;
+use resolve::*;
+
+pub use blocking::BlockingReqwestLoader;
+pub use reqwest::{header, Certificate, Client, Identity, Proxy};
+pub use rustls;
 
 /// Loader options.
 pub struct Options<I> {
@@ -32,6 +50,108 @@ pub struct Options<I> {
 	///
 	/// Defaults to 8.
 	pub max_redirections: usize,
+
+	/// HTML extraction options.
+	///
+	/// If set, HTML documents (`text/html` and `application/xhtml+xml`) are
+	/// accepted and the JSON-LD content of their script elements is
+	/// extracted. Otherwise HTML documents are rejected with
+	/// [`Error::InvalidContentType`].
+	pub html: Option<html::Options>,
+
+	/// Directory where responses are persisted between runs.
+	///
+	/// If set, successful responses are stored in this directory unless the
+	/// server forbids it with `Cache-Control: no-store`. A stored response is
+	/// used as is while it is fresh according to its `Cache-Control: max-age`
+	/// directive, and revalidated with `If-None-Match`/`If-Modified-Since`
+	/// afterward. Stale responses are still used when the server cannot be
+	/// reached.
+	///
+	/// Defaults to `None`, meaning that nothing is persisted.
+	pub cache_directory: Option<PathBuf>,
+
+	/// Preconfigured HTTP client.
+	///
+	/// If set, this client is used as is to query documents, and the
+	/// [`tls_config`](Self::tls_config),
+	/// [`root_certificates`](Self::root_certificates) and
+	/// [`identity`](Self::identity) options are ignored.
+	///
+	/// Defaults to `None`, meaning that the loader builds its own client.
+	pub client: Option<Client>,
+
+	/// TLS configuration of the HTTP client.
+	///
+	/// Use this to pin rustls settings (protocol versions, cipher suites,
+	/// trusted roots, client certificate, etc.). If set, the
+	/// [`root_certificates`](Self::root_certificates) and
+	/// [`identity`](Self::identity) options are ignored.
+	pub tls_config: Option<rustls::ClientConfig>,
+
+	/// Trusted root certificates added to the native ones, such as a private
+	/// certificate authority.
+	pub root_certificates: Vec<Certificate>,
+
+	/// Client certificate presented for mutual TLS authentication.
+	pub identity: Option<Identity>,
+
+	/// Proxies used to reach servers.
+	///
+	/// Ignored if a preconfigured [`client`](Self::client) is given.
+	/// Defaults to no proxy other than the system ones (configured with the
+	/// `HTTP_PROXY`/`HTTPS_PROXY` environment variables).
+	pub proxies: Vec<Proxy>,
+
+	/// Headers added to every request.
+	///
+	/// These headers replace the ones built by the loader, such as
+	/// `Accept`.
+	pub headers: HeaderMap,
+
+	/// Credentials provider.
+	///
+	/// Called before each request with the requested URL, returns the
+	/// credentials to send to the server, if any.
+	pub credentials: Option<CredentialsProvider>,
+
+	/// Maximum time to wait for a connection to be established.
+	///
+	/// Ignored if a preconfigured [`client`](Self::client) is given.
+	/// Defaults to `None`, meaning no timeout.
+	pub connect_timeout: Option<Duration>,
+
+	/// Maximum time to wait for data from the server, either the response
+	/// headers or the next chunk of the response body.
+	///
+	/// Defaults to `None`, meaning no timeout.
+	pub read_timeout: Option<Duration>,
+
+	/// Maximum size of a response body, in bytes.
+	///
+	/// Larger responses are rejected with [`Error::BodyTooLarge`] without
+	/// being entirely downloaded.
+	///
+	/// Defaults to `None`, meaning no limit.
+	pub max_body_size: Option<u64>,
+
+	/// Retry policy applied on server errors (`5xx` status codes) and
+	/// connection failures.
+	///
+	/// Defaults to [`RetryPolicy::none`].
+	pub retry: RetryPolicy,
+
+	/// URL policy.
+	///
+	/// If set, the requested URL and every redirection target are checked
+	/// against this policy, failing with [`Error::Refused`]. Unless a
+	/// preconfigured [`client`](Self::client) is given, the addresses host
+	/// names resolve to are also checked against the policy IP ranges,
+	/// refused addresses being discarded. Requests going through a proxy
+	/// are resolved by the proxy, and escape this last check.
+	///
+	/// Defaults to `None`, meaning that every URL is allowed.
+	pub policy: Option<Policy>,
 }
 
 impl<I> Default for Options<I> {
@@ -39,10 +159,90 @@ impl<I> Default for Options<I> {
 		Self {
 			request_profile: Vec::new(),
 			max_redirections: 8,
+			html: None,
+			cache_directory: None,
+			client: None,
+			tls_config: None,
+			root_certificates: Vec::new(),
+			identity: None,
+			proxies: Vec::new(),
+			headers: HeaderMap::new(),
+			credentials: None,
+			connect_timeout: None,
+			read_timeout: None,
+			max_body_size: None,
+			retry: RetryPolicy::none(),
+			policy: None,
 		}
 	}
 }
 
+/// HTTP authentication credentials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Credentials {
+	/// Bearer token.
+	Bearer(String),
+
+	/// Basic authentication.
+	Basic {
+		username: String,
+		password: Option<String>,
+	},
+}
+
+/// Function providing the credentials to use for a given URL.
+///
+/// Usually decides based on the URL host.
+pub type CredentialsProvider = Arc<dyn Fn(&Iri) -> Option<Credentials> + Send + Sync>;
+
+/// Retry policy.
+///
+/// The delay between two attempts starts at
+/// [`initial_backoff`](Self::initial_backoff) and doubles after each retry,
+/// up to [`max_backoff`](Self::max_backoff).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+	/// Maximum number of retries after the first attempt.
+	pub max_retries: usize,
+
+	/// Delay before the first retry.
+	pub initial_backoff: Duration,
+
+	/// Maximum delay between two attempts.
+	pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+	/// Never retry.
+	pub const fn none() -> Self {
+		Self::new(0)
+	}
+
+	/// Retry at most `max_retries` times, starting with a 500ms delay up to
+	/// 10s.
+	pub const fn new(max_retries: usize) -> Self {
+		Self {
+			max_retries,
+			initial_backoff: Duration::from_millis(500),
+			max_backoff: Duration::from_secs(10),
+		}
+	}
+
+	/// Returns the delay before the given retry, starting from 0.
+	pub fn backoff(&self, retry: usize) -> Duration {
+		let factor = 2u32.saturating_pow(retry.try_into().unwrap_or(u32::MAX));
+		self.initial_backoff
+			.saturating_mul(factor)
+			.min(self.max_backoff)
+	}
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self::none()
+	}
+}
+
 /// Loading error.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -74,21 +274,85 @@ pub enum Error {
 	#[error("too many redirections")]
 	TooManyRedirections,
 
+	/// The URL, or a redirection target, is refused by [`Options::policy`].
+	#[error("refused to load `{0}`: {1}")]
+	Refused(String, Refusal),
+
 	#[error("JSON parse error: {0}")]
 	Parse(json_syntax::parse::Error<std::io::Error>),
+
+	#[error("invalid HTML encoding")]
+	InvalidHtmlEncoding,
+
+	#[error(transparent)]
+	Html(html::Error),
+
+	#[error("connection timed out")]
+	ConnectTimeout,
+
+	#[error("read timed out")]
+	ReadTimeout,
+
+	/// The response body is larger than [`Options::max_body_size`].
+	#[error("response body exceeds {0} bytes")]
+	BodyTooLarge(u64),
+
+	/// Every attempt allowed by the [`RetryPolicy`] failed, the last one
+	/// with the given error.
+	#[error("query failed after {0} attempts: {1}")]
+	RetriesExhausted(usize, Box<Error>),
+}
+
+impl Error {
+	fn from_reqwest(e: reqwest::Error) -> Self {
+		if e.is_connect() && e.is_timeout() {
+			Self::ConnectTimeout
+		} else {
+			Self::Reqwest(e)
+		}
+	}
+
+	/// Checks if the query failed because the server could not be reached.
+	fn is_connection_failure(&self) -> bool {
+		match self {
+			Self::Reqwest(e) => e.is_connect(),
+			Self::ConnectTimeout => true,
+			_ => false,
+		}
+	}
+
+	/// Checks if this error may be recovered from by using a stale cache
+	/// entry.
+	fn is_transient(&self) -> bool {
+		matches!(
+			self,
+			Self::Reqwest(_)
+				| Self::ConnectTimeout
+				| Self::ReadTimeout
+				| Self::RetriesExhausted(..)
+		)
+	}
 }
 
 /// `reqwest`-based loader.
 ///
-/// Only works with the [`tokio`](https://tokio.rs/) runtime.
+/// Only works with the [`tokio`](https://tokio.rs/) runtime. Use a
+/// [`BlockingReqwestLoader`] with other runtimes or from synchronous code.
+///
+/// The HTTP client is built on first use from the TLS related
+/// [`Options`], unless a preconfigured [`Client`] is given with
+/// [`Options::client`].
 ///
 /// The loader will follow indirections and `Link` headers.
 ///
 /// Loaded documents are not cached: a new network query is made each time
 /// an URL is loaded even if it has already been queried before.
+/// Wrap the loader in a [`CachingLoader`](super::CachingLoader) to memoize
+/// loaded documents, or set [`Options::cache_directory`] to persist HTTP
+/// responses on disk.
 pub struct ReqwestLoader<I = IriIndex> {
 	options: Options<I>,
-	data: OnceCell<Data>,
+	data: OnceCell<Arc<Data>>,
 }
 
 impl<I> Default for ReqwestLoader<I> {
@@ -115,10 +379,20 @@ impl<I> ReqwestLoader<I> {
 /// Precomputed data.
 struct Data {
 	accept_header: String,
+	client: Client,
+	read_timeout: Option<Duration>,
+	max_body_size: Option<u64>,
+	retry: RetryPolicy,
+	headers: HeaderMap,
+	credentials: Option<CredentialsProvider>,
+	cache_directory: Option<PathBuf>,
 }
 
 impl Data {
-	fn new<I>(options: &Options<I>, vocabulary: &impl IriVocabulary<Iri = I>) -> Self {
+	fn new<I>(
+		options: &Options<I>,
+		vocabulary: &impl IriVocabulary<Iri = I>,
+	) -> Result<Self, Error> {
 		let mut json_ld_params = String::new();
 
 		if !options.request_profile.is_empty() {
@@ -141,10 +415,59 @@ impl Data {
 			}
 		}
 
-		Self {
-			accept_header: format!("application/ld+json{json_ld_params}, application/json"),
+		let mut accept_header = format!("application/ld+json{json_ld_params}, application/json");
+
+		if options.html.is_some() {
+			accept_header.push_str(", text/html;q=0.8, application/xhtml+xml;q=0.8")
+		}
+
+		Ok(Self {
+			accept_header,
+			client: build_client(options).map_err(Error::Reqwest)?,
+			read_timeout: options.read_timeout,
+			max_body_size: options.max_body_size,
+			retry: options.retry,
+			headers: options.headers.clone(),
+			credentials: options.credentials.clone(),
+			cache_directory: options.cache_directory.clone(),
+		})
+	}
+}
+
+/// Builds the HTTP client described by the given options.
+fn build_client<I>(options: &Options<I>) -> Result<Client, reqwest::Error> {
+	if let Some(client) = &options.client {
+		return Ok(client.clone());
+	}
+
+	// Redirections are followed by the loader itself.
+	let mut builder = Client::builder().redirect(redirect::Policy::none());
+
+	if let Some(tls_config) = &options.tls_config {
+		builder = builder.use_preconfigured_tls(tls_config.clone())
+	} else {
+		for certificate in &options.root_certificates {
+			builder = builder.add_root_certificate(certificate.clone())
 		}
+
+		if let Some(identity) = &options.identity {
+			builder = builder.identity(identity.clone())
+		}
+	}
+
+	if let Some(timeout) = options.connect_timeout {
+		builder = builder.connect_timeout(timeout)
 	}
+
+	for proxy in &options.proxies {
+		builder = builder.proxy(proxy.clone())
+	}
+
+	if let Some(policy) = &options.policy {
+		builder = builder.dns_resolver(Arc::new(PolicyResolver::new(policy.clone())))
+	}
+
+	builder.build()
 }
 
 /// HTTP body parse error.
@@ -159,139 +482,363 @@ pub enum ParseError {
 	Json(json_ld_syntax::parse::Error),
 }
 
-impl<I: Clone + Eq + Hash> Loader<I> for ReqwestLoader<I> {
-	type Error = Error;
+/// HTTP response, either received from the network or taken from the cache.
+struct Response {
+	status: StatusCode,
+	headers: HeaderMap,
+	body: bytes::Bytes,
+}
 
-	fn load_with<'a, V>(
-		&'a mut self,
-		vocabulary: &'a mut V,
-		mut url: I,
-	) -> BoxFuture<'a, LoadingResult<I, Error>>
-	where
-		V: IriVocabularyMut<Iri = I>,
-		//
-		V: Send + Sync,
-		I: 'a + Send,
+impl From<CachedResponse> for Response {
+	fn from(value: CachedResponse) -> Self {
+		Self {
+			status: StatusCode::OK,
+			headers: value.headers,
+			body: value.body,
+		}
+	}
+}
+
+/// Awaits the given future, failing with [`Error::ReadTimeout`] if it takes
+/// longer than `timeout`.
+async fn read<F: Future>(timeout: Option<Duration>, f: F) -> Result<F::Output, Error> {
+	match timeout {
+		Some(timeout) => tokio::time::timeout(timeout, f)
+			.await
+			.map_err(|_| Error::ReadTimeout),
+		None => Ok(f.await),
+	}
+}
+
+/// Sends the given request and downloads the response.
+async fn send(data: &Data, request: RequestBuilder) -> Result<Response, Error> {
+	let mut response = read(data.read_timeout, request.send())
+		.await?
+		.map_err(Error::from_reqwest)?;
+
+	if let (Some(limit), Some(len)) = (data.max_body_size, response.content_length()) {
+		if len > limit {
+			return Err(Error::BodyTooLarge(limit));
+		}
+	}
+
+	let mut body = bytes::BytesMut::new();
+	while let Some(chunk) = read(data.read_timeout, response.chunk())
+		.await?
+		.map_err(Error::from_reqwest)?
 	{
-		async move {
-			let data = self
-				.data
-				.get_or_init(|| Data::new(&self.options, vocabulary));
-			let mut redirection_number = 0;
-
-			'next_url: loop {
-				if redirection_number > self.options.max_redirections {
-					return Err(Error::TooManyRedirections);
+		if let Some(limit) = data.max_body_size {
+			if (body.len() + chunk.len()) as u64 > limit {
+				return Err(Error::BodyTooLarge(limit));
+			}
+		}
+
+		body.extend_from_slice(&chunk)
+	}
+
+	Ok(Response {
+		status: response.status(),
+		headers: response.headers().clone(),
+		body: body.freeze(),
+	})
+}
+
+/// Sends the request built by `request`, retrying according to the retry
+/// policy.
+async fn send_with_retries(
+	data: &Data,
+	url: &str,
+	request: impl Fn() -> RequestBuilder,
+) -> Result<Response, Error> {
+	let mut retry = 0;
+
+	loop {
+		let result = send(data, request()).await;
+
+		let failed = match &result {
+			Ok(response) => response.status.is_server_error(),
+			Err(e) => e.is_connection_failure(),
+		};
+
+		if !failed || data.retry.max_retries == 0 {
+			break result;
+		}
+
+		if retry == data.retry.max_retries {
+			let e = match result {
+				Ok(response) => Error::QueryFailed(response.status),
+				Err(e) => e,
+			};
+
+			break Err(Error::RetriesExhausted(retry + 1, Box::new(e)));
+		}
+
+		let delay = data.retry.backoff(retry);
+		log::debug!(
+			"attempt {} failed for {url}, retrying in {delay:?}",
+			retry + 1
+		);
+		tokio::time::sleep(delay).await;
+		retry += 1
+	}
+}
+
+/// Queries the given URL, going through the cache stored in
+/// [`Options::cache_directory`] if any.
+async fn fetch(data: &Data, iri: &Iri) -> Result<Response, Error> {
+	let url = iri.as_str();
+	let cache = data.cache_directory.as_deref().map(HttpCache::new);
+	let cached = cache.as_ref().and_then(|cache| cache.get(url));
+
+	if let Some(cached) = cached.as_ref().filter(|cached| cached.is_fresh()) {
+		log::debug!("using fresh cache entry: {url}");
+		return Ok(Response {
+			status: StatusCode::OK,
+			headers: cached.headers.clone(),
+			body: cached.body.clone(),
+		});
+	}
+
+	log::debug!("downloading: {url}");
+	let credentials = data.credentials.as_ref().and_then(|provider| provider(iri));
+	let request = || {
+		let mut request = data
+			.client
+			.get(url)
+			.header(ACCEPT, data.accept_header.as_str())
+			.headers(data.headers.clone());
+
+		match &credentials {
+			Some(Credentials::Bearer(token)) => request = request.bearer_auth(token),
+			Some(Credentials::Basic { username, password }) => {
+				request = request.basic_auth(username, password.as_ref())
+			}
+			None => (),
+		}
+
+		if let Some(cached) = &cached {
+			if let Some(etag) = cached.headers.get(ETAG) {
+				request = request.header(IF_NONE_MATCH, etag)
+			}
+
+			if let Some(last_modified) = cached.headers.get(LAST_MODIFIED) {
+				request = request.header(IF_MODIFIED_SINCE, last_modified)
+			}
+		}
+
+		request
+	};
+
+	let response = match send_with_retries(data, url, request).await {
+		Ok(response) => response,
+		Err(e) => {
+			return match cached {
+				Some(cached) if e.is_transient() => {
+					log::warn!("using stale cache entry for {url}: {e}");
+					Ok(cached.into())
 				}
+				_ => Err(e),
+			}
+		}
+	};
 
-				log::debug!("downloading: {}", vocabulary.iri(&url).unwrap().as_str());
-				let client = reqwest::Client::new();
-				let request = client
-					.get(vocabulary.iri(&url).unwrap().as_str())
-					.header(ACCEPT, &data.accept_header);
-
-				let response = request.send().await.map_err(Error::Reqwest)?;
-
-				match response.status() {
-					StatusCode::OK => {
-						let mut content_types = response
-							.headers()
-							.get_all(CONTENT_TYPE)
-							.into_iter()
-							.filter_map(ContentType::new);
-
-						match content_types.find(ContentType::is_json_ld) {
-							Some(content_type) => {
-								let mut context_url = None;
-								if *content_type.media_type() != "application/ld+json" {
-									for link in response.headers().get_all(LINK).into_iter() {
-										if let Some(link) = Link::new(link) {
-											if link.rel()
-												== Some(b"http://www.w3.org/ns/json-ld#context")
-											{
-												if context_url.is_some() {
-													return Err(Error::MultipleContextLinkHeaders);
-												}
-
-												let u = link
-													.href()
-													.resolved(vocabulary.iri(&url).unwrap());
-												context_url = Some(vocabulary.insert(u.as_iri()));
-											}
-										}
-									}
-								}
+	if let (Some(cache), Some(mut cached)) = (&cache, cached) {
+		if response.status == StatusCode::NOT_MODIFIED {
+			log::debug!("cache entry revalidated: {url}");
+			for (name, value) in &response.headers {
+				cached.headers.insert(name, value.clone());
+			}
 
-								let mut profile = HashSet::new();
-								for p in content_type
-									.profile()
-									.into_iter()
-									.flat_map(|p| p.split(|b| *b == b' '))
-								{
-									if let Ok(p) = std::str::from_utf8(p) {
-										if let Ok(iri) = Iri::new(p) {
-											profile.insert(Profile::new(iri, vocabulary));
-										}
-									}
-								}
+			if let Err(e) = cache.insert(url, &cached.headers, &cached.body) {
+				log::warn!("unable to update cache entry for {url}: {e}")
+			}
 
-								let bytes = response.bytes().await.map_err(Error::Reqwest)?;
+			return Ok(cached.into());
+		}
+	}
 
-								let decoder = utf8_decode::Decoder::new(bytes.iter().copied());
-								let (document, _) = json_syntax::Value::parse_utf8(decoder)
-									.map_err(Error::Parse)?;
+	if let Some(cache) = &cache {
+		if response.status == StatusCode::OK {
+			if CacheControl::new(&response.headers).no_store {
+				cache.remove(url)
+			} else if let Err(e) = cache.insert(url, &response.headers, &response.body) {
+				log::warn!("unable to store cache entry for {url}: {e}")
+			}
+		}
+	}
 
-								break Ok(RemoteDocument::new_full(
+	Ok(response)
+}
+
+impl<I: Clone + Eq + Hash> ReqwestLoader<I> {
+	/// Loads the document behind the given URL, following redirections and
+	/// `Link` headers, with `query` sending the HTTP requests.
+	async fn load_using<V, F>(
+		&mut self,
+		vocabulary: &mut V,
+		mut url: I,
+		query: impl Fn(Arc<Data>, IriBuf) -> F,
+	) -> LoadingResult<I, Error>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		F: Future<Output = Result<Response, Error>>,
+	{
+		let data = self
+			.data
+			.get_or_try_init(|| Data::new(&self.options, vocabulary).map(Arc::new))?;
+		let mut redirection_number = 0;
+
+		'next_url: loop {
+			if redirection_number > self.options.max_redirections {
+				return Err(Error::TooManyRedirections);
+			}
+
+			if let Some(policy) = &self.options.policy {
+				let iri = vocabulary.iri(&url).unwrap();
+				if let Err(reason) = policy.check(iri) {
+					log::warn!("refused to load {iri}: {reason}");
+					return Err(Error::Refused(iri.as_str().to_owned(), reason));
+				}
+			}
+
+			let response = query(data.clone(), vocabulary.iri(&url).unwrap().to_owned()).await?;
+
+			match response.status {
+				StatusCode::OK => {
+					let mut content_types: Vec<_> = response
+						.headers
+						.get_all(CONTENT_TYPE)
+						.into_iter()
+						.filter_map(ContentType::new)
+						.collect();
+
+					if let Some(html_options) = &self.options.html {
+						if !content_types.iter().any(ContentType::is_json_ld) {
+							if let Some(i) = content_types.iter().position(ContentType::is_html) {
+								let content_type = content_types.swap_remove(i);
+								let content = std::str::from_utf8(&response.body)
+									.map_err(|_| Error::InvalidHtmlEncoding)?;
+
+								break RemoteDocument::from_html_with(
+									vocabulary,
 									Some(url),
 									Some(content_type.into_media_type()),
-									context_url,
-									profile,
-									document,
-								));
+									content,
+									html_options,
+								)
+								.map_err(Error::Html);
 							}
-							None => {
-								log::debug!("no valid media type found");
-								for link in response.headers().get_all(LINK).into_iter() {
+						}
+					}
+
+					match content_types.into_iter().find(ContentType::is_json_ld) {
+						Some(content_type) => {
+							let mut context_url = None;
+							if *content_type.media_type() != "application/ld+json" {
+								for link in response.headers.get_all(LINK).into_iter() {
 									if let Some(link) = Link::new(link) {
-										if link.rel() == Some(b"alternate")
-											&& link.type_() == Some(b"application/ld+json")
+										if link.rel()
+											== Some(b"http://www.w3.org/ns/json-ld#context")
 										{
-											log::debug!("link found");
+											if context_url.is_some() {
+												return Err(Error::MultipleContextLinkHeaders);
+											}
+
 											let u =
 												link.href().resolved(vocabulary.iri(&url).unwrap());
-											url = vocabulary.insert(u.as_iri());
-											redirection_number += 1;
-											continue 'next_url;
+											context_url = Some(vocabulary.insert(u.as_iri()));
 										}
 									}
 								}
+							}
 
-								break Err(Error::InvalidContentType);
+							let mut profile = HashSet::new();
+							for p in content_type
+								.profile()
+								.into_iter()
+								.flat_map(|p| p.split(|b| *b == b' '))
+							{
+								if let Ok(p) = std::str::from_utf8(p) {
+									if let Ok(iri) = Iri::new(p) {
+										profile.insert(Profile::new(iri, vocabulary));
+									}
+								}
 							}
+
+							let decoder = utf8_decode::Decoder::new(response.body.iter().copied());
+							let (document, _) =
+								json_syntax::Value::parse_utf8(decoder).map_err(Error::Parse)?;
+
+							break Ok(RemoteDocument::new_full(
+								Some(url),
+								Some(content_type.into_media_type()),
+								context_url,
+								profile,
+								document,
+							));
 						}
-					}
-					code if code.is_redirection() => {
-						if response.status() == StatusCode::SEE_OTHER {
-							break Err(Error::Redirection303);
-						} else {
-							match response.headers().get(LOCATION) {
-								Some(location) => match std::str::from_utf8(location.as_bytes()) {
-									Ok(location) => {
-										let u = Iri::new(location).map_err(|e| {
-											Error::InvalidRedirectionUrl(e.0.to_string())
-										})?;
-										url = vocabulary.insert(u);
+						None => {
+							log::debug!("no valid media type found");
+							for link in response.headers.get_all(LINK).into_iter() {
+								if let Some(link) = Link::new(link) {
+									if link.rel() == Some(b"alternate")
+										&& link.type_() == Some(b"application/ld+json")
+									{
+										log::debug!("link found");
+										let u = link.href().resolved(vocabulary.iri(&url).unwrap());
+										url = vocabulary.insert(u.as_iri());
+										redirection_number += 1;
+										continue 'next_url;
 									}
-									Err(_e) => return Err(Error::InvalidRedirectionUrlEncoding),
-								},
-								None => break Err(Error::MissingRedirectionLocation),
+								}
 							}
+
+							break Err(Error::InvalidContentType);
 						}
 					}
-					code => break Err(Error::QueryFailed(code)),
 				}
+				code if code.is_redirection() => {
+					if response.status == StatusCode::SEE_OTHER {
+						break Err(Error::Redirection303);
+					} else {
+						match response.headers.get(LOCATION) {
+							Some(location) => match std::str::from_utf8(location.as_bytes()) {
+								Ok(location) => {
+									let u = IriRef::new(location)
+										.map_err(|e| Error::InvalidRedirectionUrl(e.0.to_string()))?
+										.resolved(vocabulary.iri(&url).unwrap());
+									url = vocabulary.insert(u.as_iri());
+									redirection_number += 1;
+								}
+								Err(_e) => return Err(Error::InvalidRedirectionUrlEncoding),
+							},
+							None => break Err(Error::MissingRedirectionLocation),
+						}
+					}
+				}
+				code => break Err(Error::QueryFailed(code)),
 			}
 		}
+	}
+}
+
+impl<I: Clone + Eq + Hash> Loader<I> for ReqwestLoader<I> {
+	type Error = Error;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		self.load_using(vocabulary, url, |data, iri| async move {
+			fetch(&data, &iri).await
+		})
 		.boxed()
 	}
 }
@@ -0,0 +1,135 @@
+use super::{fetch, Data, Error, Options, ReqwestLoader, Response};
+use crate::future::{BoxFuture, FutureExt};
+use crate::{Loader, LoadingResult};
+use futures::channel::oneshot;
+use iref::IriBuf;
+use rdf_types::{vocabulary::IriIndex, IriVocabularyMut};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::{mpsc, Arc};
+
+/// Query run by the loader thread.
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Blocking `reqwest`-based loader.
+///
+/// Runs the HTTP queries of a [`ReqwestLoader`] on a dedicated thread, with
+/// its own single-threaded [`tokio`](https://tokio.rs/) runtime. It accepts
+/// the same [`Options`], and handles redirections, `Link` headers and
+/// content types the same way.
+///
+/// Documents can be loaded from synchronous code with
+/// [`Self::load_blocking`], or from any async runtime through the
+/// [`Loader`] implementation, which awaits the queries without blocking the
+/// runtime.
+///
+/// # Panics
+///
+/// Loading panics if the loader thread panicked while running a previous
+/// query.
+pub struct BlockingReqwestLoader<I = IriIndex> {
+	inner: ReqwestLoader<I>,
+	jobs: mpsc::Sender<Job>,
+}
+
+impl<I> Default for BlockingReqwestLoader<I> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<I> BlockingReqwestLoader<I> {
+	/// Creates a new loader.
+	pub fn new() -> Self {
+		Self::new_using(Options::default())
+	}
+
+	/// Creates a new loader with the given options.
+	///
+	/// This starts the loader thread, stopped when the loader is dropped.
+	pub fn new_using(options: Options<I>) -> Self {
+		let runtime = tokio::runtime::Builder::new_current_thread()
+			.enable_all()
+			.build()
+			.expect("unable to start the loader runtime");
+
+		let (jobs, receiver) = mpsc::channel::<Job>();
+		std::thread::Builder::new()
+			.name("json-ld-loader".to_owned())
+			.spawn(move || {
+				for job in receiver {
+					runtime.block_on(job)
+				}
+			})
+			.expect("unable to start the loader thread");
+
+		Self {
+			inner: ReqwestLoader::new_using(options),
+			jobs,
+		}
+	}
+}
+
+/// Queries the given URL on the loader thread.
+fn query(
+	jobs: &mpsc::Sender<Job>,
+	data: Arc<Data>,
+	iri: IriBuf,
+) -> impl Future<Output = Result<Response, Error>> {
+	let (sender, receiver) = oneshot::channel();
+	let job = async move {
+		sender.send(fetch(&data, &iri).await).ok();
+	};
+
+	// If the loader thread is gone, the job is dropped along with `sender`.
+	jobs.send(Box::pin(job)).ok();
+	async move { receiver.await.expect("the loader thread panicked") }
+}
+
+impl<I: Clone + Eq + Hash> BlockingReqwestLoader<I> {
+	/// Loads the document behind the given IRI, using the given vocabulary,
+	/// blocking the current thread.
+	///
+	/// Blocking an async runtime stalls it: use the [`Loader`]
+	/// implementation there instead.
+	pub fn load_blocking_with<V>(&mut self, vocabulary: &mut V, url: I) -> LoadingResult<I, Error>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: Send,
+	{
+		futures::executor::block_on(self.load_with(vocabulary, url))
+	}
+
+	/// Loads the document behind the given IRI, blocking the current thread.
+	pub fn load_blocking(&mut self, url: I) -> LoadingResult<I, Error>
+	where
+		(): IriVocabularyMut<Iri = I>,
+		I: Send,
+	{
+		self.load_blocking_with(rdf_types::vocabulary::no_vocabulary_mut(), url)
+	}
+}
+
+impl<I: Clone + Eq + Hash> Loader<I> for BlockingReqwestLoader<I> {
+	type Error = Error;
+
+	fn load_with<'a, V>(
+		&'a mut self,
+		vocabulary: &'a mut V,
+		url: I,
+	) -> BoxFuture<'a, LoadingResult<I, Error>>
+	where
+		V: IriVocabularyMut<Iri = I>,
+		//
+		V: Send + Sync,
+		I: 'a + Send,
+	{
+		let jobs = &self.jobs;
+		self.inner
+			.load_using(vocabulary, url, move |data, iri| query(jobs, data, iri))
+			.boxed()
+	}
+}
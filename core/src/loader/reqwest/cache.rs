@@ -0,0 +1,198 @@
+//! On-disk HTTP cache.
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CACHE_CONTROL};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Counter used to give a unique name to each temporary file.
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// `Cache-Control` header directives relevant to the cache.
+#[derive(Debug, Default)]
+pub struct CacheControl {
+	/// The response must not be stored.
+	pub no_store: bool,
+
+	/// The response must be revalidated before each use.
+	pub no_cache: bool,
+
+	/// Number of seconds during which the response is fresh.
+	pub max_age: Option<u64>,
+}
+
+impl CacheControl {
+	pub fn new(headers: &HeaderMap) -> Self {
+		let mut result = Self::default();
+
+		for value in headers.get_all(CACHE_CONTROL) {
+			let Ok(value) = value.to_str() else { continue };
+
+			for directive in value.split(',') {
+				let (name, arg) = match directive.split_once('=') {
+					Some((name, arg)) => (name.trim(), Some(arg.trim().trim_matches('"'))),
+					None => (directive.trim(), None),
+				};
+
+				if name.eq_ignore_ascii_case("no-store") {
+					result.no_store = true
+				} else if name.eq_ignore_ascii_case("no-cache") {
+					result.no_cache = true
+				} else if name.eq_ignore_ascii_case("max-age") {
+					result.max_age = arg.and_then(|a| a.parse().ok())
+				}
+			}
+		}
+
+		result
+	}
+}
+
+/// Cached response.
+pub struct CachedResponse {
+	/// Date at which the response was stored or last revalidated.
+	pub stored_at: SystemTime,
+
+	/// Response headers.
+	pub headers: HeaderMap,
+
+	/// Response body.
+	pub body: Bytes,
+}
+
+impl CachedResponse {
+	/// Checks if the response can be used without revalidation.
+	pub fn is_fresh(&self) -> bool {
+		let cache_control = CacheControl::new(&self.headers);
+
+		if cache_control.no_cache {
+			return false;
+		}
+
+		match cache_control.max_age {
+			Some(max_age) => match self.stored_at.elapsed() {
+				Ok(age) => age < Duration::from_secs(max_age),
+				Err(_) => false,
+			},
+			None => false,
+		}
+	}
+}
+
+/// On-disk HTTP cache.
+///
+/// Each response is stored in its own file, named after the SHA-256 hash of
+/// the request URL. The file starts with the request URL and the storage
+/// date, followed by the response headers, an empty line and the body.
+pub struct HttpCache<'a> {
+	directory: &'a Path,
+}
+
+impl<'a> HttpCache<'a> {
+	pub fn new(directory: &'a Path) -> Self {
+		Self { directory }
+	}
+
+	fn path(&self, url: &str) -> PathBuf {
+		let hash = Sha256::digest(url.as_bytes());
+		let mut name = String::with_capacity(hash.len() * 2);
+		for b in hash {
+			name.push_str(&format!("{b:02x}"))
+		}
+
+		self.directory.join(name)
+	}
+
+	/// Returns the cached response for the given URL, if any.
+	///
+	/// Unreadable or corrupted entries are ignored.
+	pub fn get(&self, url: &str) -> Option<CachedResponse> {
+		let content = fs::read(self.path(url)).ok()?;
+		let mut rest = content.as_slice();
+		let mut next_line = || {
+			let end = rest.iter().position(|b| *b == b'\n')?;
+			let line = &rest[..end];
+			rest = &rest[(end + 1)..];
+			Some(line)
+		};
+
+		if next_line()? != url.as_bytes() {
+			return None;
+		}
+
+		let stored_at = std::str::from_utf8(next_line()?).ok()?.parse().ok()?;
+		let stored_at = UNIX_EPOCH + Duration::from_secs(stored_at);
+
+		let mut headers = HeaderMap::new();
+		loop {
+			let line = next_line()?;
+			if line.is_empty() {
+				break;
+			}
+
+			let i = line.iter().position(|b| *b == b':')?;
+			let name = HeaderName::from_bytes(&line[..i]).ok()?;
+			let value = &line[(i + 1)..];
+			let value = HeaderValue::from_bytes(value.strip_prefix(b" ").unwrap_or(value)).ok()?;
+			headers.append(name, value);
+		}
+
+		let body = Bytes::copy_from_slice(rest);
+
+		Some(CachedResponse {
+			stored_at,
+			headers,
+			body,
+		})
+	}
+
+	/// Stores a response for the given URL.
+	pub fn insert(&self, url: &str, headers: &HeaderMap, body: &[u8]) -> io::Result<()> {
+		fs::create_dir_all(self.directory)?;
+
+		let stored_at = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_secs();
+
+		let mut content = Vec::with_capacity(body.len() + 512);
+		writeln!(content, "{url}")?;
+		writeln!(content, "{stored_at}")?;
+		for (name, value) in headers {
+			content.extend_from_slice(name.as_str().as_bytes());
+			content.extend_from_slice(b": ");
+			content.extend_from_slice(value.as_bytes());
+			content.push(b'\n');
+		}
+		content.push(b'\n');
+		content.extend_from_slice(body);
+
+		// Write to a temporary file first so that concurrent readers never
+		// observe a partially written entry. Each write uses its own
+		// temporary file so that concurrent writers do not interleave.
+		let path = self.path(url);
+		let tmp_path = path.with_extension(format!(
+			"{}.{}.tmp",
+			std::process::id(),
+			TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+		));
+
+		if let Err(e) = fs::write(&tmp_path, content) {
+			let _ = fs::remove_file(&tmp_path);
+			return Err(e);
+		}
+
+		fs::rename(&tmp_path, path).map_err(|e| {
+			let _ = fs::remove_file(&tmp_path);
+			e
+		})
+	}
+
+	/// Removes the cached response for the given URL, if any.
+	pub fn remove(&self, url: &str) {
+		let _ = fs::remove_file(self.path(url));
+	}
+}
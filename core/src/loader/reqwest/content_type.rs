@@ -107,6 +107,10 @@ impl ContentType {
 		self.media_type == "application/json" || self.media_type == "application/ld+json"
 	}
 
+	pub fn is_html(&self) -> bool {
+		self.media_type == "text/html" || self.media_type == "application/xhtml+xml"
+	}
+
 	pub fn media_type(&self) -> &Mime {
 		&self.media_type
 	}
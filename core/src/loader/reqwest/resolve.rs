@@ -0,0 +1,51 @@
+use crate::loader::policy::{Policy, Refusal};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::sync::Arc;
+
+/// DNS resolver discarding the addresses refused by a [`Policy`].
+///
+/// Fails with the [`Refusal`] if every address is refused.
+pub struct PolicyResolver {
+	policy: Arc<Policy>,
+}
+
+impl PolicyResolver {
+	pub fn new(policy: Policy) -> Self {
+		Self {
+			policy: Arc::new(policy),
+		}
+	}
+}
+
+impl Resolve for PolicyResolver {
+	fn resolve(&self, name: Name) -> Resolving {
+		Box::pin(resolve(self.policy.clone(), name))
+	}
+}
+
+async fn resolve(
+	policy: Arc<Policy>,
+	name: Name,
+) -> Result<Addrs, Box<dyn std::error::Error + Send + Sync>> {
+	let mut refusal = None;
+	let addrs: Vec<_> = tokio::net::lookup_host((name.as_str(), 0))
+		.await?
+		.filter(|addr| match policy.check_addr(addr.ip()) {
+			Ok(()) => true,
+			Err(reason) => {
+				log::warn!(
+					"refused address {} of {}: {reason}",
+					addr.ip(),
+					name.as_str()
+				);
+				refusal = Some(reason);
+				false
+			}
+		})
+		.collect();
+
+	match refusal {
+		Some(reason) if addrs.is_empty() => Err(reason.into()),
+		_ => Ok(Box::new(addrs.into_iter())),
+	}
+}
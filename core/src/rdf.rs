@@ -11,15 +11,24 @@ use rdf_types::{
 use smallvec::SmallVec;
 use static_iref::iri;
 
+pub mod canonicalization;
+mod from_rdf;
+pub mod nquads;
 mod quad;
+pub use canonicalization::{
+	canonicalize_with, CanonicalDataset, CanonicalizationError, Options as CanonicalizationOptions,
+};
+pub use from_rdf::*;
 pub use quad::*;
 
 pub const RDF_TYPE: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
 pub const RDF_FIRST: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#first");
 pub const RDF_REST: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#rest");
 pub const RDF_VALUE: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#value");
+pub const RDF_LANGUAGE: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#language");
 pub const RDF_DIRECTION: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#direction");
 pub const RDF_JSON: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON");
+pub const RDF_LIST: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#List");
 /// IRI of the `http://www.w3.org/1999/02/22-rdf-syntax-ns#nil` value.
 pub const RDF_NIL: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#nil");
 
@@ -0,0 +1,508 @@
+//! RDF Dataset Canonicalization.
+//!
+//! Implements the [RDFC-1.0] canonicalization algorithm (compatible with
+//! URDNA2015), giving canonical labels to the blank nodes of an RDF dataset so
+//! that two isomorphic datasets produce the same quads.
+//!
+//! [RDFC-1.0]: <https://www.w3.org/TR/rdf-canon/>
+use super::{nquads, Quad, ValidId, Value};
+use indexmap::IndexMap;
+use rdf_types::{
+	literal, BlankId, BlankIdVocabularyMut, IriVocabulary, LanguageTagVocabulary, Vocabulary,
+};
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, hash::Hash};
+
+/// Canonicalization options.
+///
+/// Some specially crafted datasets ("poison datasets") require an exponential
+/// number of steps to be canonicalized. The RDFC-1.0 specification requires
+/// implementations to guard against them by bounding the work done by the
+/// Hash N-Degree Quads algorithm. Canonicalization fails with
+/// [`CanonicalizationError::TooComplex`] when one of these bounds is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+	/// Maximum number of calls to the Hash N-Degree Quads algorithm, or
+	/// `None` for no limit.
+	///
+	/// Defaults to [`Options::DEFAULT_MAX_N_DEGREE_CALLS`].
+	pub max_n_degree_calls: Option<usize>,
+
+	/// Maximum number of blank nodes sharing the same related hash whose
+	/// permutations are explored, or `None` for no limit.
+	///
+	/// Defaults to [`Options::DEFAULT_MAX_PERMUTATION_LEN`].
+	pub max_permutation_len: Option<usize>,
+}
+
+impl Options {
+	/// Default maximum number of calls to the Hash N-Degree Quads algorithm.
+	///
+	/// The specification leaves the exact bound to implementations.
+	pub const DEFAULT_MAX_N_DEGREE_CALLS: usize = 4096;
+
+	/// Default maximum number of blank nodes whose permutations are explored.
+	///
+	/// This is `8!` permutations at most for each related hash.
+	pub const DEFAULT_MAX_PERMUTATION_LEN: usize = 8;
+
+	/// Options without any limit.
+	///
+	/// Canonicalization may then run (almost) forever on poison datasets.
+	pub fn unbounded() -> Self {
+		Self {
+			max_n_degree_calls: None,
+			max_permutation_len: None,
+		}
+	}
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Self {
+			max_n_degree_calls: Some(Self::DEFAULT_MAX_N_DEGREE_CALLS),
+			max_permutation_len: Some(Self::DEFAULT_MAX_PERMUTATION_LEN),
+		}
+	}
+}
+
+/// Canonicalization error.
+#[derive(Debug, thiserror::Error)]
+pub enum CanonicalizationError {
+	/// The dataset is too complex to be canonicalized.
+	#[error("dataset too complex to canonicalize")]
+	TooComplex,
+}
+
+/// Canonical RDF dataset.
+///
+/// Result of the [`canonicalize_with`] function.
+pub struct CanonicalDataset<T, B, L> {
+	quads: Vec<Quad<T, B, L>>,
+	nquads: String,
+	issued: IndexMap<B, B>,
+}
+
+impl<T, B, L> CanonicalDataset<T, B, L> {
+	/// Returns the canonically labeled quads, in canonical order.
+	pub fn quads(&self) -> &[Quad<T, B, L>] {
+		&self.quads
+	}
+
+	/// Turns this dataset into its canonically labeled quads, in canonical
+	/// order.
+	pub fn into_quads(self) -> Vec<Quad<T, B, L>> {
+		self.quads
+	}
+
+	/// Returns the canonical N-Quads serialization of the dataset.
+	pub fn as_nquads(&self) -> &str {
+		&self.nquads
+	}
+
+	/// Turns this dataset into its canonical N-Quads serialization.
+	pub fn into_nquads(self) -> String {
+		self.nquads
+	}
+
+	/// Returns the map from input blank node identifiers to their canonical
+	/// identifier.
+	pub fn issued_identifiers(&self) -> &IndexMap<B, B> {
+		&self.issued
+	}
+
+	/// Returns the SHA-256 hash of the canonical N-Quads serialization.
+	pub fn hash(&self) -> [u8; 32] {
+		Sha256::digest(self.nquads.as_bytes()).into()
+	}
+
+	/// Returns the SHA-256 hash of the canonical N-Quads serialization, as a
+	/// lowercase hexadecimal string.
+	pub fn hash_hex(&self) -> String {
+		hash(&self.nquads)
+	}
+}
+
+/// Canonicalizes the given RDF dataset using the RDFC-1.0 algorithm.
+///
+/// Canonical blank node identifiers (`_:c14n0`, `_:c14n1`, etc.) are inserted
+/// in the given vocabulary. The work done on datasets with many
+/// indistinguishable blank nodes is bounded by the given `options`.
+pub fn canonicalize_with<V>(
+	vocabulary: &mut V,
+	quads: impl IntoIterator<Item = Quad<V::Iri, V::BlankId, V::Literal>>,
+	options: Options,
+) -> Result<CanonicalDataset<V::Iri, V::BlankId, V::Literal>, CanonicalizationError>
+where
+	V: Vocabulary<
+			Type = literal::Type<
+				<V as IriVocabulary>::Iri,
+				<V as LanguageTagVocabulary>::LanguageTag,
+			>,
+		> + BlankIdVocabularyMut,
+	V::Iri: Clone,
+	V::BlankId: Clone + Eq + Hash,
+	V::Literal: Clone,
+	V::Value: AsRef<str>,
+{
+	let quads: Vec<_> = quads.into_iter().collect();
+	let canonical = Canonicalizer::new(vocabulary, &quads, options).run()?;
+
+	let mut lines: Vec<_> = quads
+		.iter()
+		.map(|quad| {
+			let mut line = String::new();
+			nquads::write_quad(
+				vocabulary,
+				quad,
+				|b| match canonical.get(b) {
+					Some(label) => label.to_owned(),
+					None => vocabulary.blank_id(b).unwrap().as_str()[2..].to_owned(),
+				},
+				&mut line,
+			);
+			(line, quad)
+		})
+		.collect();
+
+	lines.sort_by(|a, b| a.0.cmp(&b.0));
+	lines.dedup_by(|a, b| a.0 == b.0);
+
+	let issued: IndexMap<_, _> = canonical
+		.issued
+		.iter()
+		.map(|(b, label)| {
+			let id = format!("_:{label}");
+			(
+				b.clone(),
+				vocabulary.insert_blank_id(BlankId::new(&id).unwrap()),
+			)
+		})
+		.collect();
+
+	let relabel = |id: &ValidId<V::Iri, V::BlankId>| match id {
+		ValidId::Blank(b) => match issued.get(b) {
+			Some(c) => ValidId::Blank(c.clone()),
+			None => id.clone(),
+		},
+		id => id.clone(),
+	};
+
+	let mut nquads = String::new();
+	let mut canonical_quads = Vec::with_capacity(lines.len());
+	for (line, quad) in lines {
+		nquads.push_str(&line);
+		canonical_quads.push(rdf_types::Quad(
+			relabel(&quad.0),
+			relabel(&quad.1),
+			match &quad.2 {
+				Value::Id(id) => Value::Id(relabel(id)),
+				Value::Literal(l) => Value::Literal(l.clone()),
+			},
+			quad.3.as_ref().map(relabel),
+		))
+	}
+
+	Ok(CanonicalDataset {
+		quads: canonical_quads,
+		nquads,
+		issued,
+	})
+}
+
+/// Identifier issuer.
+#[derive(Clone)]
+struct IdentifierIssuer<B> {
+	prefix: &'static str,
+	issued: IndexMap<B, String>,
+}
+
+impl<B: Clone + Eq + Hash> IdentifierIssuer<B> {
+	fn new(prefix: &'static str) -> Self {
+		Self {
+			prefix,
+			issued: IndexMap::new(),
+		}
+	}
+
+	fn get(&self, id: &B) -> Option<&str> {
+		self.issued.get(id).map(String::as_str)
+	}
+
+	fn issue(&mut self, id: &B) -> String {
+		if let Some(label) = self.issued.get(id) {
+			return label.clone();
+		}
+
+		let label = format!("{}{}", self.prefix, self.issued.len());
+		self.issued.insert(id.clone(), label.clone());
+		label
+	}
+}
+
+struct Canonicalizer<'a, V: Vocabulary> {
+	vocabulary: &'a V,
+	quads: &'a [Quad<V::Iri, V::BlankId, V::Literal>],
+
+	/// Quads referencing each blank node, once per occurrence.
+	blank_node_to_quads: IndexMap<V::BlankId, Vec<usize>>,
+
+	first_degree_hashes: IndexMap<V::BlankId, String>,
+	canonical: IdentifierIssuer<V::BlankId>,
+	options: Options,
+	n_degree_calls: usize,
+}
+
+impl<'a, V> Canonicalizer<'a, V>
+where
+	V: Vocabulary<
+		Type = literal::Type<<V as IriVocabulary>::Iri, <V as LanguageTagVocabulary>::LanguageTag>,
+	>,
+	V::BlankId: Clone + Eq + Hash,
+	V::Value: AsRef<str>,
+{
+	fn new(
+		vocabulary: &'a V,
+		quads: &'a [Quad<V::Iri, V::BlankId, V::Literal>],
+		options: Options,
+	) -> Self {
+		let mut blank_node_to_quads: IndexMap<_, Vec<_>> = IndexMap::new();
+		for (i, quad) in quads.iter().enumerate() {
+			for component in components(quad) {
+				if let (_, ValidId::Blank(b)) = component {
+					blank_node_to_quads.entry(b.clone()).or_default().push(i)
+				}
+			}
+		}
+
+		Self {
+			vocabulary,
+			quads,
+			blank_node_to_quads,
+			first_degree_hashes: IndexMap::new(),
+			canonical: IdentifierIssuer::new("c14n"),
+			options,
+			n_degree_calls: 0,
+		}
+	}
+
+	fn run(mut self) -> Result<IdentifierIssuer<V::BlankId>, CanonicalizationError> {
+		let mut hash_to_blank_nodes: BTreeMap<String, Vec<V::BlankId>> = BTreeMap::new();
+		for b in self.blank_node_to_quads.keys() {
+			let hash = self.hash_first_degree_quads(b);
+			hash_to_blank_nodes.entry(hash).or_default().push(b.clone())
+		}
+
+		self.first_degree_hashes = hash_to_blank_nodes
+			.iter()
+			.flat_map(|(hash, ids)| ids.iter().map(|b| (b.clone(), hash.clone())))
+			.collect();
+
+		for ids in hash_to_blank_nodes.values() {
+			if let [b] = ids.as_slice() {
+				self.canonical.issue(b);
+			}
+		}
+
+		for ids in hash_to_blank_nodes.values() {
+			if ids.len() > 1 {
+				let mut hash_path_list = Vec::new();
+
+				for b in ids {
+					if self.canonical.get(b).is_none() {
+						let mut issuer = IdentifierIssuer::new("b");
+						issuer.issue(b);
+						hash_path_list.push(self.hash_n_degree_quads(b, issuer)?);
+					}
+				}
+
+				hash_path_list.sort_by(|a, b| a.0.cmp(&b.0));
+
+				for (_, issuer) in hash_path_list {
+					for b in issuer.issued.keys() {
+						self.canonical.issue(b);
+					}
+				}
+			}
+		}
+
+		Ok(self.canonical)
+	}
+
+	/// Hash First Degree Quads algorithm.
+	fn hash_first_degree_quads(&self, id: &V::BlankId) -> String {
+		let mut lines: Vec<_> = self.blank_node_to_quads[id]
+			.iter()
+			.map(|&i| {
+				let mut line = String::new();
+				nquads::write_quad(
+					self.vocabulary,
+					&self.quads[i],
+					|b| (if b == id { "a" } else { "z" }).to_owned(),
+					&mut line,
+				);
+				line
+			})
+			.collect();
+
+		lines.sort();
+		hash(&lines.concat())
+	}
+
+	/// Hash Related Blank Node algorithm.
+	fn hash_related_blank_node(
+		&self,
+		related: &V::BlankId,
+		quad: &Quad<V::Iri, V::BlankId, V::Literal>,
+		issuer: &IdentifierIssuer<V::BlankId>,
+		position: char,
+	) -> String {
+		let mut input = position.to_string();
+
+		if position != 'g' {
+			match &quad.1 {
+				ValidId::Iri(i) => {
+					input.push('<');
+					input.push_str(self.vocabulary.iri(i).unwrap().as_str());
+					input.push('>');
+				}
+				ValidId::Blank(b) => input.push_str(self.vocabulary.blank_id(b).unwrap().as_str()),
+			}
+		}
+
+		match self.canonical.get(related).or_else(|| issuer.get(related)) {
+			Some(label) => {
+				input.push_str("_:");
+				input.push_str(label)
+			}
+			None => input.push_str(&self.first_degree_hashes[related]),
+		}
+
+		hash(&input)
+	}
+
+	/// Hash N-Degree Quads algorithm.
+	fn hash_n_degree_quads(
+		&mut self,
+		id: &V::BlankId,
+		mut issuer: IdentifierIssuer<V::BlankId>,
+	) -> Result<(String, IdentifierIssuer<V::BlankId>), CanonicalizationError> {
+		self.n_degree_calls += 1;
+		if self
+			.options
+			.max_n_degree_calls
+			.map_or(false, |max| self.n_degree_calls > max)
+		{
+			return Err(CanonicalizationError::TooComplex);
+		}
+
+		let mut hash_to_related: BTreeMap<String, Vec<V::BlankId>> = BTreeMap::new();
+		for &i in &self.blank_node_to_quads[id] {
+			let quad = &self.quads[i];
+			for (position, component) in components(quad) {
+				if let ValidId::Blank(related) = component {
+					if related != id {
+						let hash = self.hash_related_blank_node(related, quad, &issuer, position);
+						hash_to_related
+							.entry(hash)
+							.or_default()
+							.push(related.clone())
+					}
+				}
+			}
+		}
+
+		let mut data_to_hash = String::new();
+
+		for (related_hash, mut related_ids) in hash_to_related {
+			if self
+				.options
+				.max_permutation_len
+				.map_or(false, |max| related_ids.len() > max)
+			{
+				return Err(CanonicalizationError::TooComplex);
+			}
+
+			data_to_hash.push_str(&related_hash);
+
+			let mut chosen: Option<(String, IdentifierIssuer<V::BlankId>)> = None;
+			let is_worse = |path: &str, chosen: &Option<(String, _)>| match chosen {
+				Some((chosen_path, _)) => {
+					path.len() >= chosen_path.len() && path > chosen_path.as_str()
+				}
+				None => false,
+			};
+
+			'permutations: for permutation in permutohedron::Heap::new(&mut related_ids) {
+				let mut issuer_copy = issuer.clone();
+				let mut path = String::new();
+				let mut recursion_list = Vec::new();
+
+				for related in &permutation {
+					path.push_str("_:");
+					match self.canonical.get(related) {
+						Some(label) => path.push_str(label),
+						None => {
+							if issuer_copy.get(related).is_none() {
+								recursion_list.push(related.clone())
+							}
+
+							path.push_str(&issuer_copy.issue(related))
+						}
+					}
+
+					if is_worse(path.as_str(), &chosen) {
+						continue 'permutations;
+					}
+				}
+
+				for related in recursion_list {
+					let (hash, result_issuer) =
+						self.hash_n_degree_quads(&related, issuer_copy.clone())?;
+					path.push_str("_:");
+					path.push_str(&issuer_copy.issue(&related));
+					path.push('<');
+					path.push_str(&hash);
+					path.push('>');
+					issuer_copy = result_issuer;
+
+					if is_worse(path.as_str(), &chosen) {
+						continue 'permutations;
+					}
+				}
+
+				if chosen
+					.as_ref()
+					.map_or(true, |(chosen_path, _)| path < *chosen_path)
+				{
+					chosen = Some((path, issuer_copy))
+				}
+			}
+
+			let (chosen_path, chosen_issuer) = chosen.unwrap();
+			data_to_hash.push_str(&chosen_path);
+			issuer = chosen_issuer;
+		}
+
+		Ok((hash(&data_to_hash), issuer))
+	}
+}
+
+/// Returns the subject, object and graph components of the given quad, with
+/// their position.
+fn components<T, B, L>(quad: &Quad<T, B, L>) -> impl Iterator<Item = (char, &ValidId<T, B>)> {
+	let object = match &quad.2 {
+		Value::Id(id) => Some(id),
+		Value::Literal(_) => None,
+	};
+
+	[('s', Some(&quad.0)), ('o', object), ('g', quad.3.as_ref())]
+		.into_iter()
+		.filter_map(|(position, id)| id.map(|id| (position, id)))
+}
+
+/// Computes the SHA-256 hash of the given string, as a lowercase hexadecimal
+/// string.
+fn hash(input: &str) -> String {
+	format!("{:x}", Sha256::digest(input.as_bytes()))
+}
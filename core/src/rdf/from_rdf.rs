@@ -0,0 +1,641 @@
+use super::{
+	Quad, RdfDirection, ValidId, Value as RdfValue, RDF_DIRECTION, RDF_FIRST, RDF_JSON,
+	RDF_LANGUAGE, RDF_LIST, RDF_NIL, RDF_REST, RDF_TYPE, RDF_VALUE, XSD_BOOLEAN, XSD_DOUBLE,
+	XSD_INTEGER, XSD_STRING,
+};
+use crate::{
+	object::{Graph, List, Literal},
+	Direction, ExpandedDocument, Id, Indexed, IndexedObject, LangString, LenientLanguageTagBuf,
+	Node, Object, ProcessingMode, Value,
+};
+use indexmap::{IndexMap, IndexSet};
+use iref::{Iri, IriBuf};
+use json_ld_syntax::ErrorCode;
+use json_syntax::{NumberBuf, Parse};
+use rdf_types::{literal, BlankIdBuf, IriVocabulary, LanguageTagVocabulary, Vocabulary};
+use std::{
+	collections::{hash_map::Entry, HashMap},
+	hash::Hash,
+	str::FromStr,
+};
+
+/// Prefix of the datatype IRIs used by the [`RdfDirection::I18nDatatype`]
+/// direction representation method.
+const I18N: &str = "https://www.w3.org/ns/i18n#";
+
+/// Options of the [Serialize RDF as JSON-LD algorithm][1].
+///
+/// [1]: <https://www.w3.org/TR/json-ld11-api/#serialize-rdf-as-json-ld-algorithm>
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FromRdfOptions {
+	/// Processing mode.
+	///
+	/// `rdf:JSON` literals are turned into JSON literals only in the
+	/// JSON-LD 1.1 processing mode.
+	pub processing_mode: ProcessingMode,
+
+	/// Converts `xsd:boolean`, `xsd:integer` and `xsd:double` literals into
+	/// native JSON booleans and numbers.
+	pub use_native_types: bool,
+
+	/// Keeps `rdf:type` triples as regular properties instead of turning them
+	/// into `@type` entries.
+	pub use_rdf_type: bool,
+
+	/// Method used to decode the base direction of strings, if any.
+	pub rdf_direction: Option<RdfDirection>,
+}
+
+/// Error that can be raised while serializing an RDF dataset as JSON-LD.
+#[derive(Debug, thiserror::Error)]
+pub enum FromRdfError {
+	/// An `rdf:JSON` literal is not a valid JSON value.
+	#[error("invalid JSON literal: {0}")]
+	InvalidJsonLiteral(json_syntax::parse::Error),
+
+	/// The `rdf:direction` of a compound literal is neither `ltr` nor `rtl`.
+	#[error("invalid base direction `{0}`")]
+	InvalidBaseDirection(String),
+}
+
+impl FromRdfError {
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::InvalidJsonLiteral(_) => ErrorCode::InvalidJsonLiteral,
+			Self::InvalidBaseDirection(_) => ErrorCode::InvalidBaseDirection,
+		}
+	}
+}
+
+impl<T, B> ExpandedDocument<T, B> {
+	/// Serializes the given RDF dataset as an expanded JSON-LD document using
+	/// the [Serialize RDF as JSON-LD algorithm][1].
+	///
+	/// Unlike [`LinkedDataDeserialize`](linked_data::LinkedDataDeserialize),
+	/// this follows the `useNativeTypes`, `useRdfType` and `rdfDirection`
+	/// options of the JSON-LD API so that the output is identical to the one
+	/// of other processors.
+	///
+	/// [1]: <https://www.w3.org/TR/json-ld11-api/#serialize-rdf-as-json-ld-algorithm>
+	pub fn from_rdf_quads_with<V>(
+		vocabulary: &V,
+		quads: impl IntoIterator<Item = Quad<T, B, V::Literal>>,
+		options: FromRdfOptions,
+	) -> Result<Self, FromRdfError>
+	where
+		V: Vocabulary<
+			Iri = T,
+			BlankId = B,
+			Type = literal::Type<T, <V as LanguageTagVocabulary>::LanguageTag>,
+		>,
+		V::Value: AsRef<str>,
+		T: Clone + Eq + Hash,
+		B: Clone + Eq + Hash,
+	{
+		let mut node_map = PendingNodeMap::new();
+
+		for quad in quads {
+			node_map.insert(vocabulary, quad, options)?;
+		}
+
+		let names: Vec<_> = node_map.graphs.keys().cloned().collect();
+		for name in &names {
+			if options.rdf_direction == Some(RdfDirection::CompoundLiteral) {
+				node_map.convert_compound_literals(vocabulary, name)?;
+			}
+
+			node_map.convert_lists(vocabulary, name);
+		}
+
+		Ok(node_map.into_document())
+	}
+}
+
+impl ExpandedDocument {
+	/// Serializes the given RDF dataset as an expanded JSON-LD document using
+	/// the [Serialize RDF as JSON-LD algorithm][1].
+	///
+	/// [1]: <https://www.w3.org/TR/json-ld11-api/#serialize-rdf-as-json-ld-algorithm>
+	pub fn from_rdf_quads(
+		quads: impl IntoIterator<Item = Quad<IriBuf, BlankIdBuf, rdf_types::Literal>>,
+		options: FromRdfOptions,
+	) -> Result<Self, FromRdfError> {
+		Self::from_rdf_quads_with(&(), quads, options)
+	}
+}
+
+/// Node map object, before rendering.
+#[derive(PartialEq)]
+enum PendingObject<T, B> {
+	/// Value object.
+	Value(Value<T>),
+
+	/// Node reference.
+	Reference(ValidId<T, B>),
+
+	/// List object, referencing its items by index.
+	List(Vec<usize>),
+}
+
+impl<T, B> PendingObject<T, B> {
+	fn as_str(&self) -> Option<&str> {
+		match self {
+			Self::Value(Value::Literal(Literal::String(s), _)) => Some(s.as_str()),
+			Self::Value(Value::LangString(s)) => Some(s.as_str()),
+			_ => None,
+		}
+	}
+}
+
+/// Node object of the node map, before rendering.
+struct PendingNode<T, B> {
+	types: Vec<ValidId<T, B>>,
+	properties: IndexMap<ValidId<T, B>, Vec<usize>>,
+}
+
+impl<T, B> Default for PendingNode<T, B> {
+	fn default() -> Self {
+		Self {
+			types: Vec::new(),
+			properties: IndexMap::new(),
+		}
+	}
+}
+
+impl<T, B> PendingNode<T, B> {
+	fn is_empty(&self) -> bool {
+		self.types.is_empty() && self.properties.is_empty()
+	}
+
+	fn property<V: IriVocabulary<Iri = T>>(&self, vocabulary: &V, iri: &Iri) -> &[usize] {
+		self.properties
+			.iter()
+			.find(|(p, _)| is_iri(vocabulary, p, iri))
+			.map(|(_, values)| values.as_slice())
+			.unwrap_or_default()
+	}
+}
+
+/// Location of a value in the node map.
+#[derive(Clone)]
+struct Usage<T, B> {
+	graph: Option<ValidId<T, B>>,
+	node: ValidId<T, B>,
+	property: ValidId<T, B>,
+	value: usize,
+}
+
+struct PendingGraph<T, B> {
+	nodes: IndexMap<ValidId<T, B>, PendingNode<T, B>>,
+
+	/// Usages of `rdf:nil`.
+	nil_usages: Vec<Usage<T, B>>,
+
+	/// Subjects of an `rdf:direction` property.
+	compound_literals: IndexSet<ValidId<T, B>>,
+}
+
+impl<T, B> Default for PendingGraph<T, B> {
+	fn default() -> Self {
+		Self {
+			nodes: IndexMap::new(),
+			nil_usages: Vec::new(),
+			compound_literals: IndexSet::new(),
+		}
+	}
+}
+
+struct PendingNodeMap<T, B> {
+	/// Every object of the node map.
+	objects: Vec<PendingObject<T, B>>,
+
+	graphs: IndexMap<Option<ValidId<T, B>>, PendingGraph<T, B>>,
+
+	/// Blank node identifiers used as object, with their usage if they are
+	/// used exactly once.
+	referenced_once: HashMap<ValidId<T, B>, Option<Usage<T, B>>>,
+}
+
+impl<T: Clone + Eq + Hash, B: Clone + Eq + Hash> PendingNodeMap<T, B> {
+	fn new() -> Self {
+		let mut graphs = IndexMap::new();
+		graphs.insert(None, PendingGraph::default());
+
+		Self {
+			objects: Vec::new(),
+			graphs,
+			referenced_once: HashMap::new(),
+		}
+	}
+
+	fn insert<V>(
+		&mut self,
+		vocabulary: &V,
+		rdf_types::Quad(subject, predicate, object, graph): Quad<T, B, V::Literal>,
+		options: FromRdfOptions,
+	) -> Result<(), FromRdfError>
+	where
+		V: Vocabulary<
+			Iri = T,
+			BlankId = B,
+			Type = literal::Type<T, <V as LanguageTagVocabulary>::LanguageTag>,
+		>,
+		V::Value: AsRef<str>,
+	{
+		if let Some(name) = &graph {
+			self.graphs[&None::<ValidId<T, B>>]
+				.nodes
+				.entry(name.clone())
+				.or_default();
+		}
+
+		let g = self.graphs.entry(graph.clone()).or_default();
+		g.nodes.entry(subject.clone()).or_default();
+
+		if options.rdf_direction == Some(RdfDirection::CompoundLiteral)
+			&& is_iri(vocabulary, &predicate, RDF_DIRECTION)
+		{
+			g.compound_literals.insert(subject.clone());
+		}
+
+		match object {
+			RdfValue::Id(object) => {
+				g.nodes.entry(object.clone()).or_default();
+
+				if !options.use_rdf_type && is_iri(vocabulary, &predicate, RDF_TYPE) {
+					let node = &mut g.nodes[&subject];
+					if !node.types.contains(&object) {
+						node.types.push(object)
+					}
+
+					return Ok(());
+				}
+
+				let is_nil = is_iri(vocabulary, &object, RDF_NIL);
+				let value = insert_value(
+					&mut self.objects,
+					&mut g.nodes[&subject],
+					predicate.clone(),
+					PendingObject::Reference(object.clone()),
+				);
+
+				let usage = Usage {
+					graph,
+					node: subject,
+					property: predicate,
+					value,
+				};
+
+				if is_nil {
+					g.nil_usages.push(usage)
+				} else if object.is_blank() {
+					match self.referenced_once.entry(object) {
+						Entry::Occupied(mut entry) => *entry.get_mut() = None,
+						Entry::Vacant(entry) => {
+							entry.insert(Some(usage));
+						}
+					}
+				}
+			}
+			RdfValue::Literal(literal) => {
+				let value = literal_to_value(vocabulary, &literal, options)?;
+				insert_value(
+					&mut self.objects,
+					&mut g.nodes[&subject],
+					predicate,
+					PendingObject::Value(value),
+				);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Replaces the compound literals of the given graph by value objects.
+	fn convert_compound_literals<V: IriVocabulary<Iri = T>>(
+		&mut self,
+		vocabulary: &V,
+		name: &Option<ValidId<T, B>>,
+	) -> Result<(), FromRdfError> {
+		let graph = &mut self.graphs[name];
+
+		for id in std::mem::take(&mut graph.compound_literals) {
+			let usage = match self.referenced_once.get(&id) {
+				Some(Some(usage)) if usage.graph == *name => usage,
+				_ => continue,
+			};
+
+			let node = &graph.nodes[&id];
+			let string = match node
+				.property(vocabulary, RDF_VALUE)
+				.first()
+				.and_then(|&i| self.objects[i].as_str())
+			{
+				Some(string) => string,
+				None => continue,
+			};
+
+			let language = node
+				.property(vocabulary, RDF_LANGUAGE)
+				.first()
+				.and_then(|&i| self.objects[i].as_str())
+				.map(|language| LenientLanguageTagBuf::new(language.to_owned()).0);
+
+			let direction = match node
+				.property(vocabulary, RDF_DIRECTION)
+				.first()
+				.and_then(|&i| self.objects[i].as_str())
+			{
+				Some(direction) => Some(
+					Direction::try_from(direction)
+						.map_err(|_| FromRdfError::InvalidBaseDirection(direction.to_owned()))?,
+				),
+				None => None,
+			};
+
+			let value = match LangString::new(string.into(), language, direction) {
+				Ok(lang_string) => Value::LangString(lang_string),
+				Err(string) => Value::Literal(Literal::String(string), None),
+			};
+
+			self.objects[usage.value] = PendingObject::Value(value);
+			graph.nodes.shift_remove(&id);
+		}
+
+		Ok(())
+	}
+
+	/// Replaces the well-formed lists of the given graph by list objects.
+	fn convert_lists<V: IriVocabulary<Iri = T>>(
+		&mut self,
+		vocabulary: &V,
+		name: &Option<ValidId<T, B>>,
+	) {
+		let graph = &mut self.graphs[name];
+
+		for usage in std::mem::take(&mut graph.nil_usages) {
+			let mut node_id = usage.node;
+			let mut property = usage.property;
+			let mut head = usage.value;
+			let mut list = Vec::new();
+			let mut list_nodes = Vec::new();
+
+			while is_iri(vocabulary, &property, RDF_REST) {
+				let first = match graph.nodes.get(&node_id) {
+					Some(node) => match list_node_first(vocabulary, &node_id, node) {
+						Some(first) => first,
+						None => break,
+					},
+					None => break,
+				};
+
+				let node_usage = match self.referenced_once.get(&node_id) {
+					Some(Some(node_usage)) if node_usage.graph == *name => node_usage,
+					_ => break,
+				};
+
+				list.push(first);
+				list_nodes.push(node_id);
+
+				node_id = node_usage.node.clone();
+				property = node_usage.property.clone();
+				head = node_usage.value;
+
+				if !node_id.is_blank() {
+					break;
+				}
+			}
+
+			list.reverse();
+			self.objects[head] = PendingObject::List(list);
+
+			for id in list_nodes {
+				graph.nodes.shift_remove(&id);
+			}
+		}
+	}
+
+	fn into_document(mut self) -> ExpandedDocument<T, B> {
+		let default_graph = self
+			.graphs
+			.shift_remove(&None::<ValidId<T, B>>)
+			.unwrap_or_default();
+		let mut result = ExpandedDocument::new();
+
+		for (id, node) in default_graph.nodes {
+			let graph = self.graphs.shift_remove(&Some(id.clone()));
+
+			if node.is_empty() && graph.is_none() {
+				continue;
+			}
+
+			let mut node = self.render_node(id, node);
+
+			if let Some(graph) = graph {
+				let mut value = Graph::new();
+
+				for (id, node) in graph.nodes {
+					if !node.is_empty() {
+						value.insert(Indexed::none(Object::node(self.render_node(id, node))));
+					}
+				}
+
+				node.graph = Some(value)
+			}
+
+			result.insert(Indexed::none(Object::node(node)));
+		}
+
+		result
+	}
+
+	fn render_node(&self, id: ValidId<T, B>, pending: PendingNode<T, B>) -> Node<T, B> {
+		let mut node = Node::with_id(Id::Valid(id));
+
+		if !pending.types.is_empty() {
+			node.types = Some(pending.types.into_iter().map(Id::Valid).collect())
+		}
+
+		for (prop, values) in pending.properties {
+			for i in values {
+				node.insert(Id::Valid(prop.clone()), self.render_object(i))
+			}
+		}
+
+		node
+	}
+
+	fn render_object(&self, i: usize) -> IndexedObject<T, B> {
+		match &self.objects[i] {
+			PendingObject::Value(value) => Indexed::none(Object::Value(value.clone())),
+			PendingObject::Reference(id) => {
+				Indexed::none(Object::node(Node::with_id(Id::Valid(id.clone()))))
+			}
+			PendingObject::List(items) => Indexed::none(Object::List(List::new(
+				items.iter().map(|&i| self.render_object(i)).collect(),
+			))),
+		}
+	}
+}
+
+/// Inserts a value in the given node, unless it is already present.
+///
+/// Returns the index of the value object.
+fn insert_value<T: Eq + Hash, B: Eq + Hash>(
+	objects: &mut Vec<PendingObject<T, B>>,
+	node: &mut PendingNode<T, B>,
+	property: ValidId<T, B>,
+	object: PendingObject<T, B>,
+) -> usize {
+	let values = node.properties.entry(property).or_default();
+	match values.iter().find(|&&i| objects[i] == object) {
+		Some(&i) => i,
+		None => {
+			let i = objects.len();
+			objects.push(object);
+			values.push(i);
+			i
+		}
+	}
+}
+
+fn is_iri<V: IriVocabulary, B>(vocabulary: &V, id: &ValidId<V::Iri, B>, iri: &Iri) -> bool {
+	match id {
+		ValidId::Iri(i) => vocabulary.iri(i).unwrap() == iri,
+		ValidId::Blank(_) => false,
+	}
+}
+
+/// Returns the `rdf:first` value of the given node if it is a well-formed
+/// list node.
+fn list_node_first<V: IriVocabulary, B>(
+	vocabulary: &V,
+	id: &ValidId<V::Iri, B>,
+	node: &PendingNode<V::Iri, B>,
+) -> Option<usize> {
+	let is_well_formed = id.is_blank()
+		&& node.types.len() <= 1
+		&& node.types.iter().all(|ty| is_iri(vocabulary, ty, RDF_LIST))
+		&& node.properties.len() == 2
+		&& node.property(vocabulary, RDF_REST).len() == 1;
+
+	match node.property(vocabulary, RDF_FIRST) {
+		[first] if is_well_formed => Some(*first),
+		_ => None,
+	}
+}
+
+/// Converts an RDF literal into a value object.
+fn literal_to_value<V>(
+	vocabulary: &V,
+	literal: &V::Literal,
+	options: FromRdfOptions,
+) -> Result<Value<V::Iri>, FromRdfError>
+where
+	V: Vocabulary<
+		Type = literal::Type<<V as IriVocabulary>::Iri, <V as LanguageTagVocabulary>::LanguageTag>,
+	>,
+	V::Iri: Clone,
+	V::Value: AsRef<str>,
+{
+	let literal = vocabulary.literal(literal).unwrap();
+	let value = literal.value().as_ref();
+
+	match literal.type_() {
+		literal::Type::LangString(tag) => {
+			let tag = vocabulary.language_tag(tag).unwrap();
+			Ok(Value::LangString(
+				LangString::new(value.into(), Some(tag.cloned().into()), None).unwrap(),
+			))
+		}
+		literal::Type::Any(ty) => {
+			let ty_iri = vocabulary.iri(ty).unwrap();
+
+			if ty_iri == XSD_STRING {
+				return Ok(Value::Literal(Literal::String(value.into()), None));
+			}
+
+			if options.use_native_types {
+				let native = if ty_iri == XSD_BOOLEAN {
+					match value {
+						"true" => Some(Literal::Boolean(true)),
+						"false" => Some(Literal::Boolean(false)),
+						_ => None,
+					}
+				} else if ty_iri == XSD_INTEGER {
+					native_integer(value).map(Literal::Number)
+				} else if ty_iri == XSD_DOUBLE {
+					native_double(value).map(Literal::Number)
+				} else {
+					None
+				};
+
+				if let Some(native) = native {
+					return Ok(Value::Literal(native, None));
+				}
+			}
+
+			if ty_iri == RDF_JSON && options.processing_mode == ProcessingMode::JsonLd1_1 {
+				let (json, _) = json_syntax::Value::parse_str(value)
+					.map_err(FromRdfError::InvalidJsonLiteral)?;
+				return Ok(Value::Json(json));
+			}
+
+			if options.rdf_direction == Some(RdfDirection::I18nDatatype) {
+				if let Some(lang_string) = ty_iri
+					.as_str()
+					.strip_prefix(I18N)
+					.and_then(|suffix| i18n_lang_string(value, suffix))
+				{
+					return Ok(Value::LangString(lang_string));
+				}
+			}
+
+			Ok(Value::Literal(
+				Literal::String(value.into()),
+				Some(ty.clone()),
+			))
+		}
+	}
+}
+
+/// Decodes a string with the given `https://www.w3.org/ns/i18n#` datatype
+/// IRI suffix, of the form `language_direction` or `direction`.
+fn i18n_lang_string(value: &str, suffix: &str) -> Option<LangString> {
+	let (language, direction) = suffix.split_once('_').unwrap_or(("", suffix));
+	let direction = Direction::try_from(direction).ok()?;
+	let language = if language.is_empty() {
+		None
+	} else {
+		Some(LenientLanguageTagBuf::new(language.to_owned()).0)
+	};
+
+	LangString::new(value.into(), language, Some(direction)).ok()
+}
+
+/// Converts an `xsd:integer` value into a number, if it is in canonical
+/// form (`-?[0-9]+` without leading zeros).
+///
+/// The number keeps all the digits of the value, whatever its size.
+fn native_integer(value: &str) -> Option<NumberBuf> {
+	let digits = value.strip_prefix('-').unwrap_or(value);
+	if !digits.is_empty() && digits.bytes().all(|c| c.is_ascii_digit()) {
+		// Leading zeros are rejected by the JSON number syntax.
+		NumberBuf::from_str(value).ok()
+	} else {
+		None
+	}
+}
+
+fn native_double(value: &str) -> Option<NumberBuf> {
+	let is_lexical = value
+		.bytes()
+		.all(|c| c.is_ascii_digit() || matches!(c, b'+' | b'-' | b'.' | b'e' | b'E'));
+
+	if is_lexical {
+		let n: f64 = value.parse().ok().filter(|n: &f64| n.is_finite())?;
+		NumberBuf::from_str(ryu_js::Buffer::new().format_finite(n)).ok()
+	} else {
+		None
+	}
+}
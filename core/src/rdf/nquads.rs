@@ -0,0 +1,433 @@
+//! N-Quads serialization and parsing.
+//!
+//! Quads are written in the [canonical N-Quads form][1], so the output of
+//! [`write_quads`] is stable and can be compared or hashed.
+//!
+//! [1]: <https://www.w3.org/TR/rdf-canon/#canonical-quads>
+use super::{i18n, Literal, Quad, ValidId, Value, XSD_STRING};
+use crate::Direction;
+use iref::IriBuf;
+use langtag::LanguageTagBuf;
+use rdf_types::{
+	literal, BlankIdBuf, BlankIdVocabulary, IriVocabulary, LanguageTagVocabulary, Vocabulary,
+	VocabularyMut,
+};
+use std::{fmt::Write, io, iter::Peekable, str::Chars};
+
+/// Writes the given quads in N-Quads form, one per line.
+pub fn write_quads<V>(
+	vocabulary: &V,
+	quads: impl IntoIterator<Item = Quad<V::Iri, V::BlankId, V::Literal>>,
+	mut out: impl io::Write,
+) -> io::Result<()>
+where
+	V: Vocabulary<
+		Type = literal::Type<<V as IriVocabulary>::Iri, <V as LanguageTagVocabulary>::LanguageTag>,
+	>,
+	V::Value: AsRef<str>,
+{
+	let mut line = String::new();
+	for quad in quads {
+		line.clear();
+		write_quad(vocabulary, &quad, |b| blank_label(vocabulary, b), &mut line);
+		out.write_all(line.as_bytes())?
+	}
+
+	Ok(())
+}
+
+/// Serializes the given quads in N-Quads form, one per line.
+pub fn to_nquads_string<V>(
+	vocabulary: &V,
+	quads: impl IntoIterator<Item = Quad<V::Iri, V::BlankId, V::Literal>>,
+) -> String
+where
+	V: Vocabulary<
+		Type = literal::Type<<V as IriVocabulary>::Iri, <V as LanguageTagVocabulary>::LanguageTag>,
+	>,
+	V::Value: AsRef<str>,
+{
+	let mut result = String::new();
+	for quad in quads {
+		write_quad(
+			vocabulary,
+			&quad,
+			|b| blank_label(vocabulary, b),
+			&mut result,
+		);
+	}
+
+	result
+}
+
+fn blank_label<V: BlankIdVocabulary>(vocabulary: &V, b: &V::BlankId) -> String {
+	vocabulary.blank_id(b).unwrap().as_str()[2..].to_owned()
+}
+
+/// Writes the given quad in canonical N-Quads form, followed by a new line.
+///
+/// Blank node identifiers are written using the given `label` function,
+/// which must return the identifier without its `_:` prefix.
+pub(crate) fn write_quad<V>(
+	vocabulary: &V,
+	quad: &Quad<V::Iri, V::BlankId, V::Literal>,
+	mut label: impl FnMut(&V::BlankId) -> String,
+	out: &mut String,
+) where
+	V: Vocabulary<
+		Type = literal::Type<<V as IriVocabulary>::Iri, <V as LanguageTagVocabulary>::LanguageTag>,
+	>,
+	V::Value: AsRef<str>,
+{
+	write_id(vocabulary, &quad.0, &mut label, out);
+	out.push(' ');
+	write_id(vocabulary, &quad.1, &mut label, out);
+	out.push(' ');
+
+	match &quad.2 {
+		Value::Id(id) => write_id(vocabulary, id, &mut label, out),
+		Value::Literal(l) => {
+			let l = vocabulary.literal(l).unwrap();
+			write_string(l.value().as_ref(), out);
+
+			match l.type_() {
+				literal::Type::Any(ty) => {
+					let ty = vocabulary.iri(ty).unwrap();
+					if ty != XSD_STRING {
+						write!(out, "^^<{ty}>").unwrap();
+					}
+				}
+				literal::Type::LangString(tag) => {
+					let tag = vocabulary.language_tag(tag).unwrap();
+					write!(out, "@{}", tag.as_str()).unwrap();
+				}
+			}
+		}
+	}
+
+	if let Some(graph) = &quad.3 {
+		out.push(' ');
+		write_id(vocabulary, graph, &mut label, out);
+	}
+
+	out.push_str(" .\n");
+}
+
+fn write_id<V: IriVocabulary + BlankIdVocabulary>(
+	vocabulary: &V,
+	id: &ValidId<V::Iri, V::BlankId>,
+	label: &mut impl FnMut(&V::BlankId) -> String,
+	out: &mut String,
+) {
+	match id {
+		ValidId::Iri(i) => write!(out, "<{}>", vocabulary.iri(i).unwrap()).unwrap(),
+		ValidId::Blank(b) => write!(out, "_:{}", label(b)).unwrap(),
+	}
+}
+
+/// Writes a quoted string literal, escaped as specified by the canonical
+/// N-Quads form.
+fn write_string(s: &str, out: &mut String) {
+	out.push('"');
+
+	for c in s.chars() {
+		match c {
+			'\u{8}' => out.push_str("\\b"),
+			'\t' => out.push_str("\\t"),
+			'\n' => out.push_str("\\n"),
+			'\u{c}' => out.push_str("\\f"),
+			'\r' => out.push_str("\\r"),
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\u{0}'..='\u{1f}' | '\u{7f}' => write!(out, "\\u{:04X}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+
+	out.push('"');
+}
+
+/// N-Quads parse error.
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+	/// Unexpected character.
+	#[error("unexpected character `{1}` at line {0}")]
+	Unexpected(usize, char),
+
+	/// Unexpected end of line.
+	#[error("unexpected end of line {0}")]
+	UnexpectedEnd(usize),
+
+	/// Invalid escape sequence.
+	#[error("invalid escape sequence at line {0}")]
+	InvalidEscape(usize),
+
+	/// Invalid IRI.
+	#[error("invalid IRI `{1}` at line {0}")]
+	InvalidIri(usize, String),
+
+	/// Invalid blank node identifier.
+	#[error("invalid blank node identifier `{1}` at line {0}")]
+	InvalidBlankId(usize, String),
+
+	/// Invalid language tag.
+	#[error("invalid language tag `{1}` at line {0}")]
+	InvalidLanguageTag(usize, String),
+}
+
+/// Parses an N-Quads document.
+pub fn parse_str(
+	content: &str,
+) -> Result<Vec<Quad<IriBuf, BlankIdBuf, rdf_types::Literal>>, ParseError> {
+	parse_str_with(rdf_types::vocabulary::no_vocabulary_mut(), content)
+}
+
+/// Parses an N-Quads document, inserting its terms in the given vocabulary.
+///
+/// Strings with a base direction, written `"value"@language--direction` as
+/// defined by RDF 1.2, are turned into literals typed with the
+/// `https://www.w3.org/ns/i18n#language_direction` datatype, as produced by
+/// the [`RdfDirection::I18nDatatype`](super::RdfDirection::I18nDatatype)
+/// method.
+pub fn parse_str_with<V>(
+	vocabulary: &mut V,
+	content: &str,
+) -> Result<Vec<Quad<V::Iri, V::BlankId, V::Literal>>, ParseError>
+where
+	V: VocabularyMut<
+		Type = literal::Type<<V as IriVocabulary>::Iri, <V as LanguageTagVocabulary>::LanguageTag>,
+		Value = String,
+	>,
+{
+	let mut quads = Vec::new();
+
+	for (i, line) in content.lines().enumerate() {
+		let mut parser = Parser {
+			vocabulary: &mut *vocabulary,
+			chars: line.chars().peekable(),
+			pushed_back: None,
+			line: i + 1,
+		};
+
+		if let Some(quad) = parser.quad()? {
+			quads.push(quad)
+		}
+	}
+
+	Ok(quads)
+}
+
+/// N-Quads line parser.
+struct Parser<'a, 's, V> {
+	vocabulary: &'a mut V,
+	chars: Peekable<Chars<'s>>,
+	pushed_back: Option<char>,
+	line: usize,
+}
+
+impl<'a, 's, V> Parser<'a, 's, V>
+where
+	V: VocabularyMut<
+		Type = literal::Type<<V as IriVocabulary>::Iri, <V as LanguageTagVocabulary>::LanguageTag>,
+		Value = String,
+	>,
+{
+	fn peek(&mut self) -> Option<char> {
+		self.pushed_back.or_else(|| self.chars.peek().copied())
+	}
+
+	fn next(&mut self) -> Result<char, ParseError> {
+		self.pushed_back
+			.take()
+			.or_else(|| self.chars.next())
+			.ok_or(ParseError::UnexpectedEnd(self.line))
+	}
+
+	fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+		match self.next()? {
+			c if c == expected => Ok(()),
+			c => Err(ParseError::Unexpected(self.line, c)),
+		}
+	}
+
+	fn skip_whitespaces(&mut self) {
+		while matches!(self.peek(), Some(' ' | '\t')) {
+			self.chars.next();
+		}
+	}
+
+	/// Parses the quad of the line, if any.
+	fn quad(&mut self) -> Result<Option<Quad<V::Iri, V::BlankId, V::Literal>>, ParseError> {
+		self.skip_whitespaces();
+		if matches!(self.peek(), None | Some('#')) {
+			return Ok(None);
+		}
+
+		let subject = self.id()?;
+		self.skip_whitespaces();
+		let predicate = self.id()?;
+		self.skip_whitespaces();
+		let object = match self.peek() {
+			Some('"') => Value::Literal(self.literal()?),
+			_ => Value::Id(self.id()?),
+		};
+		self.skip_whitespaces();
+		let graph = match self.peek() {
+			Some('.') => None,
+			_ => Some(self.id()?),
+		};
+		self.skip_whitespaces();
+		self.expect('.')?;
+		self.skip_whitespaces();
+
+		match self.peek() {
+			None | Some('#') => Ok(Some(rdf_types::Quad(subject, predicate, object, graph))),
+			Some(c) => Err(ParseError::Unexpected(self.line, c)),
+		}
+	}
+
+	fn id(&mut self) -> Result<ValidId<V::Iri, V::BlankId>, ParseError> {
+		match self.peek() {
+			Some('<') => Ok(ValidId::Iri(self.iri()?)),
+			Some('_') => Ok(ValidId::Blank(self.blank_id()?)),
+			Some(c) => Err(ParseError::Unexpected(self.line, c)),
+			None => Err(ParseError::UnexpectedEnd(self.line)),
+		}
+	}
+
+	fn iri(&mut self) -> Result<V::Iri, ParseError> {
+		self.expect('<')?;
+
+		let mut iri = String::new();
+		loop {
+			match self.next()? {
+				'>' => break,
+				'\\' => iri.push(self.unicode_escape()?),
+				c => iri.push(c),
+			}
+		}
+
+		let iri = IriBuf::new(iri).map_err(|e| ParseError::InvalidIri(self.line, e.0))?;
+		Ok(self.vocabulary.insert(iri.as_iri()))
+	}
+
+	fn blank_id(&mut self) -> Result<V::BlankId, ParseError> {
+		self.expect('_')?;
+		self.expect(':')?;
+
+		let mut id = "_:".to_owned();
+		while let Some(c) = self.peek() {
+			if c.is_whitespace() || matches!(c, '<' | '"' | '#') {
+				break;
+			}
+
+			id.push(c);
+			self.chars.next();
+		}
+
+		// A blank node identifier cannot end with a `.`, which is the end of
+		// the statement.
+		if id.ends_with('.') {
+			id.pop();
+			self.pushed_back = Some('.')
+		}
+
+		let id = BlankIdBuf::new(id).map_err(|e| ParseError::InvalidBlankId(self.line, e.0))?;
+		Ok(self.vocabulary.insert_blank_id(&id))
+	}
+
+	fn literal(&mut self) -> Result<V::Literal, ParseError> {
+		self.expect('"')?;
+
+		let mut value = String::new();
+		loop {
+			match self.next()? {
+				'"' => break,
+				'\\' => {
+					let c = match self.peek() {
+						Some('u' | 'U') => self.unicode_escape()?,
+						_ => match self.next()? {
+							't' => '\t',
+							'b' => '\u{8}',
+							'n' => '\n',
+							'r' => '\r',
+							'f' => '\u{c}',
+							c @ ('"' | '\'' | '\\') => c,
+							_ => return Err(ParseError::InvalidEscape(self.line)),
+						},
+					};
+
+					value.push(c)
+				}
+				c => value.push(c),
+			}
+		}
+
+		let ty = match self.peek() {
+			Some('@') => {
+				self.chars.next();
+				let mut tag = String::new();
+				while let Some(c) = self.peek() {
+					if c.is_ascii_alphanumeric() || c == '-' {
+						tag.push(c);
+						self.chars.next();
+					} else {
+						break;
+					}
+				}
+
+				match tag.split_once("--") {
+					Some((language, direction)) => {
+						let direction = Direction::try_from(direction)
+							.map_err(|_| ParseError::InvalidLanguageTag(self.line, tag.clone()))?;
+						let language = self.language_tag(language.to_owned())?;
+						literal::Type::Any(
+							self.vocabulary
+								.insert(i18n(Some(language), direction).as_iri()),
+						)
+					}
+					None => {
+						let tag = self.language_tag(tag)?;
+						literal::Type::LangString(self.vocabulary.insert_owned_language_tag(tag))
+					}
+				}
+			}
+			Some('^') => {
+				self.chars.next();
+				self.expect('^')?;
+				literal::Type::Any(self.iri()?)
+			}
+			_ => literal::Type::Any(self.vocabulary.insert(XSD_STRING)),
+		};
+
+		Ok(self
+			.vocabulary
+			.insert_owned_literal(Literal::new(value, ty)))
+	}
+
+	fn language_tag(&self, tag: String) -> Result<LanguageTagBuf, ParseError> {
+		LanguageTagBuf::new(tag.into_bytes()).map_err(|(_, tag)| {
+			ParseError::InvalidLanguageTag(self.line, String::from_utf8_lossy(&tag).into_owned())
+		})
+	}
+
+	/// Parses an `\uXXXX` or `\UXXXXXXXX` escape sequence, after the `\`.
+	fn unicode_escape(&mut self) -> Result<char, ParseError> {
+		let len = match self.next()? {
+			'u' => 4,
+			'U' => 8,
+			_ => return Err(ParseError::InvalidEscape(self.line)),
+		};
+
+		let mut code = 0;
+		for _ in 0..len {
+			let digit = self
+				.next()?
+				.to_digit(16)
+				.ok_or(ParseError::InvalidEscape(self.line))?;
+			code = code * 16 + digit
+		}
+
+		char::from_u32(code).ok_or(ParseError::InvalidEscape(self.line))
+	}
+}
@@ -45,6 +45,11 @@ impl<'a, 'n, 'g, N: Vocabulary, G: Generator<N>> Quads<'a, 'n, 'g, N, G> {
 	pub fn cloned(self) -> ClonedQuads<'a, 'n, 'g, N, G> {
 		ClonedQuads { inner: self }
 	}
+
+	/// Returns the vocabulary used to generate the quads.
+	pub fn vocabulary(&self) -> &N {
+		self.vocabulary
+	}
 }
 
 impl<'a, 'n, 'g, N: Vocabulary + IriVocabularyMut + LanguageTagVocabularyMut, G: Generator<N>>
@@ -141,6 +146,13 @@ pub struct ClonedQuads<'a, 'n, 'g, N: Vocabulary, G: Generator<N>> {
 	inner: Quads<'a, 'n, 'g, N, G>,
 }
 
+impl<'a, 'n, 'g, N: Vocabulary, G: Generator<N>> ClonedQuads<'a, 'n, 'g, N, G> {
+	/// Returns the vocabulary used to generate the quads.
+	pub fn vocabulary(&self) -> &N {
+		self.inner.vocabulary
+	}
+}
+
 impl<'a, 'n, 'g, N: Vocabulary + IriVocabularyMut + LanguageTagVocabularyMut, G: Generator<N>>
 	Iterator for ClonedQuads<'a, 'n, 'g, N, G>
 where
@@ -1,5 +1,4 @@
 use hashbrown::HashSet;
-use iref::Iri;
 use json_syntax::Parse;
 use linked_data::{grdf, FromLinkedDataError, LinkedDataDeserialize};
 use rdf_types::{
@@ -7,7 +6,6 @@ use rdf_types::{
 	LanguageTagVocabulary, Quad, ReverseIdInterpretation, ReverseTermInterpretation, Term,
 	Vocabulary,
 };
-use static_iref::iri;
 use std::{
 	collections::{BTreeMap, BTreeSet},
 	hash::Hash,
@@ -17,8 +15,8 @@ use std::{
 use crate::{
 	object::{List, Literal},
 	rdf::{
-		RDF_FIRST, RDF_JSON, RDF_NIL, RDF_REST, RDF_TYPE, XSD_BOOLEAN, XSD_DOUBLE, XSD_INTEGER,
-		XSD_STRING,
+		RDF_FIRST, RDF_JSON, RDF_LIST, RDF_NIL, RDF_REST, RDF_TYPE, XSD_BOOLEAN, XSD_DOUBLE,
+		XSD_INTEGER, XSD_STRING,
 	},
 	ExpandedDocument, Id, Indexed, IndexedObject, LangString, Node, Object, ValidId, Value,
 };
@@ -191,8 +189,6 @@ impl<R> RdfType<R> {
 	}
 }
 
-const RDF_LIST: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#List");
-
 fn rdf_type<'a, V: IriVocabulary, I: ReverseIriInterpretation<Iri = V::Iri>>(
 	vocabulary: &V,
 	interpretation: &I,
@@ -0,0 +1,45 @@
+//! Source code mapping.
+//!
+//! The expansion algorithm can record the [`Position`] of each expanded
+//! object in the input document, given as indexes in the
+//! [`CodeMap`](json_syntax::CodeMap) returned by the JSON parser. The code map
+//! then gives the corresponding byte spans.
+use json_syntax::CodeMap;
+use std::ops::Range;
+
+/// Position of a value in a JSON document.
+///
+/// Made of the code map index of the value and, if the value (or the array
+/// it is an item of) is the value of an object entry, of the entry key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+	/// Code map index of the value.
+	pub value: usize,
+
+	/// Code map index of the entry key, if any.
+	pub key: Option<usize>,
+}
+
+impl Position {
+	/// Position of the root value.
+	pub const ROOT: Self = Self {
+		value: 0,
+		key: None,
+	};
+
+	/// Returns the byte span of the value.
+	pub fn span(&self, code_map: &CodeMap) -> Option<Range<usize>> {
+		span(code_map, self.value)
+	}
+
+	/// Returns the byte span of the entry key, if any.
+	pub fn key_span(&self, code_map: &CodeMap) -> Option<Range<usize>> {
+		self.key.and_then(|i| span(code_map, i))
+	}
+}
+
+fn span(code_map: &CodeMap, i: usize) -> Option<Range<usize>> {
+	code_map
+		.get(i)
+		.map(|entry| entry.span.start()..entry.span.end())
+}
@@ -1,4 +1,6 @@
-use crate::{expand_element, ActiveProperty, Error, Expanded, Loader, Options, WarningHandler};
+use crate::{
+	expand_element, ActiveProperty, Cursor, Expanded, Loader, LocatedError, Options, WarningHandler,
+};
 use json_ld_core::{context::TermDefinitionRef, object, Context, Environment, Object};
 use json_ld_syntax::ContainerKind;
 use json_syntax::Array;
@@ -12,10 +14,11 @@ pub(crate) async fn expand_array<N, L, W>(
 	active_property: ActiveProperty<'_>,
 	active_property_definition: Option<TermDefinitionRef<'_, N::Iri, N::BlankId>>,
 	element: &Array,
+	cursor: Cursor<'_>,
 	base_url: Option<&N::Iri>,
 	options: Options,
 	from_map: bool,
-) -> Result<Expanded<N::Iri, N::BlankId>, Error<L::Error>>
+) -> Result<Expanded<N::Iri, N::BlankId>, LocatedError<L::Error>>
 where
 	N: VocabularyMut,
 	N::Iri: Clone + Eq + Hash,
@@ -42,7 +45,7 @@ where
 	}
 
 	// For each item in element:
-	for item in element.iter() {
+	for (item, item_cursor) in element.iter().zip(cursor.items()) {
 		// Initialize `expanded_item` to the result of using this algorithm
 		// recursively, passing `active_context`, `active_property`, `item` as element,
 		// `base_url`, the `frame_expansion`, `ordered`, and `from_map` flags.
@@ -55,6 +58,7 @@ where
 			active_context,
 			active_property,
 			item,
+			item_cursor,
 			base_url,
 			options,
 			from_map,
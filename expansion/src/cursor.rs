@@ -0,0 +1,85 @@
+use json_ld_core::source::Position;
+use json_syntax::{CodeMap, Value};
+
+/// Position of the element being expanded in the input document.
+///
+/// Follows the code map of the input document, if any, while descending into
+/// it. Without code map, no position is recorded.
+#[derive(Clone, Copy)]
+pub(crate) struct Cursor<'a> {
+	code_map: Option<&'a CodeMap>,
+	position: Position,
+}
+
+impl<'a> Cursor<'a> {
+	/// Cursor on the root value.
+	pub fn root(code_map: Option<&'a CodeMap>) -> Self {
+		Self {
+			code_map,
+			position: Position::ROOT,
+		}
+	}
+
+	/// Returns the position of the element, if a code map is given.
+	pub fn position(&self) -> Option<Position> {
+		self.code_map.map(|_| self.position)
+	}
+
+	/// Returns the number of code map entries covered by the fragment at
+	/// index `i`.
+	fn volume(&self, i: usize) -> usize {
+		self.code_map
+			.and_then(|code_map| code_map.get(i))
+			.map(|entry| entry.volume)
+			.unwrap_or(1)
+	}
+
+	/// Returns the code map indexes of the successive fragments starting at
+	/// index `first`.
+	fn siblings(self, first: usize) -> impl Iterator<Item = usize> + 'a {
+		let mut next = first;
+		std::iter::from_fn(move || {
+			let i = next;
+			next += self.volume(i);
+			Some(i)
+		})
+	}
+
+	/// Cursors on the values of the entries of the object at this cursor, in
+	/// order.
+	pub fn entries(self) -> impl Iterator<Item = Self> + 'a {
+		// An entry fragment is followed by its key and value fragments.
+		self.siblings(self.position.value + 1).map(move |i| Self {
+			code_map: self.code_map,
+			position: Position {
+				value: i + 2,
+				key: Some(i + 1),
+			},
+		})
+	}
+
+	/// Cursors on the items of the array at this cursor, in order.
+	pub fn items(self) -> impl Iterator<Item = Self> + 'a {
+		self.items_from(self.position.value + 1)
+	}
+
+	/// Cursors on the items of `Value::force_as_array(value)`, where `value`
+	/// is the value at this cursor.
+	pub fn force_as_array(self, value: &Value) -> impl Iterator<Item = Self> + 'a {
+		if value.is_array() {
+			self.items_from(self.position.value + 1)
+		} else {
+			self.items_from(self.position.value)
+		}
+	}
+
+	fn items_from(self, first: usize) -> impl Iterator<Item = Self> + 'a {
+		self.siblings(first).map(move |i| Self {
+			code_map: self.code_map,
+			position: Position {
+				value: i,
+				key: self.position.key,
+			},
+		})
+	}
+}
@@ -1,7 +1,7 @@
 use super::expand_element;
-use crate::{ActiveProperty, Error, Loader, Options, WarningHandler};
+use crate::{ActiveProperty, Cursor, Loader, LocatedError, Options, WarningHandler};
 use json_ld_core::{Context, Environment, ExpandedDocument, IndexedObject, Object};
-use json_syntax::Value;
+use json_syntax::{CodeMap, Value};
 use rdf_types::VocabularyMut;
 use std::hash::Hash;
 
@@ -13,10 +13,11 @@ use std::hash::Hash;
 pub(crate) async fn expand<'a, N, L, W>(
 	env: Environment<'a, N, L, W>,
 	document: &'a Value,
+	code_map: Option<&'a CodeMap>,
 	active_context: Context<N::Iri, N::BlankId>,
 	base_url: Option<&'a N::Iri>,
 	options: Options,
-) -> Result<ExpandedDocument<N::Iri, N::BlankId>, Error<L::Error>>
+) -> Result<ExpandedDocument<N::Iri, N::BlankId>, LocatedError<L::Error>>
 where
 	N: VocabularyMut,
 	N::Iri: Clone + Eq + Hash,
@@ -36,6 +37,7 @@ where
 		&active_context,
 		ActiveProperty::None,
 		document,
+		Cursor::root(code_map),
 		base_url,
 		options,
 		false,
@@ -1,6 +1,6 @@
 use crate::{
-	expand_array, expand_iri, expand_literal, expand_node, expand_value, Error, Expanded,
-	GivenLiteralValue, LiteralValue, Loader, Options, Warning, WarningHandler,
+	expand_array, expand_iri, expand_literal, expand_node, expand_value, Cursor, Error, Expanded,
+	GivenLiteralValue, LiteralValue, Loader, LocatedError, Options, Warning, WarningHandler,
 };
 use json_ld_context_processing::{Options as ProcessingOptions, Process};
 use json_ld_core::{
@@ -13,7 +13,12 @@ use mown::Mown;
 use rdf_types::VocabularyMut;
 use std::{borrow::Cow, hash::Hash};
 
-pub(crate) struct ExpandedEntry<'a, T, B>(pub &'a str, pub Term<T, B>, pub &'a Value);
+pub(crate) struct ExpandedEntry<'a, T, B>(
+	pub &'a str,
+	pub Term<T, B>,
+	pub &'a Value,
+	pub Cursor<'a>,
+);
 
 pub(crate) enum ActiveProperty<'a> {
 	Some(&'a str),
@@ -66,18 +71,70 @@ impl<'a> PartialEq<Keyword> for ActiveProperty<'a> {
 
 /// Result of the expansion of a single element in a JSON-LD document.
 pub(crate) type ElementExpansionResult<T, B, L> =
-	Result<Expanded<T, B>, Error<<L as Loader<T>>::Error>>;
+	Result<Expanded<T, B>, LocatedError<<L as Loader<T>>::Error>>;
 
 /// Expand an element.
 ///
 /// See <https://www.w3.org/TR/json-ld11-api/#expansion-algorithm>.
 /// The default specified value for `ordered` and `from_map` is `false`.
+///
+/// The `cursor` gives the position of `element` in the input document. It is
+/// attached to the returned error, if any, and to the expanded objects.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn expand_element<'a, N, L, W>(
+	env: Environment<'a, N, L, W>,
+	active_context: &'a Context<N::Iri, N::BlankId>,
+	active_property: ActiveProperty<'a>,
+	element: &'a Value,
+	cursor: Cursor<'a>,
+	base_url: Option<&'a N::Iri>,
+	options: Options,
+	from_map: bool,
+) -> BoxFuture<'a, ElementExpansionResult<N::Iri, N::BlankId, L>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone + Eq + Hash,
+	N::BlankId: Clone + Eq + Hash,
+	L: Loader<N::Iri>,
+	W: WarningHandler<N>,
+	//
+	N: Send + Sync,
+	N::Iri: Sync + Send,
+	N::BlankId: Sync + Send,
+	L: Sync + Send,
+	L::Error: Send,
+	W: Send + Sync,
+{
+	async move {
+		match expand_element_at(
+			env,
+			active_context,
+			active_property,
+			element,
+			cursor,
+			base_url,
+			options,
+			from_map,
+		)
+		.await
+		{
+			Ok(mut expanded) => {
+				expanded.set_default_position(cursor.position());
+				Ok(expanded)
+			}
+			Err(e) => Err(e.at(cursor.position())),
+		}
+	}
+	.boxed()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn expand_element_at<'a, N, L, W>(
 	mut env: Environment<'a, N, L, W>,
 	active_context: &'a Context<N::Iri, N::BlankId>,
 	active_property: ActiveProperty<'a>,
 	element: &'a Value,
+	cursor: Cursor<'a>,
 	base_url: Option<&'a N::Iri>,
 	options: Options,
 	from_map: bool,
@@ -126,6 +183,7 @@ where
 					active_property,
 					active_property_definition,
 					element,
+					cursor,
 					base_url,
 					options,
 					from_map,
@@ -195,7 +253,7 @@ where
 							)
 							.await?
 							.into_processed(), // .err_at(|| active_property.as_ref().map(Meta::metadata).cloned().unwrap_or_default())?
-						                   // .into_inner(),
+						                    // .into_inner(),
 					);
 				}
 
@@ -325,7 +383,7 @@ where
 				let mut list_entry = None;
 				let mut set_entry = None;
 				let mut value_entry = None;
-				for Entry { key, value } in entries.iter() {
+				for (Entry { key, value }, entry_cursor) in entries.iter().zip(cursor.entries()) {
 					if key.is_empty() {
 						env.warnings.handle(env.vocabulary, Warning::EmptyTerm);
 					}
@@ -342,10 +400,12 @@ where
 						Term::Keyword(Keyword::Value) => value_entry = Some(value.clone()),
 						Term::Keyword(Keyword::List) => {
 							if active_property.is_some() && active_property != Keyword::Graph {
-								list_entry = Some(value.clone())
+								list_entry = Some((value.clone(), entry_cursor))
 							}
 						}
-						Term::Keyword(Keyword::Set) => set_entry = Some(value.clone()),
+						Term::Keyword(Keyword::Set) => {
+							set_entry = Some((value.clone(), entry_cursor))
+						}
 						Term::Id(Id::Valid(ValidId::Blank(id))) => {
 							env.warnings
 								.handle(env.vocabulary, Warning::BlankNodeIdProperty(id.clone()));
@@ -353,20 +413,20 @@ where
 						_ => (),
 					}
 
-					expanded_entries.push(ExpandedEntry(key, expanded_key, value))
+					expanded_entries.push(ExpandedEntry(key, expanded_key, value, entry_cursor))
 				}
 
-				if let Some(list_entry) = list_entry {
+				if let Some((list_entry, list_cursor)) = list_entry {
 					// List objects.
 					let mut index = None;
-					for ExpandedEntry(_, expanded_key, value) in expanded_entries {
+					for ExpandedEntry(_, expanded_key, value, _) in expanded_entries {
 						match expanded_key {
 							Term::Keyword(Keyword::Index) => match value.as_string() {
 								Some(value) => index = Some(value.to_string()),
-								None => return Err(Error::InvalidIndexValue),
+								None => return Err(Error::InvalidIndexValue.into()),
 							},
 							Term::Keyword(Keyword::List) => (),
-							_ => return Err(Error::InvalidSetOrListObject),
+							_ => return Err(Error::InvalidSetOrListObject.into()),
 						}
 					}
 
@@ -375,8 +435,9 @@ where
 					// base URL, and the ordered flags, ensuring that the
 					// result is an array..
 					let mut result = Vec::new();
+					let item_cursors = list_cursor.force_as_array(&list_entry);
 					let list_entry = Value::force_as_array(&list_entry);
-					for item in list_entry {
+					for (item, item_cursor) in list_entry.iter().zip(item_cursors) {
 						let e = expand_element(
 							Environment {
 								vocabulary: env.vocabulary,
@@ -386,6 +447,7 @@ where
 							active_context.as_ref(),
 							active_property,
 							item,
+							item_cursor,
 							base_url,
 							options,
 							false,
@@ -398,16 +460,16 @@ where
 						Object::List(object::List::new(result)),
 						index,
 					)))
-				} else if let Some(set_entry) = set_entry {
+				} else if let Some((set_entry, set_cursor)) = set_entry {
 					// Set objects.
-					for ExpandedEntry(_, expanded_key, _) in expanded_entries {
+					for ExpandedEntry(_, expanded_key, _, _) in expanded_entries {
 						match expanded_key {
 							Term::Keyword(Keyword::Index) => {
 								// having an `@index` here is tolerated,
 								// but is ignored.
 							}
 							Term::Keyword(Keyword::Set) => (),
-							_ => return Err(Error::InvalidSetOrListObject),
+							_ => return Err(Error::InvalidSetOrListObject.into()),
 						}
 					}
 
@@ -419,6 +481,7 @@ where
 						active_context.as_ref(),
 						active_property,
 						&set_entry,
+						set_cursor,
 						base_url,
 						options,
 						false,
@@ -1,4 +1,6 @@
+use json_ld_core::source::Position;
 use json_ld_syntax::ErrorCode;
+use std::fmt;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error<E> {
@@ -105,3 +107,90 @@ impl<E> From<crate::InvalidValue> for Error<E> {
 		Self::Value(e)
 	}
 }
+
+/// Expansion error, along with the position of the input value that caused
+/// it.
+///
+/// The position is only known when the input document is expanded along with
+/// its code map (see [`CodeMapped`](crate::CodeMapped)).
+#[derive(Debug)]
+pub struct LocatedError<E> {
+	error: Error<E>,
+	position: Option<Position>,
+}
+
+impl<E> LocatedError<E> {
+	/// Returns the error.
+	pub fn error(&self) -> &Error<E> {
+		&self.error
+	}
+
+	/// Drops the position and returns the error.
+	pub fn into_error(self) -> Error<E> {
+		self.error
+	}
+
+	/// Returns the position of the input value that caused the error, if
+	/// known.
+	pub fn position(&self) -> Option<Position> {
+		self.position
+	}
+
+	/// Sets the position of the error, unless it is already known.
+	pub(crate) fn at(mut self, position: Option<Position>) -> Self {
+		if self.position.is_none() {
+			self.position = position
+		}
+
+		self
+	}
+
+	pub fn code(&self) -> ErrorCode {
+		self.error.code()
+	}
+}
+
+impl<E: fmt::Display> fmt::Display for LocatedError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.error.fmt(f)
+	}
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for LocatedError<E> {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+impl<E> From<Error<E>> for LocatedError<E> {
+	fn from(error: Error<E>) -> Self {
+		Self {
+			error,
+			position: None,
+		}
+	}
+}
+
+impl<E> From<json_ld_syntax::context::InvalidContext> for LocatedError<E> {
+	fn from(e: json_ld_syntax::context::InvalidContext) -> Self {
+		Error::ContextSyntax(e).into()
+	}
+}
+
+impl<E> From<json_ld_context_processing::Error<E>> for LocatedError<E> {
+	fn from(e: json_ld_context_processing::Error<E>) -> Self {
+		Error::ContextProcessing(e).into()
+	}
+}
+
+impl<E> From<crate::LiteralExpansionError> for LocatedError<E> {
+	fn from(e: crate::LiteralExpansionError) -> Self {
+		Error::Literal(e).into()
+	}
+}
+
+impl<E> From<crate::InvalidValue> for LocatedError<E> {
+	fn from(e: crate::InvalidValue) -> Self {
+		Error::Value(e).into()
+	}
+}
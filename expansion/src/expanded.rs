@@ -1,4 +1,4 @@
-use json_ld_core::IndexedObject;
+use json_ld_core::{source::Position, IndexedObject};
 
 pub enum Expanded<T, B> {
 	Null,
@@ -30,6 +30,22 @@ impl<T, B> Expanded<T, B> {
 		}
 	}
 
+	/// Sets the position of every expanded object that does not already
+	/// have one.
+	pub(crate) fn set_default_position(&mut self, position: Option<Position>) {
+		let objects = match self {
+			Expanded::Null => return,
+			Expanded::Object(o) => std::slice::from_mut(o),
+			Expanded::Array(ary) => ary.as_mut_slice(),
+		};
+
+		for o in objects {
+			if o.position().is_none() {
+				o.set_position(position)
+			}
+		}
+	}
+
 	pub fn iter(&self) -> Iter<T, B> {
 		match self {
 			Expanded::Null => Iter::Null,
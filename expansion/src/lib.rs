@@ -15,11 +15,13 @@ use json_syntax::Value;
 use rdf_types::{vocabulary, BlankIdBuf, BlankIdVocabulary, VocabularyMut};
 
 mod array;
+mod cursor;
 mod document;
 mod element;
 mod error;
 mod expanded;
 mod literal;
+mod mapped;
 mod node;
 mod options;
 mod value;
@@ -27,10 +29,12 @@ mod warning;
 
 pub use error::*;
 pub use expanded::*;
+pub use mapped::*;
 pub use options::*;
 pub use warning::*;
 
 pub(crate) use array::*;
+pub(crate) use cursor::Cursor;
 pub(crate) use document::filter_top_level_item;
 pub(crate) use element::*;
 pub(crate) use json_ld_context_processing::algorithm::expand_iri_simple as expand_iri;
@@ -234,11 +238,13 @@ impl<Iri> Expand<Iri> for Value {
 					warnings: &mut warnings_handler,
 				},
 				self,
+				None,
 				context,
 				base_url,
 				options,
 			)
 			.await
+			.map_err(LocatedError::into_error)
 		}
 		.boxed()
 	}
@@ -246,11 +252,11 @@ impl<Iri> Expand<Iri> for Value {
 
 /// Remote document expansion.
 ///
-/// The default base URL given to the expansion algorithm is the URL of
+/// The default base URL given to the expansion algorithm is the base URL of
 /// the remote document.
 impl<Iri> Expand<Iri> for RemoteDocument<Iri> {
 	fn default_base_url(&self) -> Option<&Iri> {
-		self.url()
+		self.base_url()
 	}
 
 	fn expand_full<'a, N, L, W>(
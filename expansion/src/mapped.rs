@@ -0,0 +1,146 @@
+use crate::{document, LocatedError, Options, WarningHandler};
+use json_ld_context_processing::Context;
+use json_ld_core::{
+	future::{BoxFuture, FutureExt},
+	Environment, ExpandedDocument, Loader,
+};
+use json_syntax::{CodeMap, Value};
+use rdf_types::{vocabulary, BlankIdBuf, VocabularyMut};
+use std::hash::Hash;
+
+/// Result of the expansion of a [`CodeMapped`] document.
+pub type LocatedExpansionResult<T, B, L> =
+	Result<ExpandedDocument<T, B>, LocatedError<<L as Loader<T>>::Error>>;
+
+/// JSON document along with the code map returned by the parser.
+///
+/// Expanding it records the [`Position`](json_ld_core::source::Position) of
+/// each expanded object in the input document (see
+/// [`Indexed::position`](json_ld_core::Indexed::position)), along with the
+/// position of the input value that caused the error, if any.
+///
+/// # Example
+///
+/// ```
+/// # mod json_ld { pub use json_ld_syntax as syntax; pub use json_ld_core::NoLoader; pub use json_ld_expansion::CodeMapped; };
+/// use iref::IriBuf;
+/// use json_ld::{syntax::Parse, CodeMapped};
+///
+/// # #[async_std::test]
+/// # async fn example() {
+/// let source = r#"{ "@id": 1, "http://example.org/vocab#a": "value" }"#;
+/// let (json, code_map) = json_ld::syntax::Value::parse_str(source).unwrap();
+///
+/// let e = CodeMapped::new(&json, &code_map)
+///     .expand::<IriBuf, _>(&mut json_ld::NoLoader)
+///     .await
+///     .unwrap_err();
+///
+/// let span = e.position().unwrap().span(&code_map).unwrap();
+/// assert_eq!(&source[span], source);
+/// # }
+/// ```
+pub struct CodeMapped<'a> {
+	value: &'a Value,
+	code_map: &'a CodeMap,
+}
+
+impl<'a> CodeMapped<'a> {
+	/// Pairs the given document with its code map.
+	pub fn new(value: &'a Value, code_map: &'a CodeMap) -> Self {
+		Self { value, code_map }
+	}
+
+	/// Expand the document with full options.
+	///
+	/// See [`Expand::expand_full`](crate::Expand::expand_full).
+	#[allow(clippy::too_many_arguments)]
+	pub fn expand_full<N, L, W>(
+		&self,
+		vocabulary: &'a mut N,
+		context: Context<N::Iri, N::BlankId>,
+		base_url: Option<&'a N::Iri>,
+		loader: &'a mut L,
+		options: Options,
+		mut warnings_handler: W,
+	) -> BoxFuture<'a, LocatedExpansionResult<N::Iri, N::BlankId, L>>
+	where
+		N: VocabularyMut,
+		N::Iri: 'a + Clone + Eq + Hash,
+		N::BlankId: 'a + Clone + Eq + Hash,
+		L: Loader<N::Iri>,
+		W: 'a + WarningHandler<N>,
+		//
+		N: Send + Sync,
+		N::Iri: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+		W: Send + Sync,
+	{
+		let value = self.value;
+		let code_map = self.code_map;
+		async move {
+			document::expand(
+				Environment {
+					vocabulary,
+					loader,
+					warnings: &mut warnings_handler,
+				},
+				value,
+				Some(code_map),
+				context,
+				base_url,
+				options,
+			)
+			.await
+		}
+		.boxed()
+	}
+
+	/// Expand the document with the given `vocabulary` to interpret
+	/// identifiers, starting from an empty context without base URL.
+	pub fn expand_with<N, L>(
+		&self,
+		vocabulary: &'a mut N,
+		loader: &'a mut L,
+	) -> BoxFuture<'a, LocatedExpansionResult<N::Iri, N::BlankId, L>>
+	where
+		N: VocabularyMut,
+		N::Iri: 'a + Clone + Eq + Hash,
+		N::BlankId: 'a + Clone + Eq + Hash,
+		L: Loader<N::Iri>,
+		//
+		N: Send + Sync,
+		N::Iri: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		self.expand_full(
+			vocabulary,
+			Context::new(None),
+			None,
+			loader,
+			Options::default(),
+			(),
+		)
+	}
+
+	/// Expand the document, starting from an empty context without base URL.
+	pub fn expand<Iri, L>(
+		&self,
+		loader: &'a mut L,
+	) -> BoxFuture<'a, LocatedExpansionResult<Iri, BlankIdBuf, L>>
+	where
+		(): VocabularyMut<Iri = Iri>,
+		Iri: 'a + Clone + Eq + Hash,
+		L: Loader<Iri>,
+		//
+		Iri: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		self.expand_with(vocabulary::no_vocabulary_mut(), loader)
+	}
+}
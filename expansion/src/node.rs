@@ -1,6 +1,6 @@
 use crate::{
 	expand_element, expand_iri, expand_literal, filter_top_level_item, ActiveProperty, Error,
-	Expanded, ExpandedEntry, LiteralValue, Options, Policy, Warning, WarningHandler,
+	Expanded, ExpandedEntry, LiteralValue, LocatedError, Options, Policy, Warning, WarningHandler,
 };
 use contextual::WithContext;
 use indexmap::IndexSet;
@@ -38,7 +38,7 @@ pub(crate) async fn expand_node<'a, N, L, W>(
 	expanded_entries: Vec<ExpandedEntry<'a, N::Iri, N::BlankId>>,
 	base_url: Option<&'a N::Iri>,
 	options: Options,
-) -> Result<Option<Indexed<Node<N::Iri, N::BlankId>>>, Error<L::Error>>
+) -> Result<Option<Indexed<Node<N::Iri, N::BlankId>>>, LocatedError<L::Error>>
 where
 	N: VocabularyMut,
 	N::Iri: Clone + Eq + Hash,
@@ -111,7 +111,7 @@ type ExpandedNode<T, B> = (Indexed<Node<T, B>>, bool);
 
 /// Result of the `expand_node_entries` function.
 type NodeEntriesExpensionResult<T, B, L> =
-	Result<ExpandedNode<T, B>, Error<<L as Loader<T>>::Error>>;
+	Result<ExpandedNode<T, B>, LocatedError<<L as Loader<T>>::Error>>;
 
 #[allow(clippy::too_many_arguments)]
 fn expand_node_entries<'a, N, L, W>(
@@ -142,7 +142,7 @@ where
 	async move {
 		// For each `key` and `value` in `element`, ordered lexicographically by key
 		// if `ordered` is `true`:
-		for ExpandedEntry(key, expanded_key, value) in expanded_entries {
+		for ExpandedEntry(key, expanded_key, value, cursor) in expanded_entries {
 			match expanded_key {
 				Term::Null => (),
 
@@ -159,7 +159,7 @@ where
 					// If `active_property` equals `@reverse`, an invalid reverse property
 					// map error has been detected and processing is aborted.
 					if active_property == Keyword::Reverse {
-						return Err(Error::InvalidReversePropertyMap);
+						return Err(Error::InvalidReversePropertyMap.into());
 					}
 
 					// If `result` already has an `expanded_property` entry, other than
@@ -171,7 +171,7 @@ where
 							&& expanded_property != Keyword::Type))
 						&& result.has_key(&Term::Keyword(expanded_property))
 					{
-						return Err(Error::CollidingKeywords);
+						return Err(Error::CollidingKeywords.into());
 					}
 
 					match expanded_property {
@@ -191,7 +191,7 @@ where
 									false,
 								))
 							} else {
-								return Err(Error::InvalidIdValue);
+								return Err(Error::InvalidIdValue.into());
 							}
 						}
 						// If expanded property is @type:
@@ -216,10 +216,10 @@ where
 									{
 										result.types_mut_or_default().push(ty)
 									} else {
-										return Err(Error::InvalidTypeValue);
+										return Err(Error::InvalidTypeValue.into());
 									}
 								} else {
-									return Err(Error::InvalidTypeValue);
+									return Err(Error::InvalidTypeValue.into());
 								}
 							}
 						}
@@ -239,6 +239,7 @@ where
 								active_context,
 								ActiveProperty::Some("@graph"),
 								value,
+								cursor,
 								base_url,
 								options,
 								false,
@@ -273,6 +274,7 @@ where
 								active_context,
 								ActiveProperty::Some("@included"),
 								value,
+								cursor,
 								base_url,
 								options,
 								false,
@@ -283,7 +285,7 @@ where
 								match obj.try_cast::<Node<N::Iri, N::BlankId>>() {
 									Ok(node) => expanded_nodes.push(node),
 									Err(_) => {
-										return Err(Error::InvalidIncludedValue);
+										return Err(Error::InvalidIncludedValue.into());
 									}
 								}
 							}
@@ -305,7 +307,7 @@ where
 							} else {
 								// If value is not a string, an invalid @index value
 								// error has been detected and processing is aborted.
-								return Err(Error::InvalidIndexValue);
+								return Err(Error::InvalidIndexValue.into());
 							}
 						}
 						// If expanded property is @reverse:
@@ -313,16 +315,20 @@ where
 							// If value is not a map, an invalid @reverse value error
 							// has been detected and processing is aborted.
 							if let Some(value) = value.as_object() {
-								let mut reverse_entries: Vec<&Entry> = value.iter().collect();
+								let mut reverse_entries: Vec<_> =
+									value.iter().zip(cursor.entries()).collect();
 
 								if options.ordered {
-									reverse_entries.sort_by_key(|entry| &entry.key)
+									reverse_entries.sort_by_key(|(entry, _)| &entry.key)
 								}
 
-								for Entry {
-									key: reverse_key,
-									value: reverse_value,
-								} in reverse_entries
+								for (
+									Entry {
+										key: reverse_key,
+										value: reverse_value,
+									},
+									reverse_cursor,
+								) in reverse_entries
 								{
 									match expand_iri(
 										&mut env,
@@ -332,14 +338,15 @@ where
 										true,
 									) {
 										Term::Keyword(_) => {
-											return Err(Error::InvalidReversePropertyMap)
+											return Err(Error::InvalidReversePropertyMap.into())
 										}
 										Term::Id(Id::Invalid(_))
 											if options.policy == Policy::Strictest =>
 										{
 											return Err(Error::KeyExpansionFailed(
 												reverse_key.to_string(),
-											))
+											)
+											.into())
 										}
 										Term::Id(reverse_prop)
 											if reverse_prop
@@ -357,6 +364,7 @@ where
 												active_context,
 												ActiveProperty::Some(reverse_key.as_ref()),
 												reverse_value,
+												reverse_cursor,
 												base_url,
 												options,
 												false,
@@ -388,7 +396,8 @@ where
 														}
 														Err(_) => {
 															return Err(
-																Error::InvalidReversePropertyValue,
+																Error::InvalidReversePropertyValue
+																	.into(),
 															)
 														}
 													}
@@ -404,14 +413,15 @@ where
 											if options.policy.is_strict() {
 												return Err(Error::KeyExpansionFailed(
 													reverse_key.to_string(),
-												));
+												)
+												.into());
 											}
 											// otherwise the key is just dropped.
 										}
 									}
 								}
 							} else {
-								return Err(Error::InvalidReverseValue);
+								return Err(Error::InvalidReverseValue.into());
 							}
 						}
 						// If expanded property is @nest
@@ -419,8 +429,9 @@ where
 							let nesting_key = key;
 							// Recursively repeat steps 3, 8, 13, and 14 using `nesting_key` for active property,
 							// and nested value for element.
+							let nested_cursors = cursor.force_as_array(value);
 							let value = json_syntax::Value::force_as_array(value);
-							for nested_value in value {
+							for (nested_value, nested_cursor) in value.iter().zip(nested_cursors) {
 								// Step 3 again.
 								let mut property_scoped_base_url = None;
 								let property_scoped_context = match active_context.get(nesting_key)
@@ -457,19 +468,16 @@ where
 
 								// Steps 13 and 14 again.
 								if let Some(nested_value) = nested_value.as_object() {
-									let mut nested_entries: Vec<&Entry> = Vec::new();
-
-									for entry in nested_value.iter() {
-										nested_entries.push(entry)
-									}
+									let mut nested_entries: Vec<_> =
+										nested_value.iter().zip(nested_cursor.entries()).collect();
 
 									if options.ordered {
-										nested_entries.sort_by_key(|entry| &entry.key);
+										nested_entries.sort_by_key(|(entry, _)| &entry.key);
 									}
 
 									let nested_expanded_entries = nested_entries
 										.into_iter()
-										.map(|Entry { key, value }| {
+										.map(|(Entry { key, value }, cursor)| {
 											let expanded_key = expand_iri(
 												&mut env,
 												active_context.as_ref(),
@@ -477,7 +485,7 @@ where
 												false,
 												true,
 											);
-											ExpandedEntry(key, expanded_key, value)
+											ExpandedEntry(key, expanded_key, value, cursor)
 										})
 										.collect();
 
@@ -502,17 +510,17 @@ where
 									result = new_result;
 									has_value_object_entries = new_has_value_object_entries;
 								} else {
-									return Err(Error::InvalidNestValue);
+									return Err(Error::InvalidNestValue.into());
 								}
 							}
 						}
-						Keyword::Value => return Err(Error::InvalidNestValue),
+						Keyword::Value => return Err(Error::InvalidNestValue.into()),
 						_ => (),
 					}
 				}
 
 				Term::Id(Id::Invalid(name)) if options.policy == Policy::Strictest => {
-					return Err(Error::KeyExpansionFailed(name))
+					return Err(Error::KeyExpansionFailed(name).into())
 				}
 
 				Term::Id(prop)
@@ -650,7 +658,7 @@ where
 												// item must be a string, otherwise an
 												// invalid language map value error has
 												// been detected and processing is aborted.
-												return Err(Error::InvalidLanguageMapValue);
+												return Err(Error::InvalidLanguageMapValue.into());
 											}
 										}
 									}
@@ -684,19 +692,20 @@ where
 
 								// For each key-value pair index-index value in value,
 								// ordered lexicographically by index if ordered is true:
-								let mut entries: Vec<&Entry> = Vec::with_capacity(value.len());
-								for entry in value.iter() {
-									entries.push(entry)
-								}
+								let mut entries: Vec<_> =
+									value.iter().zip(cursor.entries()).collect();
 
 								if options.ordered {
-									entries.sort_by_key(|entry| &entry.key);
+									entries.sort_by_key(|(entry, _)| &entry.key);
 								}
 
-								for Entry {
-									key: index,
-									value: index_value,
-								} in entries
+								for (
+									Entry {
+										key: index,
+										value: index_value,
+									},
+									index_cursor,
+								) in entries
 								{
 									// If container mapping includes @id or @type,
 									// initialize `map_context` to the `previous_context`
@@ -778,6 +787,7 @@ where
 										map_context.as_ref(),
 										ActiveProperty::Some(key),
 										index_value,
+										index_cursor,
 										base_url,
 										options,
 										true,
@@ -850,7 +860,8 @@ where
 													// processing is aborted.
 													return Err(Error::Value(
 														crate::InvalidValue::ValueObject,
-													));
+													)
+													.into());
 												}
 											} else if container_mapping
 												.contains(ContainerKind::Index) && item
@@ -897,7 +908,7 @@ where
 														node.types_mut_or_default().insert(0, typ);
 													}
 												} else {
-													return Err(Error::InvalidTypeValue);
+													return Err(Error::InvalidTypeValue.into());
 												}
 											}
 										}
@@ -922,6 +933,7 @@ where
 									active_context,
 									ActiveProperty::Some(key),
 									value,
+									cursor,
 									base_url,
 									options,
 									false,
@@ -976,7 +988,7 @@ where
 							for object in expanded_value {
 								match object.try_cast::<Node<N::Iri, N::BlankId>>() {
 									Ok(node) => reverse_expanded_nodes.push(node),
-									Err(_) => return Err(Error::InvalidReversePropertyValue),
+									Err(_) => return Err(Error::InvalidReversePropertyValue.into()),
 								}
 							}
 
@@ -996,7 +1008,8 @@ where
 					if options.policy.is_strict() {
 						return Err(Error::KeyExpansionFailed(
 							prop.with(&*env.vocabulary).to_string(),
-						));
+						)
+						.into());
 					}
 					// non-keyword properties that does not include a ':' are skipped.
 				}
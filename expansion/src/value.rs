@@ -69,7 +69,7 @@ where
 	let mut language = None;
 	let mut direction = None;
 
-	for ExpandedEntry(_, expanded_key, value) in expanded_entries {
+	for ExpandedEntry(_, expanded_key, value, _) in expanded_entries {
 		match expanded_key {
 			// If expanded property is @language:
 			Term::Keyword(Keyword::Language) => {
@@ -0,0 +1,697 @@
+use crate::{
+	frame::null_default, Embed, Frame, FramedDocument, NodeFrame, Options, Pattern,
+	PropertyFrame, TypeFrame,
+};
+use contextual::AsRefWithContext;
+use json_ld_core::{
+	flattening::{ConflictingIndexes, NodeMap, NodeMapGraph},
+	object::List,
+	ExpandedDocument, Id, Indexed, IndexedNode, IndexedObject, Node, Object, ProcessingMode,
+};
+use rdf_types::{Generator, Vocabulary};
+use std::{
+	collections::{HashMap, HashSet},
+	hash::{Hash, Hasher},
+};
+
+/// Result of the [`FrameExpanded::frame_with`] function.
+pub type FrameResult<T, B> = Result<FramedDocument<T, B>, ConflictingIndexes<T, B>>;
+
+/// Frame an expanded document.
+pub trait FrameExpanded<T, B> {
+	/// Frame the document using the given expanded frame.
+	///
+	/// The generator is used to label the blank nodes of the input document
+	/// while building its node map.
+	fn frame_with<V, G>(
+		&self,
+		vocabulary: &mut V,
+		generator: G,
+		frame: &Frame<T, B>,
+		options: Options,
+	) -> FrameResult<T, B>
+	where
+		V: Vocabulary<Iri = T, BlankId = B>,
+		G: Generator<V>;
+}
+
+impl<T: Clone + Eq + Hash, B: Clone + Eq + Hash> FrameExpanded<T, B> for ExpandedDocument<T, B> {
+	fn frame_with<V, G>(
+		&self,
+		vocabulary: &mut V,
+		generator: G,
+		frame: &Frame<T, B>,
+		options: Options,
+	) -> FrameResult<T, B>
+	where
+		V: Vocabulary<Iri = T, BlankId = B>,
+		G: Generator<V>,
+	{
+		let node_map = self.generate_node_map_with(vocabulary, generator)?;
+
+		let mut merged = NodeMapGraph::new();
+		for (_, graph) in &node_map {
+			for node in graph.nodes() {
+				merged.merge_node(node.clone())
+			}
+		}
+
+		let graph = if frame.default_graph {
+			GraphName::Default
+		} else {
+			GraphName::Merged
+		};
+
+		let mut framer = Framer::new(vocabulary, options, &node_map, &merged, graph);
+		let subjects = framer.subject_ids.clone();
+		let mut objects = framer.frame(graph, false, true, &subjects, &frame.node);
+
+		if options.processing_mode == ProcessingMode::JsonLd1_1 {
+			// Blank node identifiers appearing only once are removed.
+			let prune: HashSet<B> = framer
+				.blank_ids
+				.into_iter()
+				.filter_map(|(b, count)| if count == 1 { Some(b.clone()) } else { None })
+				.collect();
+
+			if !prune.is_empty() {
+				for object in &mut objects {
+					prune_object(object, &prune)
+				}
+			}
+		}
+
+		Ok(FramedDocument::new(objects))
+	}
+}
+
+/// Graph on which the framing is performed.
+enum GraphName<'a, T, B> {
+	/// Merge of every graph of the input document.
+	Merged,
+
+	/// Default graph.
+	Default,
+
+	/// Named graph.
+	Named(&'a Id<T, B>),
+}
+
+impl<'a, T, B> Clone for GraphName<'a, T, B> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, T, B> Copy for GraphName<'a, T, B> {}
+
+impl<'a, T: PartialEq, B: PartialEq> PartialEq for GraphName<'a, T, B> {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Merged, Self::Merged) => true,
+			(Self::Default, Self::Default) => true,
+			(Self::Named(a), Self::Named(b)) => a == b,
+			_ => false,
+		}
+	}
+}
+
+impl<'a, T: Eq, B: Eq> Eq for GraphName<'a, T, B> {}
+
+impl<'a, T: Hash, B: Hash> Hash for GraphName<'a, T, B> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		core::mem::discriminant(self).hash(state);
+		if let Self::Named(id) = self {
+			id.hash(state)
+		}
+	}
+}
+
+/// Framing flags.
+#[derive(Clone, Copy)]
+struct Flags {
+	embed: Embed,
+	explicit: bool,
+	require_all: bool,
+}
+
+impl Flags {
+	fn new<T, B>(frame: &NodeFrame<T, B>, options: &Options) -> Self {
+		Self {
+			embed: frame.embed.unwrap_or(options.embed),
+			explicit: frame.explicit.unwrap_or(options.explicit),
+			require_all: frame.require_all.unwrap_or(options.require_all),
+		}
+	}
+
+	/// Creates the implicit frame used for properties not appearing in the
+	/// frame, carrying the current flags.
+	fn implicit_frame<T, B>(&self) -> NodeFrame<T, B> {
+		NodeFrame {
+			embed: Some(self.embed),
+			explicit: Some(self.explicit),
+			require_all: Some(self.require_all),
+			..Default::default()
+		}
+	}
+}
+
+/// Framing state.
+struct Framer<'a, V, T, B> {
+	vocabulary: &'a V,
+	options: Options,
+	node_map: &'a NodeMap<T, B>,
+	merged: &'a NodeMapGraph<T, B>,
+
+	/// Graph in which the top level subjects are selected.
+	subjects: &'a NodeMapGraph<T, B>,
+
+	/// Sorted top level subjects identifiers.
+	subject_ids: Vec<&'a Id<T, B>>,
+
+	/// Embedded nodes, for each graph.
+	unique_embeds: HashMap<GraphName<'a, T, B>, HashSet<&'a Id<T, B>>>,
+
+	/// Nodes currently being embedded, used to detect circular references.
+	subject_stack: Vec<(&'a Id<T, B>, GraphName<'a, T, B>)>,
+
+	/// Number of occurrences of each blank node identifier in the output.
+	blank_ids: HashMap<&'a B, usize>,
+}
+
+impl<'a, V, T, B> Framer<'a, V, T, B>
+where
+	V: Vocabulary<Iri = T, BlankId = B>,
+	T: Clone + Eq + Hash,
+	B: Clone + Eq + Hash,
+{
+	fn new(
+		vocabulary: &'a V,
+		options: Options,
+		node_map: &'a NodeMap<T, B>,
+		merged: &'a NodeMapGraph<T, B>,
+		graph: GraphName<'a, T, B>,
+	) -> Self {
+		let subjects = match graph {
+			GraphName::Merged => merged,
+			_ => node_map.graph(None).unwrap(),
+		};
+
+		let mut result = Self {
+			vocabulary,
+			options,
+			node_map,
+			merged,
+			subjects,
+			subject_ids: Vec::new(),
+			unique_embeds: HashMap::new(),
+			subject_stack: Vec::new(),
+			blank_ids: HashMap::new(),
+		};
+
+		result.subject_ids = result.sorted_ids(subjects);
+		result
+	}
+
+	fn graph(&self, graph: GraphName<'a, T, B>) -> Option<&'a NodeMapGraph<T, B>> {
+		match graph {
+			GraphName::Merged => Some(self.merged),
+			GraphName::Default => self.node_map.graph(None),
+			GraphName::Named(id) => self.node_map.graph(Some(id)),
+		}
+	}
+
+	/// Returns the identifiers of the nodes of the given graph, sorted.
+	fn sorted_ids(&self, graph: &'a NodeMapGraph<T, B>) -> Vec<&'a Id<T, B>> {
+		let mut ids: Vec<_> = graph.into_iter().map(|(id, _)| id).collect();
+		ids.sort_by(|a, b| {
+			a.as_ref_with(self.vocabulary)
+				.cmp(b.as_ref_with(self.vocabulary))
+		});
+		ids
+	}
+
+	fn count_blank_id(&mut self, id: &'a Id<T, B>) {
+		if let Some(b) = id.as_blank() {
+			*self.blank_ids.entry(b).or_default() += 1
+		}
+	}
+
+	fn is_embedded(&self, graph: GraphName<'a, T, B>, id: &Id<T, B>) -> bool {
+		self.unique_embeds
+			.get(&graph)
+			.map(|ids| ids.contains(id))
+			.unwrap_or(false)
+	}
+
+	fn creates_circular_reference(&self, graph: GraphName<'a, T, B>, id: &Id<T, B>) -> bool {
+		self.subject_stack
+			.iter()
+			.rev()
+			.any(|(other_id, other_graph)| *other_graph == graph && *other_id == id)
+	}
+
+	/// Frame the given subjects.
+	///
+	/// The `embedded` flag is set when the subjects are property values of
+	/// an enclosing node. The `top_level` flag is set when the subjects are
+	/// selected at the top level of the output, in which case each match is
+	/// embedded independently.
+	fn frame(
+		&mut self,
+		graph: GraphName<'a, T, B>,
+		embedded: bool,
+		top_level: bool,
+		subjects: &[&'a Id<T, B>],
+		frame: &NodeFrame<T, B>,
+	) -> Vec<IndexedObject<T, B>> {
+		let mut result = Vec::new();
+		let flags = Flags::new(frame, &self.options);
+
+		let nodes = match self.graph(graph) {
+			Some(nodes) => nodes,
+			None => return result,
+		};
+
+		for &id in subjects {
+			let node = match nodes.get(id) {
+				Some(node) => node,
+				None => continue,
+			};
+
+			if !self.filter_subject(node, frame, flags) {
+				continue;
+			}
+
+			if top_level {
+				self.unique_embeds.clear()
+			}
+
+			self.count_blank_id(id);
+
+			if !embedded && self.is_embedded(graph, id) {
+				// The node has already been embedded in another node.
+				continue;
+			}
+
+			if embedded
+				&& (flags.embed == Embed::Never
+					|| self.creates_circular_reference(graph, id)
+					|| (flags.embed == Embed::Once && self.is_embedded(graph, id)))
+			{
+				result.push(Indexed::none(Object::node(Node::with_id(id.clone()))));
+				continue;
+			}
+
+			self.unique_embeds.entry(graph).or_default().insert(id);
+			self.subject_stack.push((id, graph));
+
+			let mut output = Node::with_id(id.clone());
+
+			if let Some(types) = &node.types {
+				let mut output_types = Vec::with_capacity(types.len());
+				for ty in types {
+					if !output_types.contains(ty) {
+						self.count_blank_id(ty);
+						output_types.push(ty.clone())
+					}
+				}
+
+				output.types = Some(output_types)
+			}
+
+			let default_frame = NodeFrame::default();
+
+			// Subject is also the name of a graph.
+			if let Some(named_graph) = self.node_map.graph(Some(id)) {
+				let subframe = match &frame.graph {
+					Some(subframe) => Some(&**subframe),
+					None if graph != GraphName::Merged => Some(&default_frame),
+					None => None,
+				};
+
+				if let Some(subframe) = subframe {
+					let ids = self.sorted_ids(named_graph);
+					let objects = self.frame(GraphName::Named(id), false, false, &ids, subframe);
+
+					if !objects.is_empty() {
+						output.set_graph_entry(Some(objects.into_iter().collect()))
+					}
+				}
+			}
+
+			if let Some(subframe) = &frame.included {
+				let included: Vec<_> = self
+					.frame(graph, false, false, subjects, subframe)
+					.into_iter()
+					.filter_map(Indexed::into_indexed_node)
+					.collect();
+
+				if !included.is_empty() {
+					output.set_included(Some(included.into_iter().collect()))
+				}
+			}
+
+			let mut properties: Vec<_> = node.properties().iter().collect();
+			properties.sort_by(|(a, _), (b, _)| {
+				a.as_ref_with(self.vocabulary)
+					.cmp(b.as_ref_with(self.vocabulary))
+			});
+
+			for (prop, objects) in properties {
+				let prop_frame = frame.property(prop);
+				if flags.explicit && prop_frame.is_none() {
+					continue;
+				}
+
+				let implicit_frame = flags.implicit_frame();
+				let mut values = Vec::new();
+
+				for object in objects {
+					match object.inner() {
+						Object::List(list) => {
+							let subframe = match prop_frame {
+								Some(PropertyFrame::List(list_frame)) => list_frame
+									.item
+									.as_deref()
+									.and_then(PropertyFrame::as_node)
+									.unwrap_or(&implicit_frame),
+								_ => &implicit_frame,
+							};
+
+							let mut items = Vec::with_capacity(list.len());
+							for item in list.iter() {
+								match reference_id(item) {
+									Some(item_id) => {
+										items.extend(self.frame(graph, true, false, &[item_id], subframe))
+									}
+									None => items.push(item.clone()),
+								}
+							}
+
+							values.push(Indexed::new(
+								Object::List(List::new(items)),
+								object.index().map(ToOwned::to_owned),
+							))
+						}
+						_ => match reference_id(object) {
+							Some(ref_id) => {
+								let subframe = match prop_frame {
+									Some(PropertyFrame::Node(subframe)) => subframe,
+									Some(_) => &default_frame,
+									None => &implicit_frame,
+								};
+
+								values.extend(self.frame(graph, true, false, &[ref_id], subframe))
+							}
+							None => {
+								if value_matches(prop_frame, object) {
+									values.push(object.clone())
+								}
+							}
+						},
+					}
+				}
+
+				if !values.is_empty() {
+					output.properties_mut().insert_all(prop.clone(), values)
+				}
+			}
+
+			// Defaults.
+			for (prop, prop_frame) in &frame.properties {
+				if output.properties().contains(prop) {
+					continue;
+				}
+
+				let omit_default = prop_frame
+					.as_node()
+					.and_then(|subframe| subframe.omit_default)
+					.unwrap_or(self.options.omit_default);
+
+				if !omit_default {
+					let default = match prop_frame.default() {
+						Some(default) => default.to_vec(),
+						None => vec![null_default()],
+					};
+
+					output.properties_mut().insert_all(prop.clone(), default)
+				}
+			}
+
+			if output.types.is_none() {
+				if let Some(TypeFrame {
+					default: Some(default),
+					..
+				}) = &frame.types
+				{
+					output.types = Some(default.clone())
+				}
+			}
+
+			// Embed reverse values by finding nodes having this subject as
+			// a value of the associated property.
+			for (reverse_prop, subframe) in &frame.reverse_properties {
+				for i in 0..self.subject_ids.len() {
+					let subject_id = self.subject_ids[i];
+					let subject = self.subjects.get(subject_id).unwrap();
+
+					if subject
+						.properties()
+						.get(reverse_prop)
+						.any(|value| value.id() == Some(id))
+					{
+						let nodes = self.frame(graph, true, false, &[subject_id], subframe);
+
+						for node in nodes.into_iter().filter_map(Indexed::into_indexed_node) {
+							output
+								.reverse_properties_or_default()
+								.insert(reverse_prop.clone(), node)
+						}
+					}
+				}
+			}
+
+			result.push(Indexed::new(
+				Object::node(output),
+				node.index().map(ToOwned::to_owned),
+			));
+
+			self.subject_stack.pop();
+		}
+
+		result
+	}
+
+	/// Checks if the given node matches the frame.
+	fn filter_subject(&self, node: &IndexedNode<T, B>, frame: &NodeFrame<T, B>, flags: Flags) -> bool {
+		let mut wildcard = true;
+		let mut matches_some = false;
+
+		if let Some(pattern) = &frame.id {
+			let matches = pattern.matches_opt(node.id.as_ref(), |a, b| a == b);
+			if !flags.require_all {
+				return matches;
+			}
+
+			if !matches {
+				return false;
+			}
+
+			matches_some = true
+		}
+
+		if let Some(type_frame) = &frame.types {
+			wildcard = false;
+			let types = node.types();
+
+			let matches = if type_frame.pattern.is_match_none() && type_frame.default.is_none() {
+				if !types.is_empty() {
+					return false;
+				}
+
+				true
+			} else if type_frame.pattern.is_wildcard() {
+				!types.is_empty()
+			} else {
+				type_frame.default.is_some()
+					|| match &type_frame.pattern {
+						Pattern::Any(expected) => expected.iter().any(|ty| types.contains(ty)),
+						Pattern::Wildcard => !types.is_empty(),
+					}
+			};
+
+			if !flags.require_all {
+				return matches;
+			}
+
+			if !matches {
+				return false;
+			}
+
+			matches_some |= matches
+		}
+
+		for (prop, prop_frame) in &frame.properties {
+			wildcard = false;
+			let values: Vec<_> = node.properties().get(prop).collect();
+
+			if values.is_empty() && prop_frame.default().is_some() {
+				continue;
+			}
+
+			let matches = match prop_frame {
+				PropertyFrame::None => {
+					if !values.is_empty() {
+						return false;
+					}
+
+					true
+				}
+				PropertyFrame::List(list_frame) => {
+					match values.first().and_then(|value| value.as_list()) {
+						Some(list) => match list_frame.item.as_deref() {
+							Some(PropertyFrame::Value(value_frame)) => list.iter().any(|item| {
+								item.as_value()
+									.map(|value| value_frame.matches(value))
+									.unwrap_or(false)
+							}),
+							Some(PropertyFrame::Node(node_frame)) => list
+								.iter()
+								.any(|item| self.node_match(node_frame, item, flags)),
+							_ => false,
+						},
+						None => false,
+					}
+				}
+				PropertyFrame::Value(value_frame) => values.iter().any(|value| {
+					value
+						.as_value()
+						.map(|value| value_frame.matches(value))
+						.unwrap_or(false)
+				}),
+				PropertyFrame::Node(node_frame) => {
+					if is_reference_frame(node_frame) {
+						values
+							.iter()
+							.any(|value| self.node_match(node_frame, value, flags))
+					} else {
+						!values.is_empty()
+					}
+				}
+			};
+
+			if !matches && flags.require_all {
+				return false;
+			}
+
+			matches_some |= matches
+		}
+
+		wildcard || matches_some
+	}
+
+	/// Checks if the given object is a reference to a node matching the frame.
+	fn node_match(&self, frame: &NodeFrame<T, B>, object: &IndexedObject<T, B>, flags: Flags) -> bool {
+		match object.id().and_then(|id| self.subjects.get(id)) {
+			Some(node) => self.filter_subject(node, frame, flags),
+			None => false,
+		}
+	}
+}
+
+/// Returns the identifier of the given object if it is a node reference.
+fn reference_id<T, B>(object: &IndexedObject<T, B>) -> Option<&Id<T, B>> {
+	match object.inner() {
+		Object::Node(node) if node.is_empty() => node.id.as_ref(),
+		_ => None,
+	}
+}
+
+/// Checks if the given node frame only matches node identifiers.
+fn is_reference_frame<T, B>(frame: &NodeFrame<T, B>) -> bool {
+	frame.id.is_some()
+		&& frame.types.is_none()
+		&& frame.embed.is_none()
+		&& frame.explicit.is_none()
+		&& frame.require_all.is_none()
+		&& frame.omit_default.is_none()
+		&& frame.default.is_none()
+		&& frame.graph.is_none()
+		&& frame.included.is_none()
+		&& frame.reverse_properties.is_empty()
+		&& frame.properties.is_empty()
+}
+
+/// Checks if the given value object matches the property frame.
+fn value_matches<T: PartialEq, B>(
+	frame: Option<&PropertyFrame<T, B>>,
+	object: &IndexedObject<T, B>,
+) -> bool {
+	match frame {
+		Some(PropertyFrame::Value(value_frame)) => object
+			.as_value()
+			.map(|value| value_frame.matches(value))
+			.unwrap_or(false),
+		_ => true,
+	}
+}
+
+/// Removes the given blank node identifiers from the output.
+fn prune_object<T: Eq + Hash, B: Eq + Hash>(object: &mut Object<T, B>, prune: &HashSet<B>) {
+	match object {
+		Object::Value(_) => (),
+		Object::List(list) => {
+			for item in list.iter_mut() {
+				prune_object(item, prune)
+			}
+		}
+		Object::Node(node) => prune_node(node, prune),
+	}
+}
+
+fn prune_node<T: Eq + Hash, B: Eq + Hash>(node: &mut Node<T, B>, prune: &HashSet<B>) {
+	if let Some(b) = node.id.as_ref().and_then(Id::as_blank) {
+		if prune.contains(b) {
+			node.id = None
+		}
+	}
+
+	for (_, objects) in node.properties_mut().iter_mut() {
+		for object in objects.iter_mut() {
+			prune_object(object, prune)
+		}
+	}
+
+	if let Some(reverse_properties) = node.reverse_properties_mut() {
+		for (_, nodes) in reverse_properties.iter_mut() {
+			for node in nodes.iter_mut() {
+				prune_node(node, prune)
+			}
+		}
+	}
+
+	if let Some(graph) = node.graph.take() {
+		node.graph = Some(
+			graph
+				.into_iter()
+				.map(|mut object| {
+					prune_object(&mut object, prune);
+					object
+				})
+				.collect(),
+		)
+	}
+
+	if let Some(included) = node.included.take() {
+		node.included = Some(
+			included
+				.into_iter()
+				.map(|mut node| {
+					prune_node(&mut node, prune);
+					node
+				})
+				.collect(),
+		)
+	}
+}
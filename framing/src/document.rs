@@ -0,0 +1,151 @@
+use json_ld_compaction::{compact_iri, CompactDocumentResult, CompactFragment};
+use json_ld_core::{
+	future::{BoxFuture, FutureExt},
+	IndexedObject, Loader, Term,
+};
+use json_ld_syntax::{IntoJson, Keyword};
+use std::hash::Hash;
+
+/// Framed document.
+///
+/// Result of the [framing algorithm](crate::FrameExpanded), before compaction.
+#[derive(Debug, Clone)]
+pub struct FramedDocument<T, B>(Vec<IndexedObject<T, B>>);
+
+impl<T, B> FramedDocument<T, B> {
+	/// Creates a new framed document from its top level objects.
+	pub fn new(objects: Vec<IndexedObject<T, B>>) -> Self {
+		Self(objects)
+	}
+
+	/// Returns the top level objects of the document.
+	pub fn objects(&self) -> &[IndexedObject<T, B>] {
+		&self.0
+	}
+
+	/// Turns this document into its top level objects.
+	pub fn into_objects(self) -> Vec<IndexedObject<T, B>> {
+		self.0
+	}
+
+	/// Compacts the framed document using the frame context.
+	///
+	/// If `omit_graph` is `true` and the output contains only one node
+	/// object, the top level `@graph` entry is omitted.
+	pub fn compact_full<'a, N, L>(
+		&'a self,
+		vocabulary: &'a mut N,
+		context: json_ld_context_processing::ProcessedRef<'a, 'a, T, B>,
+		loader: &'a mut L,
+		options: json_ld_compaction::Options,
+		omit_graph: bool,
+	) -> BoxFuture<'a, CompactDocumentResult<T, L>>
+	where
+		N: rdf_types::VocabularyMut<Iri = T, BlankId = B>,
+		T: Clone + Hash + Eq,
+		B: Clone + Hash + Eq,
+		L: Loader<T>,
+		//
+		N: Send + Sync,
+		T: Send + Sync,
+		B: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		async move {
+			let compacted = self
+				.0
+				.compact_fragment_full(
+					vocabulary,
+					context.processed(),
+					context.processed(),
+					Some("@graph"),
+					loader,
+					options,
+				)
+				.await?;
+
+			let mut items = match compacted {
+				json_syntax::Value::Array(items) => items,
+				other => vec![other],
+			};
+
+			let mut output = json_syntax::Object::new();
+
+			let json_context = IntoJson::into_json(context.unprocessed().clone());
+			if !json_context.is_null() && !json_context.is_empty_array_or_object() {
+				output.insert("@context".into(), json_context);
+			}
+
+			if omit_graph && options.compact_arrays && items.len() <= 1 {
+				if let Some(json_syntax::Value::Object(object)) = items.pop() {
+					for entry in object.entries() {
+						output.insert(entry.key.clone(), entry.value.clone());
+					}
+				}
+			} else {
+				let key = compact_iri(
+					vocabulary,
+					context.processed(),
+					&Term::Keyword(Keyword::Graph),
+					true,
+					false,
+					options,
+				)?;
+
+				output.insert(key.unwrap().into(), json_syntax::Value::Array(items));
+			}
+
+			let mut output = json_syntax::Value::Object(output);
+			cleanup_null(&mut output, true);
+			Ok(output)
+		}
+		.boxed()
+	}
+}
+
+/// Replaces the `@null` default values with `null`.
+///
+/// Once compacted, the `@null` default value is either `null` or a
+/// `{"@value": null}` object. Null values are removed from arrays. JSON
+/// literals and the `@context` entry of the top level object are left
+/// untouched.
+fn cleanup_null(value: &mut json_syntax::Value, top_level: bool) {
+	match value {
+		json_syntax::Value::Array(items) => {
+			for item in items.iter_mut() {
+				cleanup_null(item, false)
+			}
+
+			items.retain(|item| !item.is_null())
+		}
+		json_syntax::Value::Object(object) => {
+			let entry = |key: &str| object.get_unique(key).ok().flatten();
+
+			if entry("@type").and_then(json_syntax::Value::as_str) == Some("@json") {
+				return;
+			}
+
+			if entry("@value")
+				.map(json_syntax::Value::is_null)
+				.unwrap_or(false)
+			{
+				*value = json_syntax::Value::Null
+			} else {
+				let mut result = json_syntax::Object::new();
+
+				for entry in object.entries() {
+					let mut entry_value = entry.value.clone();
+					if !top_level || entry.key.as_str() != "@context" {
+						cleanup_null(&mut entry_value, false)
+					}
+
+					result.insert(entry.key.clone(), entry_value);
+				}
+
+				*object = result
+			}
+		}
+		_ => (),
+	}
+}
@@ -0,0 +1,929 @@
+use crate::{
+	frame::null_default, Embed, Error, Frame, ListFrame, NodeFrame, Options, Pattern,
+	PropertyFrame, TypeFrame, ValueFrame, NULL,
+};
+use json_ld_context_processing::{
+	algorithm::{expand_iri_simple, MalformedIri},
+	Process,
+};
+use json_ld_core::{
+	future::{BoxFuture, FutureExt},
+	object::Literal,
+	Context, Environment, Id, Indexed, IndexedObject, LangString, LenientLanguageTagBuf, Loader,
+	Node, Object, Term, Type, ValidId, Value,
+};
+use json_ld_syntax::{Keyword, Nullable, TryFromJson};
+use mown::Mown;
+use rdf_types::VocabularyMut;
+use std::hash::Hash;
+
+/// Result of the frame expansion.
+pub type ExpandFrameResult<T, B, L> = Result<Frame<T, B>, Error<<L as Loader<T>>::Error>>;
+
+type NodeFrameResult<T, B, L> = Result<NodeFrame<T, B>, Error<<L as Loader<T>>::Error>>;
+
+type PropertyFrameResult<T, B, L> = Result<PropertyFrame<T, B>, Error<<L as Loader<T>>::Error>>;
+
+/// Frame expansion.
+///
+/// Expands a JSON-LD frame document, following the
+/// [frame expansion](https://www.w3.org/TR/json-ld11-framing/#frame-expansion)
+/// rules. Contrarily to the regular expansion algorithm, the frame keywords
+/// (`@embed`, `@explicit`, `@omitDefault`, `@requireAll`, `@default`) are
+/// preserved, as well as wildcards (`{}`) and match-none patterns (`[]`).
+pub trait ExpandFrame {
+	/// Expands the frame with the given `active_context`.
+	///
+	/// The `active_context` is usually the result of the processing of the
+	/// frame `@context` entry. Nested contexts are processed on the fly.
+	fn expand_frame_full<'a, N, L>(
+		&'a self,
+		vocabulary: &'a mut N,
+		active_context: &'a Context<N::Iri, N::BlankId>,
+		base_url: Option<&'a N::Iri>,
+		loader: &'a mut L,
+		options: Options,
+	) -> BoxFuture<'a, ExpandFrameResult<N::Iri, N::BlankId, L>>
+	where
+		N: VocabularyMut,
+		N::Iri: Clone + Eq + Hash,
+		N::BlankId: Clone + Eq + Hash,
+		L: Loader<N::Iri>,
+		//
+		N: Send + Sync,
+		N::Iri: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send;
+}
+
+impl ExpandFrame for json_syntax::Value {
+	fn expand_frame_full<'a, N, L>(
+		&'a self,
+		vocabulary: &'a mut N,
+		active_context: &'a Context<N::Iri, N::BlankId>,
+		base_url: Option<&'a N::Iri>,
+		loader: &'a mut L,
+		options: Options,
+	) -> BoxFuture<'a, ExpandFrameResult<N::Iri, N::BlankId, L>>
+	where
+		N: VocabularyMut,
+		N::Iri: Clone + Eq + Hash,
+		N::BlankId: Clone + Eq + Hash,
+		L: Loader<N::Iri>,
+		//
+		N: Send + Sync,
+		N::Iri: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		async move {
+			let object = match self {
+				json_syntax::Value::Object(object) => object,
+				json_syntax::Value::Array(items) => match items.as_slice() {
+					[] => return Ok(Frame::default()),
+					[json_syntax::Value::Object(object)] => object,
+					_ => return Err(Error::InvalidFrame),
+				},
+				_ => return Err(Error::InvalidFrame),
+			};
+
+			// If the frame has a top level `@graph` entry, the default graph
+			// is framed instead of the merged graph.
+			let mut default_graph = false;
+			let mut graph_only = true;
+			for entry in object.iter() {
+				match expand_key(vocabulary, loader, active_context, entry.key.as_str()) {
+					Term::Keyword(Keyword::Graph) => default_graph = true,
+					Term::Keyword(Keyword::Context) => (),
+					_ => graph_only = false,
+				}
+			}
+
+			let node = if default_graph && graph_only {
+				// The frame is a top level `@graph` wrapper.
+				let active_context = match object
+					.get_unique("@context")
+					.map_err(Error::duplicate_key_ref)?
+				{
+					Some(local_context) => Mown::Owned(
+						process_local_context(
+							vocabulary,
+							active_context,
+							loader,
+							local_context,
+							base_url,
+							options,
+						)
+						.await?,
+					),
+					None => Mown::Borrowed(active_context),
+				};
+
+				let mut node = NodeFrame::default();
+				for entry in object.iter() {
+					if let Term::Keyword(Keyword::Graph) =
+						expand_key(vocabulary, loader, &active_context, entry.key.as_str())
+					{
+						node = expand_graph_frame(
+							vocabulary,
+							&active_context,
+							&entry.value,
+							base_url,
+							loader,
+							options,
+						)
+						.await?
+					}
+				}
+
+				node
+			} else {
+				expand_node_frame(
+					vocabulary,
+					active_context,
+					None,
+					object,
+					base_url,
+					loader,
+					options,
+				)
+				.await?
+			};
+
+			Ok(Frame {
+				node,
+				default_graph,
+			})
+		}
+		.boxed()
+	}
+}
+
+fn expand_key<N, L>(
+	vocabulary: &mut N,
+	loader: &mut L,
+	active_context: &Context<N::Iri, N::BlankId>,
+	key: &str,
+) -> Term<N::Iri, N::BlankId>
+where
+	N: VocabularyMut,
+	N::Iri: Clone,
+	N::BlankId: Clone,
+{
+	expand_iri(vocabulary, loader, active_context, key, false, true)
+}
+
+fn expand_iri<N, L>(
+	vocabulary: &mut N,
+	loader: &mut L,
+	active_context: &Context<N::Iri, N::BlankId>,
+	value: &str,
+	document_relative: bool,
+	vocab: bool,
+) -> Term<N::Iri, N::BlankId>
+where
+	N: VocabularyMut,
+	N::Iri: Clone,
+	N::BlankId: Clone,
+{
+	expand_iri_simple::<MalformedIri, _, _, _>(
+		&mut Environment {
+			vocabulary,
+			loader,
+			warnings: &mut (),
+		},
+		active_context,
+		Nullable::Some(value.into()),
+		document_relative,
+		vocab,
+	)
+}
+
+async fn process_local_context<N, L>(
+	vocabulary: &mut N,
+	active_context: &Context<N::Iri, N::BlankId>,
+	loader: &mut L,
+	local_context: &json_syntax::Value,
+	base_url: Option<&N::Iri>,
+	options: Options,
+) -> Result<Context<N::Iri, N::BlankId>, Error<L::Error>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone + Eq + Hash,
+	N::BlankId: Clone + Eq + Hash,
+	L: Loader<N::Iri>,
+	//
+	N: Send + Sync,
+	N::Iri: Send + Sync,
+	N::BlankId: Send + Sync,
+	L: Send + Sync,
+	L::Error: Send,
+{
+	let local_context = json_ld_syntax::context::Context::try_from_json(local_context.clone())?;
+	Ok(local_context
+		.process_with(
+			vocabulary,
+			active_context,
+			loader,
+			base_url.cloned(),
+			options.into(),
+		)
+		.await?
+		.into_processed())
+}
+
+/// Returns the unique item of the given value if it is an array with one
+/// element, or the value itself.
+fn single(value: &json_syntax::Value) -> Option<&json_syntax::Value> {
+	match value {
+		json_syntax::Value::Array(items) => match items.as_slice() {
+			[item] => Some(item),
+			_ => None,
+		},
+		value => Some(value),
+	}
+}
+
+fn expand_flag<E>(value: &json_syntax::Value) -> Result<bool, Error<E>> {
+	match single(value) {
+		Some(json_syntax::Value::Boolean(b)) => Ok(*b),
+		_ => Err(Error::InvalidFrame),
+	}
+}
+
+fn is_empty_object(value: &json_syntax::Value) -> bool {
+	matches!(value, json_syntax::Value::Object(o) if o.is_empty())
+}
+
+fn expand_graph_frame<'a, N, L>(
+	vocabulary: &'a mut N,
+	active_context: &'a Context<N::Iri, N::BlankId>,
+	value: &'a json_syntax::Value,
+	base_url: Option<&'a N::Iri>,
+	loader: &'a mut L,
+	options: Options,
+) -> BoxFuture<'a, NodeFrameResult<N::Iri, N::BlankId, L>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone + Eq + Hash,
+	N::BlankId: Clone + Eq + Hash,
+	L: Loader<N::Iri>,
+	//
+	N: Send + Sync,
+	N::Iri: Send + Sync,
+	N::BlankId: Send + Sync,
+	L: Send + Sync,
+	L::Error: Send,
+{
+	async move {
+		let value = match value {
+			json_syntax::Value::Array(items) => items.first(),
+			value => Some(value),
+		};
+
+		match value {
+			Some(json_syntax::Value::Object(object)) => {
+				expand_node_frame(
+					vocabulary,
+					active_context,
+					None,
+					object,
+					base_url,
+					loader,
+					options,
+				)
+				.await
+			}
+			_ => Ok(NodeFrame::default()),
+		}
+	}
+	.boxed()
+}
+
+fn expand_id_pattern<N, L>(
+	vocabulary: &mut N,
+	loader: &mut L,
+	active_context: &Context<N::Iri, N::BlankId>,
+	value: &json_syntax::Value,
+) -> Result<Pattern<Id<N::Iri, N::BlankId>>, Error<L::Error>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone,
+	N::BlankId: Clone,
+	L: Loader<N::Iri>,
+{
+	let items = match value {
+		json_syntax::Value::Array(items) => items.as_slice(),
+		value => std::slice::from_ref(value),
+	};
+
+	if items.is_empty() || items.iter().any(is_empty_object) {
+		return Ok(Pattern::Wildcard);
+	}
+
+	let mut ids = Vec::with_capacity(items.len());
+	for item in items {
+		match item {
+			json_syntax::Value::String(s) => {
+				match expand_iri(vocabulary, loader, active_context, s, true, false) {
+					Term::Id(id) => ids.push(id),
+					_ => return Err(Error::InvalidFrame),
+				}
+			}
+			_ => return Err(Error::InvalidFrame),
+		}
+	}
+
+	Ok(Pattern::Any(ids))
+}
+
+fn expand_type_frame<N, L>(
+	vocabulary: &mut N,
+	loader: &mut L,
+	active_context: &Context<N::Iri, N::BlankId>,
+	value: &json_syntax::Value,
+) -> Result<TypeFrame<N::Iri, N::BlankId>, Error<L::Error>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone,
+	N::BlankId: Clone,
+	L: Loader<N::Iri>,
+{
+	let items = match value {
+		json_syntax::Value::Array(items) => items.as_slice(),
+		value => std::slice::from_ref(value),
+	};
+
+	let mut wildcard = false;
+	let mut types = Vec::with_capacity(items.len());
+	let mut default = None;
+
+	let expand_type = |vocabulary: &mut N, loader: &mut L, s: &str| match expand_iri(
+		vocabulary,
+		loader,
+		active_context,
+		s,
+		true,
+		true,
+	) {
+		Term::Id(id) => Ok(id),
+		_ => Err(Error::InvalidFrame),
+	};
+
+	for item in items {
+		match item {
+			json_syntax::Value::String(s) => types.push(expand_type(vocabulary, loader, s)?),
+			json_syntax::Value::Object(o) if o.is_empty() => wildcard = true,
+			json_syntax::Value::Object(o) => {
+				match o
+					.get_unique("@default")
+					.map_err(Error::duplicate_key_ref)?
+				{
+					Some(value) if o.len() == 1 => {
+						let values = match value {
+							json_syntax::Value::Array(values) => values.as_slice(),
+							value => std::slice::from_ref(value),
+						};
+
+						let mut default_types = Vec::with_capacity(values.len());
+						for value in values {
+							match value {
+								json_syntax::Value::String(s) => {
+									default_types.push(expand_type(vocabulary, loader, s)?)
+								}
+								_ => return Err(Error::InvalidFrame),
+							}
+						}
+
+						default = Some(default_types)
+					}
+					_ => return Err(Error::InvalidFrame),
+				}
+			}
+			_ => return Err(Error::InvalidFrame),
+		}
+	}
+
+	let pattern = if wildcard && types.is_empty() {
+		Pattern::Wildcard
+	} else {
+		Pattern::Any(types)
+	};
+
+	Ok(TypeFrame { pattern, default })
+}
+
+fn expand_node_frame<'a, N, L>(
+	vocabulary: &'a mut N,
+	active_context: &'a Context<N::Iri, N::BlankId>,
+	active_property: Option<&'a str>,
+	object: &'a json_syntax::Object,
+	base_url: Option<&'a N::Iri>,
+	loader: &'a mut L,
+	options: Options,
+) -> BoxFuture<'a, NodeFrameResult<N::Iri, N::BlankId, L>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone + Eq + Hash,
+	N::BlankId: Clone + Eq + Hash,
+	L: Loader<N::Iri>,
+	//
+	N: Send + Sync,
+	N::Iri: Send + Sync,
+	N::BlankId: Send + Sync,
+	L: Send + Sync,
+	L::Error: Send,
+{
+	async move {
+		let active_context = match object
+			.get_unique("@context")
+			.map_err(Error::duplicate_key_ref)?
+		{
+			Some(local_context) => Mown::Owned(
+				process_local_context(
+					vocabulary,
+					active_context,
+					loader,
+					local_context,
+					base_url,
+					options,
+				)
+				.await?,
+			),
+			None => Mown::Borrowed(active_context),
+		};
+
+		let mut frame = NodeFrame::default();
+
+		for entry in object.iter() {
+			let key = entry.key.as_str();
+			let value = &entry.value;
+
+			match key {
+				"@embed" => {
+					frame.embed = Some(
+						single(value)
+							.and_then(Embed::from_json)
+							.ok_or(Error::InvalidEmbedValue)?,
+					);
+					continue;
+				}
+				"@explicit" => {
+					frame.explicit = Some(expand_flag(value)?);
+					continue;
+				}
+				"@requireAll" => {
+					frame.require_all = Some(expand_flag(value)?);
+					continue;
+				}
+				"@omitDefault" => {
+					frame.omit_default = Some(expand_flag(value)?);
+					continue;
+				}
+				"@default" => {
+					frame.default = Some(expand_default_value(
+						vocabulary,
+						loader,
+						&active_context,
+						active_property,
+						value,
+					)?);
+					continue;
+				}
+				_ => (),
+			}
+
+			match expand_key(vocabulary, loader, &active_context, key) {
+				Term::Keyword(Keyword::Context) => (),
+				Term::Keyword(Keyword::Id) => {
+					frame.id = Some(expand_id_pattern(
+						vocabulary,
+						loader,
+						&active_context,
+						value,
+					)?)
+				}
+				Term::Keyword(Keyword::Type) => {
+					frame.types = Some(expand_type_frame(
+						vocabulary,
+						loader,
+						&active_context,
+						value,
+					)?)
+				}
+				Term::Keyword(Keyword::Graph) => {
+					frame.graph = Some(Box::new(
+						expand_graph_frame(
+							vocabulary,
+							&active_context,
+							value,
+							base_url,
+							loader,
+							options,
+						)
+						.await?,
+					))
+				}
+				Term::Keyword(Keyword::Included) => {
+					frame.included = Some(Box::new(
+						expand_graph_frame(
+							vocabulary,
+							&active_context,
+							value,
+							base_url,
+							loader,
+							options,
+						)
+						.await?,
+					))
+				}
+				Term::Keyword(Keyword::Reverse) => match value {
+					json_syntax::Value::Object(reverse) => {
+						for entry in reverse.iter() {
+							if let Term::Id(prop) =
+								expand_key(vocabulary, loader, &active_context, entry.key.as_str())
+							{
+								let subframe = expand_graph_frame(
+									vocabulary,
+									&active_context,
+									&entry.value,
+									base_url,
+									loader,
+									options,
+								)
+								.await?;
+								frame.reverse_properties.insert(prop, subframe);
+							}
+						}
+					}
+					_ => return Err(Error::InvalidFrame),
+				},
+				Term::Keyword(_) | Term::Null => (),
+				Term::Id(prop) => {
+					let definition = active_context.get(key);
+
+					// Property-scoped context.
+					let property_context = match definition.and_then(|d| d.context()) {
+						Some(local_context) => Mown::Owned(
+							local_context
+								.process_with(
+									vocabulary,
+									&active_context,
+									loader,
+									definition.and_then(|d| d.base_url()).cloned(),
+									json_ld_context_processing::Options::from(options)
+										.with_override(),
+								)
+								.await?
+								.into_processed(),
+						),
+						None => Mown::Borrowed(&*active_context),
+					};
+
+					if definition.map(|d| d.reverse_property()).unwrap_or(false) {
+						let subframe = expand_graph_frame(
+							vocabulary,
+							&property_context,
+							value,
+							base_url,
+							loader,
+							options,
+						)
+						.await?;
+						frame.reverse_properties.insert(prop, subframe);
+					} else {
+						let property_frame = expand_property_frame(
+							vocabulary,
+							&property_context,
+							key,
+							value,
+							base_url,
+							loader,
+							options,
+						)
+						.await?;
+						frame.properties.insert(prop, property_frame);
+					}
+				}
+			}
+		}
+
+		Ok(frame)
+	}
+	.boxed()
+}
+
+fn expand_property_frame<'a, N, L>(
+	vocabulary: &'a mut N,
+	active_context: &'a Context<N::Iri, N::BlankId>,
+	active_property: &'a str,
+	value: &'a json_syntax::Value,
+	base_url: Option<&'a N::Iri>,
+	loader: &'a mut L,
+	options: Options,
+) -> BoxFuture<'a, PropertyFrameResult<N::Iri, N::BlankId, L>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone + Eq + Hash,
+	N::BlankId: Clone + Eq + Hash,
+	L: Loader<N::Iri>,
+	//
+	N: Send + Sync,
+	N::Iri: Send + Sync,
+	N::BlankId: Send + Sync,
+	L: Send + Sync,
+	L::Error: Send,
+{
+	async move {
+		match value {
+			json_syntax::Value::Array(items) => match items.first() {
+				Some(item) => {
+					expand_property_frame(
+						vocabulary,
+						active_context,
+						active_property,
+						item,
+						base_url,
+						loader,
+						options,
+					)
+					.await
+				}
+				None => Ok(PropertyFrame::None),
+			},
+			json_syntax::Value::Object(object) => {
+				let mut list = None;
+				let mut is_value = false;
+				for entry in object.iter() {
+					match expand_key(vocabulary, loader, active_context, entry.key.as_str()) {
+						Term::Keyword(Keyword::List) => list = Some(&entry.value),
+						Term::Keyword(Keyword::Value) => is_value = true,
+						_ => (),
+					}
+				}
+
+				if let Some(items) = list {
+					let item = match items {
+						json_syntax::Value::Array(items) => items.first(),
+						item => Some(item),
+					};
+
+					let item = match item {
+						Some(item) => Some(Box::new(
+							expand_property_frame(
+								vocabulary,
+								active_context,
+								active_property,
+								item,
+								base_url,
+								loader,
+								options,
+							)
+							.await?,
+						)),
+						None => None,
+					};
+
+					Ok(PropertyFrame::List(ListFrame { item }))
+				} else if is_value {
+					Ok(PropertyFrame::Value(expand_value_frame(
+						vocabulary,
+						loader,
+						active_context,
+						object,
+					)?))
+				} else {
+					Ok(PropertyFrame::Node(
+						expand_node_frame(
+							vocabulary,
+							active_context,
+							Some(active_property),
+							object,
+							base_url,
+							loader,
+							options,
+						)
+						.await?,
+					))
+				}
+			}
+			json_syntax::Value::Null => Ok(PropertyFrame::None),
+			scalar => {
+				let definition = active_context.get(active_property);
+				let ty = definition.and_then(|d| d.typ());
+
+				match (ty, scalar) {
+					(Some(Type::Id | Type::Vocab), json_syntax::Value::String(s)) => {
+						let vocab = matches!(ty, Some(Type::Vocab));
+						match expand_iri(vocabulary, loader, active_context, s, true, vocab) {
+							Term::Id(id) => Ok(PropertyFrame::Node(NodeFrame {
+								id: Some(Pattern::Any(vec![id])),
+								..Default::default()
+							})),
+							_ => Err(Error::InvalidFrame),
+						}
+					}
+					_ => {
+						let literal = Literal::try_from(scalar.clone())
+							.map_err(|_| Error::InvalidFrame)?;
+						let types = match ty {
+							Some(Type::Iri(t)) => Some(Pattern::Any(vec![t.clone()])),
+							_ => None,
+						};
+
+						Ok(PropertyFrame::Value(ValueFrame {
+							value: Some(Pattern::Any(vec![literal])),
+							types,
+							language: None,
+						}))
+					}
+				}
+			}
+		}
+	}
+	.boxed()
+}
+
+fn expand_value_frame<N, L>(
+	vocabulary: &mut N,
+	loader: &mut L,
+	active_context: &Context<N::Iri, N::BlankId>,
+	object: &json_syntax::Object,
+) -> Result<ValueFrame<N::Iri>, Error<L::Error>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone,
+	N::BlankId: Clone,
+	L: Loader<N::Iri>,
+{
+	let mut frame = ValueFrame {
+		value: None,
+		types: None,
+		language: None,
+	};
+
+	for entry in object.iter() {
+		let items = match &entry.value {
+			json_syntax::Value::Array(items) => items.as_slice(),
+			value => std::slice::from_ref(value),
+		};
+
+		let wildcard = items.iter().any(is_empty_object);
+
+		match expand_key(vocabulary, loader, active_context, entry.key.as_str()) {
+			Term::Keyword(Keyword::Value) => {
+				frame.value = Some(if wildcard {
+					Pattern::Wildcard
+				} else {
+					let mut values = Vec::with_capacity(items.len());
+					for item in items {
+						values.push(Literal::try_from(item.clone()).map_err(|_| Error::InvalidFrame)?)
+					}
+					Pattern::Any(values)
+				})
+			}
+			Term::Keyword(Keyword::Type) => {
+				frame.types = Some(if wildcard {
+					Pattern::Wildcard
+				} else {
+					let mut types = Vec::with_capacity(items.len());
+					for item in items {
+						match item
+							.as_str()
+							.map(|s| expand_iri(vocabulary, loader, active_context, s, true, true))
+						{
+							Some(Term::Id(Id::Valid(ValidId::Iri(iri)))) => types.push(iri),
+							_ => return Err(Error::InvalidFrame),
+						}
+					}
+					Pattern::Any(types)
+				})
+			}
+			Term::Keyword(Keyword::Language) => {
+				frame.language = Some(if wildcard {
+					Pattern::Wildcard
+				} else {
+					let mut languages = Vec::with_capacity(items.len());
+					for item in items {
+						match item.as_str() {
+							Some(s) => languages.push(s.to_lowercase()),
+							None => return Err(Error::InvalidFrame),
+						}
+					}
+					Pattern::Any(languages)
+				})
+			}
+			_ => (),
+		}
+	}
+
+	Ok(frame)
+}
+
+/// Expands the value of a `@default` entry.
+fn expand_default_value<N, L>(
+	vocabulary: &mut N,
+	loader: &mut L,
+	active_context: &Context<N::Iri, N::BlankId>,
+	active_property: Option<&str>,
+	value: &json_syntax::Value,
+) -> Result<Vec<IndexedObject<N::Iri, N::BlankId>>, Error<L::Error>>
+where
+	N: VocabularyMut,
+	N::Iri: Clone,
+	N::BlankId: Clone,
+	L: Loader<N::Iri>,
+{
+	let items = match value {
+		json_syntax::Value::Array(items) => items.as_slice(),
+		value => std::slice::from_ref(value),
+	};
+
+	let mut result = Vec::with_capacity(items.len());
+	for item in items {
+		let object = match item {
+			json_syntax::Value::Null => null_default(),
+			json_syntax::Value::String(s) if s.as_str() == NULL => null_default(),
+			json_syntax::Value::Object(object) => {
+				let mut value = None;
+				let mut id = None;
+				let mut ty = None;
+				let mut language = None;
+
+				for entry in object.iter() {
+					match expand_key(vocabulary, loader, active_context, entry.key.as_str()) {
+						Term::Keyword(Keyword::Value) => value = Some(&entry.value),
+						Term::Keyword(Keyword::Id) => id = entry.value.as_str(),
+						Term::Keyword(Keyword::Type) => ty = entry.value.as_str(),
+						Term::Keyword(Keyword::Language) => language = entry.value.as_str(),
+						_ => (),
+					}
+				}
+
+				match (value, id) {
+					(Some(value), None) => {
+						let literal =
+							Literal::try_from(value.clone()).map_err(|_| Error::InvalidFrame)?;
+						let value = match (ty, language) {
+							(Some(ty), None) => {
+								match expand_iri(vocabulary, loader, active_context, ty, true, true)
+								{
+									Term::Id(Id::Valid(ValidId::Iri(ty))) => {
+										Value::Literal(literal, Some(ty))
+									}
+									_ => return Err(Error::InvalidFrame),
+								}
+							}
+							(None, Some(language)) => match literal {
+								Literal::String(s) => Value::LangString(
+									LangString::new(
+										s,
+										Some(
+											LenientLanguageTagBuf::new(language.to_owned()).0,
+										),
+										None,
+									)
+									.map_err(|_| Error::InvalidFrame)?,
+								),
+								_ => return Err(Error::InvalidFrame),
+							},
+							(None, None) => Value::Literal(literal, None),
+							_ => return Err(Error::InvalidFrame),
+						};
+
+						Indexed::none(Object::Value(value))
+					}
+					(None, Some(id)) => {
+						match expand_iri(vocabulary, loader, active_context, id, true, false) {
+							Term::Id(id) => Indexed::none(Object::node(Node::with_id(id))),
+							_ => return Err(Error::InvalidFrame),
+						}
+					}
+					_ => return Err(Error::InvalidFrame),
+				}
+			}
+			scalar => {
+				let literal = Literal::try_from(scalar.clone()).map_err(|_| Error::InvalidFrame)?;
+				let definition = active_property.and_then(|p| active_context.get(p));
+				match (definition.and_then(|d| d.typ()), literal) {
+					(Some(Type::Id | Type::Vocab), Literal::String(s)) => {
+						match expand_iri(vocabulary, loader, active_context, &s, true, false) {
+							Term::Id(id) => Indexed::none(Object::node(Node::with_id(id))),
+							_ => return Err(Error::InvalidFrame),
+						}
+					}
+					(Some(Type::Iri(ty)), literal) => {
+						Indexed::none(Object::Value(Value::Literal(literal, Some(ty.clone()))))
+					}
+					(_, literal) => Indexed::none(Object::Value(Value::Literal(literal, None))),
+				}
+			}
+		};
+
+		result.push(object)
+	}
+
+	Ok(result)
+}
@@ -0,0 +1,251 @@
+use crate::Embed;
+use indexmap::IndexMap;
+use json_ld_core::{object::Literal, Id, IndexedObject, Value};
+use std::hash::Hash;
+
+/// Expanded frame.
+///
+/// A frame is a node frame describing what node objects must be matched
+/// (duck typing) and how the matched node objects must be embedded in the
+/// output.
+#[derive(Debug, Clone)]
+pub struct Frame<T, B> {
+	/// Top level node frame.
+	pub node: NodeFrame<T, B>,
+
+	/// Whether or not the frame applies to the default graph.
+	///
+	/// This is `true` when the top level frame object has a `@graph` entry.
+	/// Otherwise the frame applies to the merge of every graph of the input.
+	pub default_graph: bool,
+}
+
+impl<T, B> Default for Frame<T, B> {
+	fn default() -> Self {
+		Self {
+			node: NodeFrame::default(),
+			default_graph: false,
+		}
+	}
+}
+
+/// Pattern matching a set of values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pattern<T> {
+	/// Wildcard (`{}`), matching any value.
+	Wildcard,
+
+	/// Matches any of the given values.
+	///
+	/// If empty (`[]`), this pattern matches the absence of value.
+	Any(Vec<T>),
+}
+
+impl<T> Pattern<T> {
+	/// Checks if this pattern is the wildcard pattern.
+	pub fn is_wildcard(&self) -> bool {
+		matches!(self, Self::Wildcard)
+	}
+
+	/// Checks if this pattern matches the absence of value.
+	pub fn is_match_none(&self) -> bool {
+		matches!(self, Self::Any(values) if values.is_empty())
+	}
+
+	/// Checks if the given optional value matches this pattern.
+	pub fn matches_opt<U>(&self, value: Option<&U>, mut eq: impl FnMut(&T, &U) -> bool) -> bool {
+		match (self, value) {
+			(Self::Wildcard, value) => value.is_some(),
+			(Self::Any(values), Some(value)) => values.iter().any(|v| eq(v, value)),
+			(Self::Any(values), None) => values.is_empty(),
+		}
+	}
+}
+
+/// Frame `@type` entry.
+#[derive(Debug, Clone)]
+pub struct TypeFrame<T, B> {
+	/// Type pattern.
+	pub pattern: Pattern<Id<T, B>>,
+
+	/// Default types (`{"@default": ...}`).
+	///
+	/// A frame with a default type matches any node object. Node objects
+	/// without type are given the default types in the output.
+	pub default: Option<Vec<Id<T, B>>>,
+}
+
+/// Value pattern.
+///
+/// Value object appearing in a frame, where each of the `@value`, `@type` and
+/// `@language` entries may be a wildcard (`{}`), a list of possible values
+/// or absent.
+#[derive(Debug, Clone)]
+pub struct ValueFrame<T> {
+	/// `@value` entry.
+	pub value: Option<Pattern<Literal>>,
+
+	/// `@type` entry.
+	pub types: Option<Pattern<T>>,
+
+	/// `@language` entry.
+	pub language: Option<Pattern<String>>,
+}
+
+impl<T: PartialEq> ValueFrame<T> {
+	/// Checks if the given value object matches this value pattern.
+	pub fn matches(&self, value: &Value<T>) -> bool {
+		if self.value.is_none() && self.types.is_none() && self.language.is_none() {
+			return true;
+		}
+
+		let literal = match value {
+			Value::Literal(l, _) => Some(l.clone()),
+			Value::LangString(s) => Some(Literal::String(s.as_str().into())),
+			Value::Json(_) => None,
+		};
+
+		let value_matches = match &self.value {
+			Some(pattern) => pattern.matches_opt(literal.as_ref(), |a, b| a == b),
+			None => false,
+		};
+
+		let type_matches = match &self.types {
+			Some(pattern) => pattern.matches_opt(value.literal_type(), |a, b| a == b),
+			None => value.literal_type().is_none(),
+		};
+
+		let language = value.language().map(|l| l.as_str().to_lowercase());
+		let language_matches = match &self.language {
+			Some(pattern) => {
+				pattern.matches_opt(language.as_ref(), |a, b| a.to_lowercase() == *b)
+			}
+			None => language.is_none(),
+		};
+
+		value_matches && type_matches && language_matches
+	}
+}
+
+/// List pattern (`{"@list": [...]}`).
+#[derive(Debug, Clone)]
+pub struct ListFrame<T, B> {
+	/// Pattern matched by the list items.
+	pub item: Option<Box<PropertyFrame<T, B>>>,
+}
+
+/// Frame associated to a property.
+#[derive(Debug, Clone)]
+pub enum PropertyFrame<T, B> {
+	/// Match none (`[]`).
+	///
+	/// Matches node objects that do not have the property.
+	None,
+
+	/// Node frame.
+	Node(NodeFrame<T, B>),
+
+	/// Value pattern.
+	Value(ValueFrame<T>),
+
+	/// List pattern.
+	List(ListFrame<T, B>),
+}
+
+impl<T, B> PropertyFrame<T, B> {
+	/// Returns the node frame, if this is a node frame.
+	pub fn as_node(&self) -> Option<&NodeFrame<T, B>> {
+		match self {
+			Self::Node(frame) => Some(frame),
+			_ => None,
+		}
+	}
+
+	/// Returns the default value of the property, if any.
+	pub fn default(&self) -> Option<&[IndexedObject<T, B>]> {
+		match self {
+			Self::Node(frame) => frame.default.as_deref(),
+			_ => None,
+		}
+	}
+}
+
+/// Node frame.
+#[derive(Debug, Clone)]
+pub struct NodeFrame<T, B> {
+	/// `@id` entry.
+	pub id: Option<Pattern<Id<T, B>>>,
+
+	/// `@type` entry.
+	pub types: Option<TypeFrame<T, B>>,
+
+	/// `@embed` flag.
+	pub embed: Option<Embed>,
+
+	/// `@explicit` flag.
+	pub explicit: Option<bool>,
+
+	/// `@requireAll` flag.
+	pub require_all: Option<bool>,
+
+	/// `@omitDefault` flag.
+	pub omit_default: Option<bool>,
+
+	/// `@default` entry.
+	///
+	/// The `@null` default value is represented by a `null` literal value
+	/// object, which never appears in expanded documents. It is replaced by
+	/// `null` once the framed document is compacted.
+	pub default: Option<Vec<IndexedObject<T, B>>>,
+
+	/// `@graph` entry.
+	pub graph: Option<Box<NodeFrame<T, B>>>,
+
+	/// `@included` entry.
+	pub included: Option<Box<NodeFrame<T, B>>>,
+
+	/// `@reverse` entry.
+	pub reverse_properties: IndexMap<Id<T, B>, NodeFrame<T, B>>,
+
+	/// Properties.
+	pub properties: IndexMap<Id<T, B>, PropertyFrame<T, B>>,
+}
+
+impl<T, B> Default for NodeFrame<T, B> {
+	fn default() -> Self {
+		Self {
+			id: None,
+			types: None,
+			embed: None,
+			explicit: None,
+			require_all: None,
+			omit_default: None,
+			default: None,
+			graph: None,
+			included: None,
+			reverse_properties: IndexMap::new(),
+			properties: IndexMap::new(),
+		}
+	}
+}
+
+impl<T: Eq + Hash, B: Eq + Hash> NodeFrame<T, B> {
+	/// Returns the frame associated to the given property, if any.
+	pub fn property(&self, prop: &Id<T, B>) -> Option<&PropertyFrame<T, B>> {
+		self.properties.get(prop)
+	}
+}
+
+/// The `@null` default value, as written in frames.
+pub(crate) const NULL: &str = "@null";
+
+/// Creates the value object representing the `@null` default value.
+///
+/// Expansion removes value objects with a `null` value, so this value object
+/// cannot be confused with the values of the framed document.
+pub(crate) fn null_default<T, B>() -> IndexedObject<T, B> {
+	json_ld_core::Indexed::none(json_ld_core::Object::Value(Value::Literal(
+		Literal::Null,
+		None,
+	)))
+}
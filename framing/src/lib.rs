@@ -0,0 +1,157 @@
+//! This library implements the [JSON-LD framing algorithm](https://www.w3.org/TR/json-ld11-framing/#framing-algorithm)
+//! for the [`json-ld` crate](https://crates.io/crates/json-ld).
+//!
+//! # Usage
+//!
+//! A frame document is first expanded into a [`Frame`] using the
+//! [`ExpandFrame`] trait. The frame is then matched against an expanded
+//! document using the [`FrameExpanded`] trait, producing a [`FramedDocument`]
+//! that can be compacted using the context of the frame.
+use json_ld_core::ProcessingMode;
+use json_ld_syntax::ErrorCode;
+
+mod algorithm;
+mod document;
+mod expansion;
+mod frame;
+
+pub use algorithm::*;
+pub use document::*;
+pub use expansion::*;
+pub use frame::*;
+
+/// Framing error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error<E> {
+	#[error("Invalid frame")]
+	InvalidFrame,
+
+	#[error("Invalid `@embed` value")]
+	InvalidEmbedValue,
+
+	#[error("Invalid context: {0}")]
+	ContextSyntax(#[from] json_ld_syntax::context::InvalidContext),
+
+	#[error("Context processing failed: {0}")]
+	ContextProcessing(json_ld_context_processing::Error<E>),
+
+	#[error("Duplicate key `{0}`")]
+	DuplicateKey(json_syntax::object::Key),
+}
+
+impl<E> Error<E> {
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::InvalidFrame => ErrorCode::InvalidFrame,
+			Self::InvalidEmbedValue => ErrorCode::InvalidEmbedValue,
+			Self::ContextSyntax(e) => e.code(),
+			Self::ContextProcessing(e) => e.code(),
+			Self::DuplicateKey(_) => ErrorCode::DuplicateKey,
+		}
+	}
+
+	pub fn duplicate_key_ref(
+		json_syntax::object::Duplicate(a, _b): json_syntax::object::Duplicate<
+			&json_syntax::object::Entry,
+		>,
+	) -> Self {
+		Self::DuplicateKey(a.key.clone())
+	}
+}
+
+impl<E> From<json_ld_context_processing::Error<E>> for Error<E> {
+	fn from(e: json_ld_context_processing::Error<E>) -> Self {
+		Self::ContextProcessing(e)
+	}
+}
+
+/// Embedding policy, given by the `@embed` frame keyword.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Embed {
+	/// Always embed node objects as property values, unless this would cause
+	/// a circular reference.
+	Always,
+
+	/// Only embed the first occurrence of a node object, other occurrences
+	/// are replaced by node references.
+	#[default]
+	Once,
+
+	/// Always use node references.
+	Never,
+}
+
+impl Embed {
+	/// Parses an `@embed` value.
+	///
+	/// The `true` value is equivalent to `@once` and `false` to `@never`.
+	pub fn from_json(value: &json_syntax::Value) -> Option<Self> {
+		match value {
+			json_syntax::Value::Boolean(true) => Some(Self::Once),
+			json_syntax::Value::Boolean(false) => Some(Self::Never),
+			json_syntax::Value::String(s) => match s.as_str() {
+				"@always" => Some(Self::Always),
+				"@once" => Some(Self::Once),
+				"@never" => Some(Self::Never),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+}
+
+/// Framing options.
+#[derive(Clone, Copy)]
+pub struct Options {
+	/// JSON-LD processing mode.
+	pub processing_mode: ProcessingMode,
+
+	/// Default value of the `@embed` flag.
+	///
+	/// Defaults to [`Embed::Once`].
+	pub embed: Embed,
+
+	/// Default value of the `@explicit` flag.
+	///
+	/// If set to `true`, only properties present in the frame are included in
+	/// the output.
+	pub explicit: bool,
+
+	/// Default value of the `@requireAll` flag.
+	///
+	/// If set to `true`, node objects must match every property of the frame
+	/// to be selected.
+	pub require_all: bool,
+
+	/// Default value of the `@omitDefault` flag.
+	///
+	/// If set to `true`, properties missing from a node object are not added
+	/// to the output, even if a default value is specified by the frame.
+	pub omit_default: bool,
+
+	/// If set to `true`, the top level `@graph` entry is omitted when the
+	/// output contains only one node object.
+	pub omit_graph: bool,
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Self {
+			processing_mode: ProcessingMode::default(),
+			embed: Embed::default(),
+			explicit: false,
+			require_all: false,
+			omit_default: false,
+			omit_graph: true,
+		}
+	}
+}
+
+impl From<Options> for json_ld_context_processing::Options {
+	fn from(options: Options) -> json_ld_context_processing::Options {
+		json_ld_context_processing::Options {
+			processing_mode: options.processing_mode,
+			..Default::default()
+		}
+	}
+}
@@ -22,6 +22,7 @@
 //!   - [Expansion](#expansion)
 //!   - [Compaction](#compaction)
 //!   - [Flattening](#flattening)
+//!   - [Framing](#framing)
 //! <!-- toc -->
 //!
 //! # Design
@@ -318,10 +319,57 @@
 //! println!("output: {}", nodes.pretty_print());
 //! # }
 //! ```
+//!
+//! ## Framing
+//!
+//! The JSON-LD Framing is a transformation that reshapes a document
+//! following the structure of a *frame* document. The frame is used to select
+//! the node objects of the input (duck typing) and to describe how they must
+//! be embedded into each other.
+//! There are two ways to frame a JSON-LD document with this library
+//! depending on your starting point:
+//!   - If you want to frame an arbitrary remote document, simply use the
+//!     [`JsonLdProcessor::frame`] (or [`JsonLdProcessor::frame_with`]) method.
+//!     This will return a JSON-LD document compacted with the frame context.
+//!   - Otherwise to frame an [`ExpandedDocument`] you can expand the frame
+//!     using [`framing::ExpandFrame`] then use the
+//!     [`framing::FrameExpanded::frame_with`] method.
+//!     This will return a [`framing::FramedDocument`].
+//!
+//! [`JsonLdProcessor::frame`]: crate::JsonLdProcessor::frame
+//! [`JsonLdProcessor::frame_with`]: crate::JsonLdProcessor::frame_with
+//!
+//! ### Example
+//!
+//! ```
+//! use static_iref::iri;
+//! use json_ld::{JsonLdProcessor, Options, RemoteDocumentReference, syntax::Print};
+//!
+//! # #[async_std::main]
+//! # async fn main() {
+//! let input = RemoteDocumentReference::iri(iri!("https://example.com/sample.jsonld").to_owned());
+//! let frame = RemoteDocumentReference::iri(iri!("https://example.com/frame.jsonld").to_owned());
+//!
+//! // Use `FsLoader` to redirect any URL starting with `https://example.com/` to
+//! // the local `example` directory. No HTTP query.
+//! let mut loader = json_ld::FsLoader::default();
+//! loader.mount(iri!("https://example.com/").to_owned(), "examples");
+//!
+//! let mut generator = rdf_types::generator::Blank::new();
+//!
+//! let framed = input
+//!   .frame(&mut generator, frame, &mut loader)
+//!   .await
+//!   .expect("framing failed");
+//!
+//! println!("output: {}", framed.pretty_print());
+//! # }
+//! ```
 pub use json_ld_compaction as compaction;
 pub use json_ld_context_processing as context_processing;
 pub use json_ld_core::*;
 pub use json_ld_expansion as expansion;
+pub use json_ld_framing as framing;
 pub use json_ld_serialization as ser;
 pub use json_ld_syntax as syntax;
 
@@ -1,14 +1,16 @@
 use crate::compaction::{self, Compact};
 use crate::context_processing::{self, Process};
 use crate::expansion;
+use crate::framing;
 use crate::syntax::ErrorCode;
 use crate::{flattening::ConflictingIndexes, Context, ExpandedDocument, Loader, ProcessingMode};
 use iref::IriBuf;
-use json_ld_core::rdf::RdfDirection;
+use json_ld_core::context::AnyProcessedContextCache;
+use json_ld_core::rdf::{self, FromRdfError, FromRdfOptions, RdfDirection};
 use json_ld_core::ContextLoadError;
 use json_ld_core::{
 	future::{BoxFuture, FutureExt},
-	Document, RdfQuads, RemoteContextReference,
+	Document, RdfQuads, RemoteContextReference, RemoteDocumentReference,
 };
 use rdf_types::{vocabulary, BlankIdBuf, Generator, IriVocabulary, Vocabulary, VocabularyMut};
 use std::hash::Hash;
@@ -77,8 +79,58 @@ pub struct Options<I = IriBuf> {
 	/// may be also be removed.
 	pub produce_generalized_rdf: bool,
 
+	/// If set to `true`, `xsd:boolean`, `xsd:integer` and `xsd:double`
+	/// literals are converted into native JSON values when transforming from
+	/// RDF.
+	///
+	/// Defaults to `false`.
+	pub use_native_types: bool,
+
+	/// If set to `true`, `rdf:type` triples are kept as regular properties
+	/// instead of being turned into `@type` entries when transforming from
+	/// RDF.
+	///
+	/// Defaults to `false`.
+	pub use_rdf_type: bool,
+
 	/// Term expansion policy, passed to the document expansion algorithm.
 	pub expansion_policy: expansion::Policy,
+
+	/// Default value of the `@embed` framing flag.
+	///
+	/// Defaults to [`framing::Embed::Once`].
+	pub embed: framing::Embed,
+
+	/// Default value of the `@explicit` framing flag.
+	///
+	/// Defaults to `false`.
+	pub explicit: bool,
+
+	/// Default value of the `@requireAll` framing flag.
+	///
+	/// Defaults to `false`.
+	pub require_all: bool,
+
+	/// Default value of the `@omitDefault` framing flag.
+	///
+	/// Defaults to `false`.
+	pub omit_default: bool,
+
+	/// If set to `true`, the top level `@graph` entry of a framed document is
+	/// omitted when the output contains only one node object.
+	///
+	/// If unset, defaults to `true` in the JSON-LD 1.1 processing mode, and
+	/// `false` otherwise.
+	pub omit_graph: Option<bool>,
+
+	/// Processed remote context cache, attached to every active context
+	/// created by the processor.
+	///
+	/// The cache is ignored if its IRI and blank node identifier types are
+	/// not those of the vocabulary used by the processor.
+	///
+	/// Defaults to `None`.
+	pub processed_context_cache: Option<AnyProcessedContextCache>,
 }
 
 impl<I> Options<I> {
@@ -101,6 +153,19 @@ impl<I> Options<I> {
 		}
 	}
 
+	/// Creates a new empty active context with the given base IRI, attached to
+	/// the processed context cache, if any.
+	pub fn new_context<B: 'static>(&self, base_iri: Option<I>) -> Context<I, B>
+	where
+		I: 'static,
+	{
+		Context::new(base_iri).with_processed_context_cache(
+			self.processed_context_cache
+				.as_ref()
+				.and_then(AnyProcessedContextCache::downcast),
+		)
+	}
+
 	/// Builds options for the context processing algorithm from these options.
 	pub fn context_processing_options(&self) -> context_processing::Options {
 		context_processing::Options {
@@ -118,6 +183,30 @@ impl<I> Options<I> {
 		}
 	}
 
+	/// Builds options for the framing algorithm from these options.
+	pub fn framing_options(&self) -> framing::Options {
+		framing::Options {
+			processing_mode: self.processing_mode,
+			embed: self.embed,
+			explicit: self.explicit,
+			require_all: self.require_all,
+			omit_default: self.omit_default,
+			omit_graph: self
+				.omit_graph
+				.unwrap_or(self.processing_mode == ProcessingMode::JsonLd1_1),
+		}
+	}
+
+	/// Builds options for the RDF to JSON-LD algorithm from these options.
+	pub fn from_rdf_options(&self) -> FromRdfOptions {
+		FromRdfOptions {
+			processing_mode: self.processing_mode,
+			use_native_types: self.use_native_types,
+			use_rdf_type: self.use_rdf_type,
+			rdf_direction: self.rdf_direction,
+		}
+	}
+
 	/// Builds options for the compaction algorithm from these options.
 	pub fn compaction_options(&self) -> compaction::Options {
 		compaction::Options {
@@ -140,7 +229,15 @@ impl<I> Default for Options<I> {
 			processing_mode: ProcessingMode::JsonLd1_1,
 			rdf_direction: None,
 			produce_generalized_rdf: false,
+			use_native_types: false,
+			use_rdf_type: false,
 			expansion_policy: expansion::Policy::default(),
+			embed: framing::Embed::default(),
+			explicit: false,
+			require_all: false,
+			omit_default: false,
+			omit_graph: None,
+			processed_context_cache: None,
 		}
 	}
 }
@@ -258,6 +355,46 @@ impl<I, B, E> FlattenError<I, B, E> {
 pub type FlattenResult<I, B, L> =
 	Result<json_syntax::Value, FlattenError<I, B, <L as Loader<I>>::Error>>;
 
+/// Error that can be raised by the [`JsonLdProcessor::frame`] function.
+#[derive(Debug, thiserror::Error)]
+pub enum FrameError<I, B, E> {
+	#[error("Expansion failed: {0}")]
+	Expand(ExpandError<E>),
+
+	#[error("Context processing failed: {0}")]
+	ContextProcessing(context_processing::Error<E>),
+
+	#[error("Framing failed: {0}")]
+	Framing(framing::Error<E>),
+
+	#[error("Compaction failed: {0}")]
+	Compaction(compaction::Error<E>),
+
+	#[error("Conflicting indexes: {0}")]
+	ConflictingIndexes(ConflictingIndexes<I, B>),
+
+	#[error("Remote document loading failed: {0}")]
+	Loading(E),
+}
+
+impl<I, B, E> FrameError<I, B, E> {
+	/// Returns the code of this error.
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::Expand(e) => e.code(),
+			Self::ContextProcessing(e) => e.code(),
+			Self::Framing(e) => e.code(),
+			Self::Compaction(e) => e.code(),
+			Self::ConflictingIndexes(_) => ErrorCode::ConflictingIndexes,
+			Self::Loading(_) => ErrorCode::LoadingDocumentFailed,
+		}
+	}
+}
+
+/// Result of the [`JsonLdProcessor::frame`] function.
+pub type FrameResult<I, B, L> =
+	Result<json_syntax::Value, FrameError<I, B, <L as Loader<I>>::Error>>;
+
 /// Error that can be raised by the [`JsonLdProcessor::to_rdf`] function.
 #[derive(Debug, thiserror::Error)]
 pub enum ToRdfError<E> {
@@ -279,6 +416,9 @@ impl<E> ToRdfError<E> {
 pub type ToRdfResult<'a, V, G, L> =
 	Result<ToRdf<'a, 'a, V, G>, ToRdfError<<L as Loader<<V as IriVocabulary>::Iri>>::Error>>;
 
+/// Result of the [`from_rdf`] function.
+pub type FromRdfResult<I, B> = Result<ExpandedDocument<I, B>, FromRdfError>;
+
 /// Result of the [`JsonLdProcessor::compare`] function.
 pub type CompareResult<I, L> = Result<bool, ExpandError<<L as Loader<I>>::Error>>;
 
@@ -376,8 +516,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<CompareResult<Iri, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -429,8 +569,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<CompareResult<Iri, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -483,8 +623,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<CompareResult<Iri, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -533,7 +673,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<CompareResult<Iri, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -584,7 +724,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<CompareResult<Iri, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -643,8 +783,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ExpandResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -697,8 +837,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ExpandResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -752,8 +892,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ExpandResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -802,7 +942,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ExpandResult<Iri, BlankIdBuf, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -843,7 +983,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	fn expand<'a, L>(&'a self, loader: &'a mut L) -> BoxFuture<ExpandResult<Iri, BlankIdBuf, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -866,8 +1006,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, IntoDocumentResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -884,8 +1024,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, IntoDocumentResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -904,8 +1044,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, IntoDocumentResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -923,7 +1063,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, IntoDocumentResult<Iri, BlankIdBuf, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -988,8 +1128,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, CompactResult<Iri, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -1048,8 +1188,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, CompactResult<Iri, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -1110,8 +1250,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, CompactResult<Iri, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -1167,7 +1307,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, CompactResult<Iri, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -1219,7 +1359,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, CompactResult<Iri, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -1299,8 +1439,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, FlattenResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -1370,8 +1510,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, FlattenResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -1443,8 +1583,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, FlattenResult<Iri, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		N: Send + Sync,
@@ -1510,7 +1650,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, FlattenResult<Iri, BlankIdBuf, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -1572,7 +1712,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<'a, FlattenResult<Iri, BlankIdBuf, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		L: Loader<Iri>,
 		//
 		Iri: Send + Sync,
@@ -1582,6 +1722,310 @@ pub trait JsonLdProcessor<Iri>: Sized {
 		self.flatten_with(vocabulary::no_vocabulary_mut(), generator, loader)
 	}
 
+	/// Frame the document with the given `vocabulary`, `generator`, `frame`
+	/// and `loader`, using the given `options` and warning handler.
+	///
+	/// The input document and the frame are both expanded. The node objects
+	/// of the input matching the frame are then embedded following the frame
+	/// structure, and the result is compacted using the frame context.
+	///
+	/// Framing requires a node map of the input document, which is why the
+	/// framing functions take an [`rdf_types::Generator`] as parameter to
+	/// assign an identifier to nested anonymous nodes.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use static_iref::iri;
+	/// use json_ld::{JsonLdProcessor, Options, RemoteDocumentReference, warning};
+	/// use rdf_types::IriVocabularyMut;
+	///
+	/// # #[async_std::main]
+	/// # async fn main() {
+	/// // Creates the vocabulary that will map each `rdf_types::vocabulary::Index`
+	/// // to an actual `IriBuf`.
+	/// let mut vocabulary: rdf_types::IndexVocabulary = rdf_types::IndexVocabulary::new();
+	///
+	/// let iri_index = vocabulary.insert(iri!("https://example.com/sample.jsonld"));
+	/// let input = RemoteDocumentReference::iri(iri_index);
+	///
+	/// let frame_index = vocabulary.insert(iri!("https://example.com/frame.jsonld"));
+	/// let frame = RemoteDocumentReference::iri(frame_index);
+	///
+	/// // Use `FsLoader` to redirect any URL starting with `https://example.com/` to
+	/// // the local `example` directory. No HTTP query.
+	/// let mut loader = json_ld::FsLoader::default();
+	/// loader.mount(vocabulary.insert(iri!("https://example.com/")), "examples");
+	///
+	/// let mut generator = rdf_types::generator::Blank::new();
+	///
+	/// let framed = input
+	///   .frame_full(
+	///     &mut vocabulary,
+	///     &mut generator,
+	///     frame,
+	///     &mut loader,
+	///     Options::default(),
+	///     warning::PrintWith
+	///   )
+	///   .await
+	///   .expect("framing failed");
+	/// # }
+	/// ```
+	fn frame_full<'a, N, L>(
+		&'a self,
+		vocabulary: &'a mut N,
+		generator: &'a mut (impl Send + Generator<N>),
+		frame: RemoteDocumentReference<Iri>,
+		loader: &'a mut L,
+		options: Options<Iri>,
+		warnings: impl 'a
+			+ Send
+			+ Sync
+			+ context_processing::WarningHandler<N>
+			+ expansion::WarningHandler<N>,
+	) -> BoxFuture<'a, FrameResult<Iri, N::BlankId, L>>
+	where
+		N: VocabularyMut<Iri = Iri>,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
+		L: Loader<Iri>,
+		//
+		N: Send + Sync,
+		Iri: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send;
+
+	/// Frame the document with the given `vocabulary`, `generator`, `frame`
+	/// and `loader`, using the given `options`.
+	///
+	/// Warnings are ignored.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use static_iref::iri;
+	/// use json_ld::{JsonLdProcessor, Options, RemoteDocumentReference};
+	/// use rdf_types::IriVocabularyMut;
+	///
+	/// # #[async_std::main]
+	/// # async fn main() {
+	/// let mut vocabulary: rdf_types::IndexVocabulary = rdf_types::IndexVocabulary::new();
+	///
+	/// let iri_index = vocabulary.insert(iri!("https://example.com/sample.jsonld"));
+	/// let input = RemoteDocumentReference::iri(iri_index);
+	///
+	/// let frame_index = vocabulary.insert(iri!("https://example.com/frame.jsonld"));
+	/// let frame = RemoteDocumentReference::iri(frame_index);
+	///
+	/// let mut loader = json_ld::FsLoader::default();
+	/// loader.mount(vocabulary.insert(iri!("https://example.com/")), "examples");
+	///
+	/// let mut generator = rdf_types::generator::Blank::new();
+	///
+	/// let framed = input
+	///   .frame_with_using(
+	///     &mut vocabulary,
+	///     &mut generator,
+	///     frame,
+	///     &mut loader,
+	///     Options::default()
+	///   )
+	///   .await
+	///   .expect("framing failed");
+	/// # }
+	/// ```
+	fn frame_with_using<'a, N, L>(
+		&'a self,
+		vocabulary: &'a mut N,
+		generator: &'a mut (impl Send + Generator<N>),
+		frame: RemoteDocumentReference<Iri>,
+		loader: &'a mut L,
+		options: Options<Iri>,
+	) -> BoxFuture<'a, FrameResult<Iri, N::BlankId, L>>
+	where
+		N: VocabularyMut<Iri = Iri>,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
+		L: Loader<Iri>,
+		//
+		N: Send + Sync,
+		Iri: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		self.frame_full(vocabulary, generator, frame, loader, options, ())
+	}
+
+	/// Frame the document with the given `vocabulary`, `generator`, `frame`
+	/// and `loader`.
+	///
+	/// Default options are used.
+	/// Warnings are ignored.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use static_iref::iri;
+	/// use json_ld::{JsonLdProcessor, RemoteDocumentReference};
+	/// use rdf_types::IriVocabularyMut;
+	///
+	/// # #[async_std::main]
+	/// # async fn main() {
+	/// let mut vocabulary: rdf_types::IndexVocabulary = rdf_types::IndexVocabulary::new();
+	///
+	/// let iri_index = vocabulary.insert(iri!("https://example.com/sample.jsonld"));
+	/// let input = RemoteDocumentReference::iri(iri_index);
+	///
+	/// let frame_index = vocabulary.insert(iri!("https://example.com/frame.jsonld"));
+	/// let frame = RemoteDocumentReference::iri(frame_index);
+	///
+	/// let mut loader = json_ld::FsLoader::default();
+	/// loader.mount(vocabulary.insert(iri!("https://example.com/")), "examples");
+	///
+	/// let mut generator = rdf_types::generator::Blank::new();
+	///
+	/// let framed = input
+	///   .frame_with(
+	///     &mut vocabulary,
+	///     &mut generator,
+	///     frame,
+	///     &mut loader
+	///   )
+	///   .await
+	///   .expect("framing failed");
+	/// # }
+	/// ```
+	fn frame_with<'a, N, L>(
+		&'a self,
+		vocabulary: &'a mut N,
+		generator: &'a mut (impl Send + Generator<N>),
+		frame: RemoteDocumentReference<Iri>,
+		loader: &'a mut L,
+	) -> BoxFuture<'a, FrameResult<Iri, N::BlankId, L>>
+	where
+		N: VocabularyMut<Iri = Iri>,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
+		L: Loader<Iri>,
+		//
+		N: Send + Sync,
+		Iri: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		self.frame_with_using(vocabulary, generator, frame, loader, Options::default())
+	}
+
+	/// Frame the document with the given `generator`, `frame`, `loader` and
+	/// using the given `options`.
+	///
+	/// Warnings are ignored.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use static_iref::iri;
+	/// use json_ld::{JsonLdProcessor, Options, RemoteDocumentReference};
+	///
+	/// # #[async_std::main]
+	/// # async fn main() {
+	/// let input = RemoteDocumentReference::iri(iri!("https://example.com/sample.jsonld").to_owned());
+	/// let frame = RemoteDocumentReference::iri(iri!("https://example.com/frame.jsonld").to_owned());
+	///
+	/// let mut loader = json_ld::FsLoader::default();
+	/// loader.mount(iri!("https://example.com/").to_owned(), "examples");
+	///
+	/// let mut generator = rdf_types::generator::Blank::new();
+	///
+	/// let framed = input
+	///   .frame_using(
+	///     &mut generator,
+	///     frame,
+	///     &mut loader,
+	///     Options::default()
+	///   )
+	///   .await
+	///   .expect("framing failed");
+	/// # }
+	/// ```
+	fn frame_using<'a, L>(
+		&'a self,
+		generator: &'a mut (impl Send + Generator),
+		frame: RemoteDocumentReference<Iri>,
+		loader: &'a mut L,
+		options: Options<Iri>,
+	) -> BoxFuture<'a, FrameResult<Iri, BlankIdBuf, L>>
+	where
+		(): VocabularyMut<Iri = Iri>,
+		Iri: 'static + Clone + Eq + Hash,
+		L: Loader<Iri>,
+		//
+		Iri: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		self.frame_with_using(
+			vocabulary::no_vocabulary_mut(),
+			generator,
+			frame,
+			loader,
+			options,
+		)
+	}
+
+	/// Frame the document with the given `generator`, `frame` and `loader`.
+	///
+	/// Default options are used.
+	/// Warnings are ignored.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use static_iref::iri;
+	/// use json_ld::{JsonLdProcessor, RemoteDocumentReference};
+	///
+	/// # #[async_std::main]
+	/// # async fn main() {
+	/// let input = RemoteDocumentReference::iri(iri!("https://example.com/sample.jsonld").to_owned());
+	/// let frame = RemoteDocumentReference::iri(iri!("https://example.com/frame.jsonld").to_owned());
+	///
+	/// let mut loader = json_ld::FsLoader::default();
+	/// loader.mount(iri!("https://example.com/").to_owned(), "examples");
+	///
+	/// let mut generator = rdf_types::generator::Blank::new();
+	///
+	/// let framed = input
+	///   .frame(
+	///     &mut generator,
+	///     frame,
+	///     &mut loader
+	///   )
+	///   .await
+	///   .expect("framing failed");
+	/// # }
+	/// ```
+	fn frame<'a, L>(
+		&'a self,
+		generator: &'a mut (impl Send + Generator),
+		frame: RemoteDocumentReference<Iri>,
+		loader: &'a mut L,
+	) -> BoxFuture<'a, FrameResult<Iri, BlankIdBuf, L>>
+	where
+		(): VocabularyMut<Iri = Iri>,
+		Iri: 'static + Clone + Eq + Hash,
+		L: Loader<Iri>,
+		//
+		Iri: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		self.frame_with(vocabulary::no_vocabulary_mut(), generator, frame, loader)
+	}
+
 	/// Serializes the document into an RDF dataset with a custom vocabulary
 	/// using the given `options` and warnings handler.
 	///
@@ -1656,8 +2100,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ToRdfResult<'a, N, G, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		G: Generator<N>,
 		L: Loader<Iri>,
 		//
@@ -1755,8 +2199,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ToRdfResult<'a, N, G, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		G: Generator<N>,
 		L: Loader<Iri>,
 		//
@@ -1838,8 +2282,8 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ToRdfResult<'a, N, G, L>>
 	where
 		N: VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		G: Generator<N>,
 		L: Loader<Iri>,
 		//
@@ -1921,7 +2365,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ToRdfResult<'a, (), G, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		G: Generator,
 		L: Loader<Iri>,
 		//
@@ -2006,7 +2450,7 @@ pub trait JsonLdProcessor<Iri>: Sized {
 	) -> BoxFuture<ToRdfResult<'a, (), G, L>>
 	where
 		(): VocabularyMut<Iri = Iri>,
-		Iri: 'a + Clone + Eq + Hash,
+		Iri: 'static + Clone + Eq + Hash,
 		G: Generator,
 		L: Loader<Iri>,
 		//
@@ -2059,6 +2503,94 @@ impl<'v, 'g, V: Vocabulary, G: rdf_types::Generator<V>> ToRdf<'v, 'g, V, G> {
 		)
 	}
 
+	/// Returns an iterator over the owned quads of the dataset.
+	fn owned_quads(&mut self) -> json_ld_core::rdf::ClonedQuads<'_, '_, '_, V, G> {
+		self.doc
+			.rdf_quads_full(
+				self.vocabulary,
+				self.generator,
+				self.rdf_direction,
+				self.produce_generalized_rdf,
+			)
+			.cloned()
+	}
+
+	/// Canonicalizes the RDF dataset using the [RDFC-1.0] algorithm.
+	///
+	/// Returns the canonically labeled quads, sorted in canonical order,
+	/// along with their canonical N-Quads serialization and its hash.
+	///
+	/// The work done on datasets with many indistinguishable blank nodes is
+	/// bounded by the default [`rdf::CanonicalizationOptions`]: at most
+	/// [`DEFAULT_MAX_N_DEGREE_CALLS`] calls to the Hash N-Degree Quads
+	/// algorithm, exploring the permutations of at most
+	/// [`DEFAULT_MAX_PERMUTATION_LEN`] blank nodes. Canonicalization fails
+	/// with [`rdf::CanonicalizationError::TooComplex`] beyond these bounds.
+	/// Use [`Self::canonicalize_using`] to change them.
+	///
+	/// [RDFC-1.0]: <https://www.w3.org/TR/rdf-canon/>
+	/// [`DEFAULT_MAX_N_DEGREE_CALLS`]: rdf::CanonicalizationOptions::DEFAULT_MAX_N_DEGREE_CALLS
+	/// [`DEFAULT_MAX_PERMUTATION_LEN`]: rdf::CanonicalizationOptions::DEFAULT_MAX_PERMUTATION_LEN
+	pub fn canonicalize(
+		&mut self,
+	) -> Result<rdf::CanonicalDataset<V::Iri, V::BlankId, V::Literal>, rdf::CanonicalizationError>
+	where
+		V: VocabularyMut<Type = rdf_types::literal::Type<V::Iri, V::LanguageTag>, Value = String>,
+		V::Iri: Clone,
+		V::BlankId: Clone + Eq + Hash,
+		V::Literal: Clone,
+	{
+		self.canonicalize_using(rdf::CanonicalizationOptions::default())
+	}
+
+	/// Canonicalizes the RDF dataset using the [RDFC-1.0] algorithm and the
+	/// given `options`.
+	///
+	/// [RDFC-1.0]: <https://www.w3.org/TR/rdf-canon/>
+	pub fn canonicalize_using(
+		&mut self,
+		options: rdf::CanonicalizationOptions,
+	) -> Result<rdf::CanonicalDataset<V::Iri, V::BlankId, V::Literal>, rdf::CanonicalizationError>
+	where
+		V: VocabularyMut<Type = rdf_types::literal::Type<V::Iri, V::LanguageTag>, Value = String>,
+		V::Iri: Clone,
+		V::BlankId: Clone + Eq + Hash,
+		V::Literal: Clone,
+	{
+		let quads: Vec<_> = self.owned_quads().collect();
+		rdf::canonicalize_with(self.vocabulary, quads, options)
+	}
+
+	/// Writes the RDF dataset in N-Quads form, one quad per line.
+	pub fn write_nquads(&mut self, mut out: impl std::io::Write) -> std::io::Result<()>
+	where
+		V: VocabularyMut<Type = rdf_types::literal::Type<V::Iri, V::LanguageTag>, Value = String>,
+		V::Iri: Clone,
+		V::BlankId: Clone,
+		V::Literal: Clone,
+	{
+		let mut quads = self.owned_quads();
+		while let Some(quad) = quads.next() {
+			rdf::nquads::write_quads(quads.vocabulary(), [quad], &mut out)?
+		}
+
+		Ok(())
+	}
+
+	/// Serializes the RDF dataset in N-Quads form, one quad per line.
+	pub fn to_nquads_string(&mut self) -> String
+	where
+		V: VocabularyMut<Type = rdf_types::literal::Type<V::Iri, V::LanguageTag>, Value = String>,
+		V::Iri: Clone,
+		V::BlankId: Clone,
+		V::Literal: Clone,
+	{
+		let mut buffer = Vec::new();
+		self.write_nquads(&mut buffer)
+			.expect("writing to a buffer cannot fail");
+		String::from_utf8(buffer).expect("N-Quads are valid UTF-8")
+	}
+
 	#[inline(always)]
 	pub fn cloned_quads<'a: 'v + 'g>(
 		&'a mut self,
@@ -2103,6 +2635,111 @@ impl<'v, 'g, V: Vocabulary, G: rdf_types::Generator<V>> ToRdf<'v, 'g, V, G> {
 	}
 }
 
+/// Serializes an RDF dataset as an expanded JSON-LD document with a custom
+/// vocabulary using the given `options`.
+///
+/// The `use_native_types`, `use_rdf_type`, `rdf_direction` and
+/// `processing_mode` options are taken into account.
+pub fn from_rdf_with<N>(
+	vocabulary: &N,
+	quads: impl IntoIterator<Item = rdf::Quad<N::Iri, N::BlankId, N::Literal>>,
+	options: Options<N::Iri>,
+) -> FromRdfResult<N::Iri, N::BlankId>
+where
+	N: Vocabulary<
+		Type = rdf_types::literal::Type<
+			<N as IriVocabulary>::Iri,
+			<N as rdf_types::LanguageTagVocabulary>::LanguageTag,
+		>,
+	>,
+	N::Iri: Clone + Eq + Hash,
+	N::BlankId: Clone + Eq + Hash,
+	N::Value: AsRef<str>,
+{
+	ExpandedDocument::from_rdf_quads_with(vocabulary, quads, options.from_rdf_options())
+}
+
+/// Serializes an RDF dataset as an expanded JSON-LD document using the given
+/// `options`.
+///
+/// # Example
+///
+/// ```
+/// use json_ld::{rdf::Value, ValidId};
+/// use rdf_types::Quad;
+/// use static_iref::iri;
+///
+/// let quads = [Quad(
+///   ValidId::Iri(iri!("https://example.com/a").to_owned()),
+///   ValidId::Iri(iri!("https://example.com/knows").to_owned()),
+///   Value::Id(ValidId::Iri(iri!("https://example.com/b").to_owned())),
+///   None,
+/// )];
+///
+/// let expanded = json_ld::from_rdf(quads, json_ld::Options::default())
+///   .expect("invalid dataset");
+/// ```
+pub fn from_rdf(
+	quads: impl IntoIterator<Item = rdf::Quad<IriBuf, BlankIdBuf, rdf_types::Literal>>,
+	options: Options,
+) -> FromRdfResult<IriBuf, BlankIdBuf> {
+	from_rdf_with(&(), quads, options)
+}
+
+/// Error that can be raised by the [`from_nquads`] function.
+#[derive(Debug, thiserror::Error)]
+pub enum FromNQuadsError {
+	/// N-Quads parsing failed.
+	#[error("N-Quads parsing failed: {0}")]
+	Parse(rdf::nquads::ParseError),
+
+	/// RDF to JSON-LD serialization failed.
+	#[error("RDF serialization failed: {0}")]
+	FromRdf(FromRdfError),
+}
+
+/// Result of the [`from_nquads`] function.
+pub type FromNQuadsResult<I, B> = Result<ExpandedDocument<I, B>, FromNQuadsError>;
+
+/// Parses an N-Quads document and serializes it as an expanded JSON-LD
+/// document with a custom vocabulary using the given `options`.
+pub fn from_nquads_with<N>(
+	vocabulary: &mut N,
+	content: &str,
+	options: Options<N::Iri>,
+) -> FromNQuadsResult<N::Iri, N::BlankId>
+where
+	N: VocabularyMut<
+		Type = rdf_types::literal::Type<
+			<N as IriVocabulary>::Iri,
+			<N as rdf_types::LanguageTagVocabulary>::LanguageTag,
+		>,
+		Value = String,
+	>,
+	N::Iri: Clone + Eq + Hash,
+	N::BlankId: Clone + Eq + Hash,
+{
+	let quads = rdf::nquads::parse_str_with(vocabulary, content).map_err(FromNQuadsError::Parse)?;
+	from_rdf_with(vocabulary, quads, options).map_err(FromNQuadsError::FromRdf)
+}
+
+/// Parses an N-Quads document and serializes it as an expanded JSON-LD
+/// document using the given `options`.
+///
+/// # Example
+///
+/// ```
+/// let expanded = json_ld::from_nquads(
+///   "<https://example.com/a> <https://example.com/name> \"Alice\"@en .",
+///   json_ld::Options::default(),
+/// )
+/// .expect("invalid N-Quads");
+/// ```
+pub fn from_nquads(content: &str, options: Options) -> FromNQuadsResult<IriBuf, BlankIdBuf> {
+	let quads = rdf::nquads::parse_str(content).map_err(FromNQuadsError::Parse)?;
+	from_rdf(quads, options).map_err(FromNQuadsError::FromRdf)
+}
+
 async fn compact_expanded_full<'a, T, N, L>(
 	expanded_input: &'a T,
 	url: Option<&'a N::Iri>,
@@ -2114,8 +2751,8 @@ async fn compact_expanded_full<'a, T, N, L>(
 ) -> Result<json_syntax::Value, CompactError<L::Error>>
 where
 	N: Send + Sync + VocabularyMut,
-	N::Iri: Clone + Eq + Hash,
-	N::BlankId: 'a + Clone + Eq + Hash,
+	N::Iri: 'static + Clone + Eq + Hash,
+	N::BlankId: 'static + Clone + Eq + Hash,
 	T: Compact<N::Iri, N::BlankId>,
 	L: Loader<N::Iri>,
 	L::Error: Send,
@@ -2137,7 +2774,7 @@ where
 	let mut active_context = context
 		.process_full(
 			vocabulary,
-			&Context::new(None),
+			&options.new_context(None),
 			loader,
 			context_base.cloned(),
 			options.context_processing_options(),
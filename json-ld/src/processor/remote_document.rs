@@ -1,16 +1,18 @@
 use super::{
 	compact_expanded_full, CompactError, CompactResult, CompareResult, ExpandError, ExpandResult,
-	FlattenError, FlattenResult, JsonLdProcessor, Options,
+	FlattenError, FlattenResult, FrameError, FrameResult, JsonLdProcessor, Options,
 };
 use crate::context_processing::{self, Process};
 use crate::expansion::{self, Expand};
+use crate::framing::{self, ExpandFrame, FrameExpanded};
 use crate::IntoDocumentResult;
-use crate::{Context, Flatten, Loader, RemoteDocument, RemoteDocumentReference};
+use crate::{Flatten, Loader, RemoteDocument, RemoteDocumentReference};
 use contextual::WithContext;
 use json_ld_core::{
 	future::{BoxFuture, FutureExt},
 	Document, RemoteContextReference,
 };
+use json_ld_syntax::TryFromJson;
 use rdf_types::{Generator, VocabularyMut};
 use std::hash::Hash;
 
@@ -29,8 +31,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 	) -> BoxFuture<CompareResult<I, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -73,8 +75,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 	) -> BoxFuture<ExpandResult<I, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -85,7 +87,7 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 	{
 		async move {
 			let mut active_context =
-				Context::new(options.base.clone().or_else(|| self.url().cloned()));
+				options.new_context(options.base.clone().or_else(|| self.base_url().cloned()));
 
 			if let Some(expand_context) = options.expand_context.take() {
 				active_context = expand_context
@@ -129,7 +131,7 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 				.expand_full(
 					vocabulary,
 					active_context,
-					self.url().or(options.base.as_ref()),
+					self.base_url().or(options.base.as_ref()),
 					loader,
 					options.expansion_options(),
 					warnings,
@@ -153,8 +155,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 	) -> BoxFuture<'a, IntoDocumentResult<I, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: 'a + Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -185,8 +187,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 	) -> BoxFuture<'a, CompactResult<I, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -208,7 +210,7 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 
 			compact_expanded_full(
 				&expanded_input,
-				self.url(),
+				self.base_url(),
 				vocabulary,
 				context,
 				loader,
@@ -235,8 +237,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 	) -> BoxFuture<'a, FlattenResult<I, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -263,7 +265,7 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 			match context {
 				Some(context) => compact_expanded_full(
 					&flattened_output,
-					self.url(),
+					self.base_url(),
 					vocabulary,
 					context,
 					loader,
@@ -279,6 +281,107 @@ impl<I> JsonLdProcessor<I> for RemoteDocument<I> {
 		}
 		.boxed()
 	}
+
+	fn frame_full<'a, N, L>(
+		&'a self,
+		vocabulary: &'a mut N,
+		generator: &'a mut (impl Send + Generator<N>),
+		frame: RemoteDocumentReference<I>,
+		loader: &'a mut L,
+		options: Options<I>,
+		mut warnings: impl 'a
+			+ Send
+			+ Sync
+			+ context_processing::WarningHandler<N>
+			+ expansion::WarningHandler<N>,
+	) -> BoxFuture<'a, FrameResult<I, N::BlankId, L>>
+	where
+		N: VocabularyMut<Iri = I>,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
+		L: Loader<I>,
+		//
+		N: Send + Sync,
+		I: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		async move {
+			let expanded_input = JsonLdProcessor::expand_full(
+				self,
+				vocabulary,
+				loader,
+				options.clone().unordered(),
+				&mut warnings,
+			)
+			.await
+			.map_err(FrameError::Expand)?;
+
+			let frame = frame
+				.load_with(vocabulary, loader)
+				.await
+				.map_err(FrameError::Loading)?;
+
+			let frame_base = frame.base_url().or(options.base.as_ref());
+			let framing_options = options.framing_options();
+
+			let expanded_frame = frame
+				.document()
+				.expand_frame_full(
+					vocabulary,
+					&options.new_context(frame_base.cloned()),
+					frame_base,
+					loader,
+					framing_options,
+				)
+				.await
+				.map_err(FrameError::Framing)?;
+
+			let mut active_context = frame_context(frame.document())
+				.map_err(FrameError::Framing)?
+				.process_full(
+					vocabulary,
+					&options.new_context(None),
+					loader,
+					frame_base.cloned(),
+					options.context_processing_options(),
+					warnings,
+				)
+				.await
+				.map_err(FrameError::ContextProcessing)?;
+
+			match options.base.as_ref() {
+				Some(base) => active_context.set_base_iri(Some(base.clone())),
+				None => {
+					if options.compact_to_relative && active_context.base_iri().is_none() {
+						active_context.set_base_iri(self.base_url().cloned());
+					}
+				}
+			}
+
+			let framed_output = FrameExpanded::frame_with(
+				&expanded_input,
+				vocabulary,
+				generator,
+				&expanded_frame,
+				framing_options,
+			)
+			.map_err(FrameError::ConflictingIndexes)?;
+
+			framed_output
+				.compact_full(
+					vocabulary,
+					active_context.as_ref(),
+					loader,
+					options.compaction_options(),
+					framing_options.omit_graph,
+				)
+				.await
+				.map_err(FrameError::Compaction)
+		}
+		.boxed()
+	}
 }
 
 impl<I> JsonLdProcessor<I> for RemoteDocumentReference<I, json_syntax::Value> {
@@ -296,8 +399,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocumentReference<I, json_syntax::Value> {
 	) -> BoxFuture<CompareResult<I, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -341,8 +444,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocumentReference<I, json_syntax::Value> {
 	) -> BoxFuture<ExpandResult<I, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -374,8 +477,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocumentReference<I, json_syntax::Value> {
 	) -> BoxFuture<'a, IntoDocumentResult<I, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: 'a + Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -408,8 +511,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocumentReference<I, json_syntax::Value> {
 	) -> BoxFuture<'a, CompactResult<I, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -451,8 +554,8 @@ impl<I> JsonLdProcessor<I> for RemoteDocumentReference<I, json_syntax::Value> {
 	) -> BoxFuture<'a, FlattenResult<I, N::BlankId, L>>
 	where
 		N: VocabularyMut<Iri = I>,
-		I: Clone + Eq + Hash,
-		N::BlankId: 'a + Clone + Eq + Hash,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
 		L: Loader<I>,
 		//
 		N: Send + Sync,
@@ -479,4 +582,74 @@ impl<I> JsonLdProcessor<I> for RemoteDocumentReference<I, json_syntax::Value> {
 		}
 		.boxed()
 	}
+
+	fn frame_full<'a, N, L>(
+		&'a self,
+		vocabulary: &'a mut N,
+		generator: &'a mut (impl Send + Generator<N>),
+		frame: RemoteDocumentReference<I>,
+		loader: &'a mut L,
+		options: Options<I>,
+		warnings: impl 'a
+			+ Send
+			+ Sync
+			+ context_processing::WarningHandler<N>
+			+ expansion::WarningHandler<N>,
+	) -> BoxFuture<'a, FrameResult<I, N::BlankId, L>>
+	where
+		N: VocabularyMut<Iri = I>,
+		I: 'static + Clone + Eq + Hash,
+		N::BlankId: 'static + Clone + Eq + Hash,
+		L: Loader<I>,
+		//
+		N: Send + Sync,
+		I: Send + Sync,
+		N::BlankId: Send + Sync,
+		L: Send + Sync,
+		L::Error: Send,
+	{
+		async move {
+			let doc = self
+				.loaded_with(vocabulary, loader)
+				.await
+				.map_err(FrameError::Loading)?;
+			JsonLdProcessor::frame_full(
+				doc.as_ref(),
+				vocabulary,
+				generator,
+				frame,
+				loader,
+				options,
+				warnings,
+			)
+			.await
+		}
+		.boxed()
+	}
+}
+
+/// Returns the `@context` entry of the given frame document.
+///
+/// If the frame has no context, the empty context is returned.
+fn frame_context<E>(
+	frame: &json_syntax::Value,
+) -> Result<json_ld_syntax::context::Context, framing::Error<E>> {
+	let object = match frame {
+		json_syntax::Value::Object(object) => Some(object),
+		json_syntax::Value::Array(items) => items.first().and_then(json_syntax::Value::as_object),
+		_ => None,
+	};
+
+	match object {
+		Some(object) => match object
+			.get_unique("@context")
+			.map_err(framing::Error::duplicate_key_ref)?
+		{
+			Some(context) => Ok(json_ld_syntax::context::Context::try_from_json(
+				context.clone(),
+			)?),
+			None => Ok(json_ld_syntax::context::Context::default()),
+		},
+		None => Ok(json_ld_syntax::context::Context::default()),
+	}
 }
@@ -0,0 +1,94 @@
+use json_ld::{
+	rdf::{CanonicalizationError, CanonicalizationOptions},
+	syntax::Parse,
+	JsonLdProcessor, RemoteDocument,
+};
+use rdf_types::IndexVocabulary;
+
+async fn canonical_nquads(json: &str) -> (String, String) {
+	let (json, _) = json_ld::syntax::Value::parse_str(json).unwrap();
+	let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+	let mut generator = rdf_types::generator::Blank::new();
+
+	let input = RemoteDocument::new(None, None, json);
+	let mut rdf = input
+		.to_rdf_with(&mut vocabulary, &mut generator, &mut json_ld::NoLoader)
+		.await
+		.unwrap();
+
+	let dataset = rdf.canonicalize().unwrap();
+	(dataset.hash_hex(), dataset.into_nquads())
+}
+
+#[async_std::test]
+async fn canonicalize_blank_nodes() {
+	let (hash_a, nquads_a) = canonical_nquads(
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@id": "_:x",
+			"knows": {"@id": "_:y", "name": "Bob"},
+			"name": "Alice"
+		}
+		"#,
+	)
+	.await;
+
+	let (hash_b, nquads_b) = canonical_nquads(
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@id": "_:foo",
+			"name": "Alice",
+			"knows": {"@id": "_:bar", "name": "Bob"}
+		}
+		"#,
+	)
+	.await;
+
+	assert_eq!(
+		nquads_a,
+		"_:c14n0 <http://example.org/knows> _:c14n1 .\n\
+		_:c14n0 <http://example.org/name> \"Alice\" .\n\
+		_:c14n1 <http://example.org/name> \"Bob\" .\n"
+	);
+	assert_eq!(nquads_a, nquads_b);
+	assert_eq!(hash_a, hash_b)
+}
+
+#[async_std::test]
+async fn canonicalize_options() {
+	let (json, _) = json_ld::syntax::Value::parse_str(
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@graph": [
+				{"@id": "_:a", "knows": {"@id": "_:b"}},
+				{"@id": "_:b", "knows": {"@id": "_:c"}},
+				{"@id": "_:c", "knows": {"@id": "_:a"}}
+			]
+		}
+		"#,
+	)
+	.unwrap();
+
+	let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+	let mut generator = rdf_types::generator::Blank::new();
+
+	let input = RemoteDocument::new(None, None, json);
+	let mut rdf = input
+		.to_rdf_with(&mut vocabulary, &mut generator, &mut json_ld::NoLoader)
+		.await
+		.unwrap();
+
+	let bounded = rdf.canonicalize_using(CanonicalizationOptions {
+		max_n_degree_calls: Some(1),
+		..CanonicalizationOptions::default()
+	});
+	assert!(matches!(bounded, Err(CanonicalizationError::TooComplex)));
+
+	let unbounded = rdf
+		.canonicalize_using(CanonicalizationOptions::unbounded())
+		.unwrap();
+	assert_eq!(unbounded.quads().len(), 3)
+}
@@ -0,0 +1,81 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+use iref::IriBuf;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+/// Context defining the `name` term.
+pub const CONTEXT: &str = r#"{"@context": {"name": "http://schema.org/name"}}"#;
+
+pub fn iri(s: &str) -> IriBuf {
+	IriBuf::new(s.to_owned()).unwrap()
+}
+
+pub fn json(s: &str) -> json_ld::syntax::Value {
+	use json_ld::syntax::Parse;
+	json_ld::syntax::Value::parse_str(s).unwrap().0
+}
+
+/// `200 OK` response with the given JSON-LD body.
+pub fn ok(body: &str) -> String {
+	format!(
+		"HTTP/1.1 200 OK\r\n\
+		Content-Type: application/ld+json\r\n\
+		Content-Length: {}\r\n\
+		Connection: close\r\n\r\n{body}",
+		body.len()
+	)
+}
+
+/// Reads the head of an HTTP request, lowercased.
+///
+/// Returns `None` if the connection fails or is closed first.
+pub fn read_request_head(stream: &mut impl Read) -> Option<String> {
+	let mut head = Vec::new();
+	let mut byte = [0];
+	while !head.ends_with(b"\r\n\r\n") {
+		match stream.read(&mut byte) {
+			Ok(1) => head.push(byte[0]),
+			_ => return None,
+		}
+	}
+
+	Some(String::from_utf8_lossy(&head).to_ascii_lowercase())
+}
+
+/// Stand-in HTTP server answering `connections` connections.
+///
+/// The `respond` function is given the server base URL and the head of each
+/// request (see [`read_request_head`]), and returns the response to send. A
+/// `None` response makes the server hang for a while without answering.
+///
+/// Returns the base URL along with the server thread, returning the heads of
+/// the received requests.
+pub fn serve(
+	connections: usize,
+	mut respond: impl FnMut(&str, &str) -> Option<String> + Send + 'static,
+) -> (String, std::thread::JoinHandle<Vec<String>>) {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let base = format!("http://{}", listener.local_addr().unwrap());
+
+	let server_base = base.clone();
+	let handle = std::thread::spawn(move || {
+		let mut requests = Vec::new();
+		for _ in 0..connections {
+			let (mut stream, _) = listener.accept().unwrap();
+			let request = read_request_head(&mut stream).unwrap();
+
+			match respond(&server_base, &request) {
+				Some(response) => stream.write_all(response.as_bytes()).unwrap(),
+				None => std::thread::sleep(Duration::from_millis(500)),
+			}
+
+			requests.push(request)
+		}
+
+		requests
+	});
+
+	(base, handle)
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use common::{iri, json};
+use json_ld::{
+	context_processing::{
+		compatibility::{compare, Field},
+		Options,
+	},
+	syntax::{context, Parse, TryFromJson},
+	MapLoader, NoLoader,
+};
+
+fn context(s: &str) -> context::Context {
+	let (json, _) = json_ld::syntax::Value::parse_str(s).unwrap();
+	context::Context::try_from_json(json).unwrap()
+}
+
+#[async_std::test]
+async fn protected_term_changes() {
+	let old = context(
+		r#"{
+			"@protected": true,
+			"name": "http://schema.org/name",
+			"tags": {"@id": "http://example.org/tags", "@container": "@set"},
+			"email": "http://schema.org/email"
+		}"#,
+	);
+
+	let new = context(
+		r#"{
+			"@protected": true,
+			"name": "http://xmlns.com/foaf/0.1/name",
+			"tags": {"@id": "http://example.org/tags", "@container": "@list"},
+			"knows": "http://schema.org/knows"
+		}"#,
+	);
+
+	let diff = compare(&mut (), &mut NoLoader, &old, &new, None, Options::default())
+		.await
+		.unwrap();
+
+	let added: Vec<_> = diff.added.iter().map(|(term, _)| term.as_str()).collect();
+	assert_eq!(added, ["knows"]);
+
+	let removed: Vec<_> = diff.removed.iter().map(|(term, _)| term.as_str()).collect();
+	assert_eq!(removed, ["email"]);
+
+	let changed: Vec<_> = diff
+		.changed
+		.iter()
+		.map(|change| (change.term.as_str(), change.fields.clone()))
+		.collect();
+	assert_eq!(
+		changed,
+		[("name", vec![Field::Iri]), ("tags", vec![Field::Container])]
+	);
+
+	assert_eq!(diff.protected_redefinitions().count(), 2);
+	assert!(diff.is_breaking());
+
+	let same = compare(&mut (), &mut NoLoader, &old, &old, None, Options::default())
+		.await
+		.unwrap();
+	assert!(same.is_empty());
+	assert!(!same.is_breaking());
+}
+
+#[async_std::test]
+async fn scoped_context_base_url() {
+	let mut loader = MapLoader::new();
+	for version in ["v1", "v2"] {
+		let nest = if version == "v1" {
+			r#", "@nest": "@nest""#
+		} else {
+			""
+		};
+		loader.insert(
+			iri(&format!("https://example.org/{version}/context.jsonld")),
+			json(&format!(
+				r#"{{"@context": {{
+					"tag": {{"@id": "http://example.org/tag", "@context": "scoped.jsonld"}},
+					"meta": {{"@id": "http://example.org/meta"{nest}}}
+				}}}}"#
+			)),
+		);
+		loader.insert(
+			iri(&format!("https://example.org/{version}/scoped.jsonld")),
+			json(r#"{"@context": {}}"#),
+		);
+	}
+
+	let old = context(r#""https://example.org/v1/context.jsonld""#);
+	let new = context(r#""https://example.org/v2/context.jsonld""#);
+
+	let diff = compare(&mut (), &mut loader, &old, &new, None, Options::default())
+		.await
+		.unwrap();
+
+	let changed: Vec<_> = diff
+		.changed
+		.iter()
+		.map(|change| {
+			(
+				change.term.as_str(),
+				change.fields.clone(),
+				change.changes_expansion(),
+			)
+		})
+		.collect();
+	assert_eq!(
+		changed,
+		[
+			("meta", vec![Field::Nest], false),
+			("tag", vec![Field::BaseUrl], true)
+		]
+	);
+	assert!(diff.is_breaking());
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use iref::IriBuf;
+use json_ld::{
+	context::Resolution,
+	syntax::{context, Parse, TryFromJson},
+	Id, NoLoader, Process, Term, Type,
+};
+
+fn term(s: &str) -> Term<IriBuf, rdf_types::BlankIdBuf> {
+	Term::Id(Id::iri(common::iri(s)))
+}
+
+#[async_std::test]
+async fn resolve() {
+	let (json, _) = json_ld::syntax::Value::parse_str(
+		r#"{
+			"@vocab": "http://example.org/vocab#",
+			"schema": "http://schema.org/",
+			"name": {"@id": "http://schema.org/name", "@protected": true},
+			"fullName": "http://schema.org/name",
+			"knows": {"@id": "schema:knows", "@type": "@id"}
+		}"#,
+	)
+	.unwrap();
+
+	let context = context::Context::try_from_json(json)
+		.unwrap()
+		.process(&mut (), &mut NoLoader, None)
+		.await
+		.unwrap()
+		.into_processed();
+
+	let name = context.resolve(&mut (), "name");
+	assert_eq!(name.resolution, Resolution::Definition);
+	assert!(name.value == term("http://schema.org/name"));
+	assert!(name.is_protected());
+
+	let knows = context.resolve(&mut (), "knows");
+	assert!(knows.value == term("http://schema.org/knows"));
+	assert!(knows.definition.unwrap().typ == Some(Type::Id));
+	assert!(!knows.is_protected());
+
+	let birth_date = context.resolve(&mut (), "schema:birthDate");
+	assert_eq!(birth_date.resolution, Resolution::CompactIri);
+	assert!(birth_date.value == term("http://schema.org/birthDate"));
+	assert!(birth_date.prefix.is_some());
+
+	let foo = context.resolve(&mut (), "foo");
+	assert_eq!(foo.resolution, Resolution::Vocabulary);
+	assert!(foo.value == term("http://example.org/vocab#foo"));
+
+	let terms: Vec<_> = context
+		.terms_for(&term("http://schema.org/name"))
+		.into_iter()
+		.map(|key| key.as_str())
+		.collect();
+	assert_eq!(terms, ["name", "fullName"]);
+	assert!(context.terms_for(&term("http://schema.org/age")).is_empty());
+}
+
+#[async_std::test]
+async fn resolve_full() {
+	let (json, _) = json_ld::syntax::Value::parse_str(
+		r#"{
+			"@base": "http://example.org/base/",
+			"name": "http://schema.org/name"
+		}"#,
+	)
+	.unwrap();
+
+	let context = context::Context::try_from_json(json)
+		.unwrap()
+		.process(&mut (), &mut NoLoader, None)
+		.await
+		.unwrap()
+		.into_processed();
+
+	let blank = context.resolve(&mut (), "_:");
+	assert_eq!(blank.resolution, Resolution::BlankId);
+	assert!(blank.value == Term::Id(Id::Invalid("_:".to_string())));
+
+	let relative = context.resolve_full(&mut (), "doc", true, false);
+	assert_eq!(relative.resolution, Resolution::BaseIri);
+	assert!(relative.value == term("http://example.org/base/doc"));
+
+	// Term definitions are only used for vocabulary-relative values.
+	let name = context.resolve_full(&mut (), "name", false, false);
+	assert_eq!(name.resolution, Resolution::Unresolved);
+	assert!(name.value == Term::Id(Id::Invalid("name".to_string())));
+}
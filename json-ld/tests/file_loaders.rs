@@ -0,0 +1,250 @@
+mod common;
+
+#[cfg(feature = "archive")]
+mod archive {
+	use super::common::{iri, CONTEXT};
+	use iref::IriBuf;
+	use json_ld::{archive, ArchiveLoader, Loader};
+	use std::io::Write;
+	use std::path::PathBuf;
+
+	/// Creates a tar and a zip archive, both containing a `contexts/v1.jsonld`
+	/// entry.
+	fn setup() -> (PathBuf, PathBuf, PathBuf) {
+		let dir =
+			std::env::temp_dir().join(format!("json-ld-archive-loader-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+
+		let tar_path = dir.join("contexts.tar");
+		let mut builder = tar::Builder::new(std::fs::File::create(&tar_path).unwrap());
+		let mut header = tar::Header::new_gnu();
+		header.set_size(CONTEXT.len() as u64);
+		header.set_mode(0o644);
+		header.set_cksum();
+		builder
+			.append_data(&mut header, "./contexts/v1.jsonld", CONTEXT.as_bytes())
+			.unwrap();
+		builder.finish().unwrap();
+
+		let zip_path = dir.join("contexts.zip");
+		let mut writer = zip::ZipWriter::new(std::fs::File::create(&zip_path).unwrap());
+		writer
+			.start_file("contexts/v1.jsonld", zip::write::FileOptions::default())
+			.unwrap();
+		writer.write_all(CONTEXT.as_bytes()).unwrap();
+		writer.finish().unwrap();
+
+		(dir, tar_path, zip_path)
+	}
+
+	#[async_std::test]
+	async fn load_from_archives() {
+		let (dir, tar_path, zip_path) = setup();
+
+		let mut loader: ArchiveLoader<IriBuf> = ArchiveLoader::new();
+		loader
+			.mount(iri("https://example.org/"), &tar_path)
+			.unwrap();
+		loader
+			.mount(iri("https://example.com/"), &zip_path)
+			.unwrap();
+
+		let doc = loader
+			.load(iri("https://example.org/contexts/v1.jsonld"))
+			.await
+			.unwrap();
+		assert!(doc.document().is_object());
+
+		let doc = loader
+			.load(iri("https://example.com/contexts/v1.jsonld"))
+			.await
+			.unwrap();
+		assert!(doc.document().is_object());
+
+		assert!(matches!(
+			loader
+				.load(iri("https://example.com/contexts/v2.jsonld"))
+				.await,
+			Err(archive::Error::NotFound(_))
+		));
+
+		assert!(matches!(
+			loader
+				.load(iri("https://example.net/contexts/v1.jsonld"))
+				.await,
+			Err(archive::Error::NoMountPoint)
+		));
+
+		// Cached documents do not require the archive anymore.
+		std::fs::remove_dir_all(dir).unwrap();
+		assert!(loader
+			.load(iri("https://example.org/contexts/v1.jsonld"))
+			.await
+			.is_ok());
+	}
+}
+
+mod fs {
+	use super::common::{iri, CONTEXT};
+	use iref::IriBuf;
+	use json_ld::{FsLoader, Loader};
+	use std::path::PathBuf;
+
+	/// Creates a temporary directory with the following layout:
+	///
+	/// ```text
+	/// root/ctx.jsonld
+	/// root/dir/index.json
+	/// root/nested/ctx.jsonld
+	/// nested/ctx.json
+	/// ```
+	fn setup() -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("json-ld-fs-loader-{}", std::process::id()));
+		std::fs::create_dir_all(dir.join("root/dir")).unwrap();
+		std::fs::create_dir_all(dir.join("root/nested")).unwrap();
+		std::fs::create_dir_all(dir.join("nested")).unwrap();
+		std::fs::write(dir.join("root/ctx.jsonld"), CONTEXT).unwrap();
+		std::fs::write(dir.join("root/dir/index.json"), CONTEXT).unwrap();
+		std::fs::write(dir.join("root/nested/ctx.jsonld"), CONTEXT).unwrap();
+		std::fs::write(dir.join("nested/ctx.json"), CONTEXT).unwrap();
+		dir
+	}
+
+	#[async_std::test]
+	async fn resolution() {
+		let dir = setup();
+
+		let mut loader: FsLoader<IriBuf> = FsLoader::new();
+		loader.mount(iri("https://example.org/"), dir.join("root"));
+		loader.mount(iri("https://example.org/nested/"), dir.join("nested"));
+
+		let doc = loader.load(iri("https://example.org/ctx")).await.unwrap();
+		assert_eq!(doc.content_type().unwrap().as_ref(), "application/ld+json");
+
+		let doc = loader.load(iri("https://example.org/dir/")).await.unwrap();
+		assert_eq!(doc.content_type().unwrap().as_ref(), "application/json");
+
+		// The longest prefix wins.
+		let doc = loader
+			.load(iri("https://example.org/nested/ctx"))
+			.await
+			.unwrap();
+		assert_eq!(doc.content_type().unwrap().as_ref(), "application/json");
+
+		loader.set_extensions(["json"]);
+		assert!(loader.load(iri("https://example.org/ctx")).await.is_err());
+
+		std::fs::remove_dir_all(dir).unwrap()
+	}
+}
+
+mod file_data {
+	use super::common::{iri, CONTEXT};
+	use iref::IriBuf;
+	use json_ld::{
+		data, file, syntax::Parse, ChainLoader, DataLoader, FileLoader, JsonLdProcessor, Loader,
+		RemoteDocument,
+	};
+	use std::path::{Path, PathBuf};
+
+	fn file_iri(path: &Path) -> IriBuf {
+		iri(&format!("file://{}", path.display()))
+	}
+
+	/// Creates a temporary directory with a `root/context.jsonld` file and a
+	/// `secret.jsonld` file outside of `root`.
+	fn setup(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("json-ld-{name}-{}", std::process::id()));
+		std::fs::create_dir_all(dir.join("root")).unwrap();
+		std::fs::write(dir.join("root/context.jsonld"), CONTEXT).unwrap();
+		std::fs::write(dir.join("secret.jsonld"), CONTEXT).unwrap();
+		dir
+	}
+
+	#[async_std::test]
+	async fn file_loader() {
+		let dir = setup("file-loader");
+		let mut loader = FileLoader::with_root(dir.join("root"));
+
+		let doc = loader
+			.load(file_iri(&dir.join("root/context.jsonld")))
+			.await
+			.unwrap();
+		assert!(doc.document().is_object());
+
+		assert!(matches!(
+			loader.load(file_iri(&dir.join("secret.jsonld"))).await,
+			Err(file::Error::OutsideRoot(_))
+		));
+
+		assert!(matches!(
+			loader
+				.load(file_iri(&dir.join("root/../secret.jsonld")))
+				.await,
+			Err(file::Error::OutsideRoot(_))
+		));
+
+		assert!(matches!(
+			loader.load(iri("file://example.org/context.jsonld")).await,
+			Err(file::Error::RemoteHost(_))
+		));
+
+		std::fs::remove_dir_all(dir).unwrap()
+	}
+
+	#[async_std::test]
+	async fn data_loader() {
+		let mut loader = DataLoader::new();
+
+		let doc = loader
+			.load(iri("data:application/ld+json;base64,eyJAY29udGV4dCI6e319"))
+			.await
+			.unwrap();
+		assert_eq!(doc.content_type().unwrap().as_ref(), "application/ld+json");
+
+		let doc = loader
+			.load(iri("data:application/ld+json;BASE64,eyJAY29udGV4dCI6e319"))
+			.await
+			.unwrap();
+		assert!(doc.document().is_object());
+
+		let doc = loader
+			.load(iri("data:,%7B%22@context%22:%7B%7D%7D"))
+			.await
+			.unwrap();
+		assert!(doc.document().is_object());
+
+		assert!(matches!(
+			loader.load(iri("data:text/plain,hello")).await,
+			Err(data::Error::InvalidContentType(_))
+		));
+	}
+
+	#[async_std::test]
+	async fn expansion() {
+		let dir = setup("expansion");
+		let file_context = file_iri(&dir.join("root/context.jsonld"));
+
+		let (json, _) = json_ld::syntax::Value::parse_str(&format!(
+			r#"{{
+				"@context": [
+					"{file_context}",
+					"data:application/ld+json,%7B%22@context%22:%7B%22knows%22:%22http://xmlns.com/foaf/0.1/knows%22%7D%7D"
+				],
+				"name": "Alice",
+				"knows": "Bob"
+			}}"#
+		))
+		.unwrap();
+
+		let input: RemoteDocument<IriBuf> = RemoteDocument::new(None, None, json);
+		let mut loader = ChainLoader::new(FileLoader::with_root(dir.join("root")), DataLoader);
+		let expanded = input.expand(&mut loader).await.unwrap();
+
+		let node = expanded.into_iter().next().unwrap().into_inner();
+		let node = node.into_node().unwrap();
+		assert_eq!(node.properties().len(), 2);
+
+		std::fs::remove_dir_all(dir).unwrap()
+	}
+}
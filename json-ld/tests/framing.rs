@@ -0,0 +1,164 @@
+use json_ld::{
+	syntax::{Compare, Parse},
+	JsonLdProcessor, RemoteDocument, RemoteDocumentReference,
+};
+
+async fn frame(input: &str, frame: &str) -> json_ld::syntax::Value {
+	let (input, _) = json_ld::syntax::Value::parse_str(input).unwrap();
+	let (frame, _) = json_ld::syntax::Value::parse_str(frame).unwrap();
+
+	let mut generator = rdf_types::generator::Blank::new_with_prefix("b".to_string());
+
+	RemoteDocument::new(None, None, input)
+		.frame(
+			&mut generator,
+			RemoteDocumentReference::Loaded(RemoteDocument::new(None, None, frame)),
+			&mut json_ld::NoLoader,
+		)
+		.await
+		.unwrap()
+}
+
+#[async_std::test]
+async fn frame_library() {
+	let output = frame(
+		r#"
+		{
+			"@context": {
+				"@vocab": "http://example.org/",
+				"contains": {"@type": "@id"}
+			},
+			"@graph": [
+				{
+					"@id": "http://example.org/library",
+					"@type": "Library",
+					"contains": "http://example.org/library/the-republic"
+				},
+				{
+					"@id": "http://example.org/library/the-republic",
+					"@type": "Book",
+					"creator": "Plato",
+					"title": "The Republic",
+					"contains": "http://example.org/library/the-republic#introduction"
+				},
+				{
+					"@id": "http://example.org/library/the-republic#introduction",
+					"@type": "Chapter",
+					"description": "An introductory chapter on The Republic.",
+					"title": "The Introduction"
+				}
+			]
+		}
+		"#,
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@type": "Library",
+			"contains": {
+				"@type": "Book",
+				"contains": {"@type": "Chapter"}
+			}
+		}
+		"#,
+	)
+	.await;
+
+	let (expected, _) = json_ld::syntax::Value::parse_str(
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@id": "http://example.org/library",
+			"@type": "Library",
+			"contains": {
+				"@id": "http://example.org/library/the-republic",
+				"@type": "Book",
+				"contains": {
+					"@id": "http://example.org/library/the-republic#introduction",
+					"@type": "Chapter",
+					"description": "An introductory chapter on The Republic.",
+					"title": "The Introduction"
+				},
+				"creator": "Plato",
+				"title": "The Republic"
+			}
+		}
+		"#,
+	)
+	.unwrap();
+
+	assert!(output.compare(&expected))
+}
+
+#[async_std::test]
+async fn frame_default_value() {
+	let output = frame(
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@id": "http://example.org/a",
+			"@type": "Thing"
+		}
+		"#,
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@type": "Thing",
+			"name": {"@default": "unnamed"},
+			"label": {}
+		}
+		"#,
+	)
+	.await;
+
+	let (expected, _) = json_ld::syntax::Value::parse_str(
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@id": "http://example.org/a",
+			"@type": "Thing",
+			"label": null,
+			"name": "unnamed"
+		}
+		"#,
+	)
+	.unwrap();
+
+	assert!(output.compare(&expected))
+}
+
+#[async_std::test]
+async fn frame_null_string() {
+	let output = frame(
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@id": "http://example.org/a",
+			"@type": "Thing",
+			"name": "@null"
+		}
+		"#,
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@type": "Thing",
+			"label": {"@default": "@null"}
+		}
+		"#,
+	)
+	.await;
+
+	let (expected, _) = json_ld::syntax::Value::parse_str(
+		r#"
+		{
+			"@context": {"@vocab": "http://example.org/"},
+			"@id": "http://example.org/a",
+			"@type": "Thing",
+			"label": null,
+			"name": "@null"
+		}
+		"#,
+	)
+	.unwrap();
+
+	assert!(output.compare(&expected))
+}
@@ -0,0 +1,226 @@
+mod common;
+
+use iref::{Iri, IriBuf};
+use json_ld::{
+	rdf::{RdfDirection, Value},
+	syntax::Parse,
+	ExpandedDocument, JsonLdProcessor, Options, RemoteDocument, ValidId,
+};
+use rdf_types::{literal, BlankIdBuf, Literal, Quad};
+use static_iref::iri;
+
+type RdfQuad = json_ld::rdf::Quad<IriBuf, BlankIdBuf, Literal>;
+
+fn id(s: &str) -> ValidId<IriBuf, BlankIdBuf> {
+	ValidId::Iri(common::iri(s))
+}
+
+fn blank(s: &str) -> ValidId<IriBuf, BlankIdBuf> {
+	ValidId::Blank(BlankIdBuf::new(s.to_owned()).unwrap())
+}
+
+fn typed(value: &str, ty: &Iri) -> Value<IriBuf, BlankIdBuf, Literal> {
+	Value::Literal(Literal::new(
+		value.to_owned(),
+		literal::Type::Any(ty.to_owned()),
+	))
+}
+
+async fn expand(json: &str) -> ExpandedDocument {
+	let (json, _) = json_ld::syntax::Value::parse_str(json).unwrap();
+	RemoteDocument::new(None, None, json)
+		.expand(&mut json_ld::NoLoader)
+		.await
+		.unwrap()
+}
+
+#[async_std::test]
+async fn from_rdf_list_native_types() {
+	let rdf_first = id("http://www.w3.org/1999/02/22-rdf-syntax-ns#first");
+	let rdf_rest = id("http://www.w3.org/1999/02/22-rdf-syntax-ns#rest");
+	let rdf_nil = id("http://www.w3.org/1999/02/22-rdf-syntax-ns#nil");
+	let integer = iri!("http://www.w3.org/2001/XMLSchema#integer");
+	let boolean = iri!("http://www.w3.org/2001/XMLSchema#boolean");
+
+	let quads: Vec<RdfQuad> = vec![
+		Quad(
+			id("http://example.org/a"),
+			id("http://example.org/values"),
+			Value::Id(blank("_:l0")),
+			None,
+		),
+		Quad(blank("_:l0"), rdf_first.clone(), typed("1", integer), None),
+		Quad(
+			blank("_:l0"),
+			rdf_rest.clone(),
+			Value::Id(blank("_:l1")),
+			None,
+		),
+		Quad(blank("_:l1"), rdf_first, typed("true", boolean), None),
+		Quad(blank("_:l1"), rdf_rest, Value::Id(rdf_nil), None),
+	];
+
+	let output = json_ld::from_rdf(
+		quads,
+		Options {
+			use_native_types: true,
+			..Options::default()
+		},
+	)
+	.unwrap();
+
+	let expected = expand(
+		r#"
+		{
+			"@id": "http://example.org/a",
+			"http://example.org/values": {"@list": [1, true]}
+		}
+		"#,
+	)
+	.await;
+
+	assert_eq!(output, expected)
+}
+
+#[async_std::test]
+async fn from_rdf_native_integers() {
+	let integer = iri!("http://www.w3.org/2001/XMLSchema#integer");
+	let quads: Vec<RdfQuad> = [
+		("big", "123456789012345678901234567890"),
+		("negative", "-42"),
+		("signed", "+5"),
+		("padded", "007"),
+	]
+	.into_iter()
+	.map(|(property, value)| {
+		Quad(
+			id("http://example.org/a"),
+			id(&format!("http://example.org/{property}")),
+			typed(value, integer),
+			None,
+		)
+	})
+	.collect();
+
+	let output = json_ld::from_rdf(
+		quads,
+		Options {
+			use_native_types: true,
+			..Options::default()
+		},
+	)
+	.unwrap();
+
+	let expected = expand(
+		r#"
+		{
+			"@id": "http://example.org/a",
+			"http://example.org/big": 123456789012345678901234567890,
+			"http://example.org/negative": -42,
+			"http://example.org/signed": {
+				"@value": "+5",
+				"@type": "http://www.w3.org/2001/XMLSchema#integer"
+			},
+			"http://example.org/padded": {
+				"@value": "007",
+				"@type": "http://www.w3.org/2001/XMLSchema#integer"
+			}
+		}
+		"#,
+	)
+	.await;
+
+	assert_eq!(output, expected)
+}
+
+#[async_std::test]
+async fn from_rdf_typed_list_node() {
+	let rdf_type = id("http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
+	let rdf_first = id("http://www.w3.org/1999/02/22-rdf-syntax-ns#first");
+	let rdf_rest = id("http://www.w3.org/1999/02/22-rdf-syntax-ns#rest");
+	let rdf_nil = id("http://www.w3.org/1999/02/22-rdf-syntax-ns#nil");
+	let rdf_list = id("http://www.w3.org/1999/02/22-rdf-syntax-ns#List");
+	let string = iri!("http://www.w3.org/2001/XMLSchema#string");
+
+	// A list node may only have the `rdf:List` type.
+	let quads: Vec<RdfQuad> = vec![
+		Quad(
+			id("http://example.org/a"),
+			id("http://example.org/values"),
+			Value::Id(blank("_:l0")),
+			None,
+		),
+		Quad(blank("_:l0"), rdf_type.clone(), Value::Id(rdf_list), None),
+		Quad(
+			blank("_:l0"),
+			rdf_type,
+			Value::Id(id("http://example.org/Other")),
+			None,
+		),
+		Quad(blank("_:l0"), rdf_first, typed("a", string), None),
+		Quad(blank("_:l0"), rdf_rest, Value::Id(rdf_nil), None),
+	];
+
+	let output = json_ld::from_rdf(quads, Options::default()).unwrap();
+
+	let expected = expand(
+		r#"
+		[
+			{
+				"@id": "http://example.org/a",
+				"http://example.org/values": {"@id": "_:l0"}
+			},
+			{
+				"@id": "_:l0",
+				"@type": [
+					"http://www.w3.org/1999/02/22-rdf-syntax-ns#List",
+					"http://example.org/Other"
+				],
+				"http://www.w3.org/1999/02/22-rdf-syntax-ns#first": "a",
+				"http://www.w3.org/1999/02/22-rdf-syntax-ns#rest": {"@list": []}
+			}
+		]
+		"#,
+	)
+	.await;
+
+	assert_eq!(output, expected)
+}
+
+#[async_std::test]
+async fn from_rdf_i18n_datatype() {
+	let quads: Vec<RdfQuad> = vec![Quad(
+		id("http://example.org/a"),
+		id("http://example.org/label"),
+		typed("Hello", iri!("https://www.w3.org/ns/i18n#en_rtl")),
+		Some(id("http://example.org/g")),
+	)];
+
+	let output = json_ld::from_rdf(
+		quads,
+		Options {
+			rdf_direction: Some(RdfDirection::I18nDatatype),
+			..Options::default()
+		},
+	)
+	.unwrap();
+
+	let expected = expand(
+		r#"
+		{
+			"@id": "http://example.org/g",
+			"@graph": {
+				"@id": "http://example.org/a",
+				"http://example.org/label": {
+					"@value": "Hello",
+					"@language": "en",
+					"@direction": "rtl"
+				}
+			}
+		}
+		"#,
+	)
+	.await;
+
+	assert_eq!(output, expected)
+}
@@ -0,0 +1,114 @@
+use iref::IriBuf;
+use json_ld::{html, syntax::Parse, JsonLdProcessor, RemoteDocument};
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html>
+	<head>
+		<base href="/base/">
+		<!-- <script type="application/ld+json">{"ignored": true}</script> -->
+		<script type="application/ld+json">
+			{"@id": "first", "http://example.org/name": "First"}
+		</script>
+		<script id="second" type="application/ld+json;profile=http://example.org/profile">
+			[{"@id": "second", "http://example.org/name": "Second"}]
+		</script>
+		<script id="js" type="text/javascript">var x = "</p>";</script>
+	</head>
+</html>"#;
+
+fn url(s: &str) -> Option<IriBuf> {
+	Some(IriBuf::new(s.to_owned()).unwrap())
+}
+
+async fn ids(input: RemoteDocument) -> Vec<String> {
+	let expanded = input.expand(&mut json_ld::NoLoader).await.unwrap();
+	let mut ids: Vec<_> = expanded
+		.iter()
+		.filter_map(|o| o.id().map(|id| id.as_str().to_owned()))
+		.collect();
+	ids.sort();
+	ids
+}
+
+#[async_std::test]
+async fn extract_html_scripts() {
+	let first = RemoteDocument::from_html(
+		url("http://example.org/page.html"),
+		PAGE,
+		&html::Options::default(),
+	)
+	.unwrap();
+	assert_eq!(
+		first.url().map(IriBuf::as_str),
+		Some("http://example.org/page.html")
+	);
+	assert_eq!(
+		first.base_url().map(IriBuf::as_str),
+		Some("http://example.org/base/")
+	);
+	assert_eq!(ids(first).await, ["http://example.org/base/first"]);
+
+	let by_id = RemoteDocument::from_html(
+		url("http://example.org/page.html#second"),
+		PAGE,
+		&html::Options::default(),
+	)
+	.unwrap();
+	assert_eq!(ids(by_id).await, ["http://example.org/base/second"]);
+
+	let by_profile = RemoteDocument::from_html(
+		url("http://example.org/page.html"),
+		PAGE,
+		&html::Options {
+			profile: url("http://example.org/profile"),
+			..html::Options::default()
+		},
+	)
+	.unwrap();
+	assert_eq!(ids(by_profile).await, ["http://example.org/base/second"]);
+
+	let all = RemoteDocument::from_html(
+		url("http://example.org/page.html"),
+		PAGE,
+		&html::Options {
+			extract_all_scripts: true,
+			..html::Options::default()
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		ids(all).await,
+		[
+			"http://example.org/base/first",
+			"http://example.org/base/second"
+		]
+	);
+
+	let not_json_ld = RemoteDocument::from_html(
+		url("http://example.org/page.html#js"),
+		PAGE,
+		&html::Options::default(),
+	);
+	assert!(matches!(
+		not_json_ld,
+		Err(html::Error::InvalidScriptType(_))
+	))
+}
+
+#[async_std::test]
+async fn end_tag_prefix_in_script() {
+	let page = r#"<script type="application/ld+json">
+		{"@id": "http://example.org/a", "http://example.org/note": "</scripts>"}
+	</script>"#;
+
+	let input = RemoteDocument::from_html(None, page, &html::Options::default()).unwrap();
+	assert_eq!(ids(input).await, ["http://example.org/a"]);
+}
+
+#[async_std::test]
+async fn unterminated_script() {
+	let page = r#"<script type="application/ld+json">{"@id": "http://example.org/a"}"#;
+
+	let input = RemoteDocument::from_html(None, page, &html::Options::default()).unwrap();
+	assert_eq!(ids(input).await, ["http://example.org/a"]);
+}
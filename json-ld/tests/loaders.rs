@@ -0,0 +1,566 @@
+mod common;
+
+mod map {
+	use super::common::{iri, CONTEXT};
+	use json_ld::{syntax::Parse, JsonLdProcessor, Loader, MapLoader, RemoteDocument};
+
+	#[async_std::test]
+	async fn map_loader() {
+		let (context, _) = json_ld::syntax::Value::parse_str(CONTEXT).unwrap();
+
+		let mut loader = MapLoader::new();
+		loader.insert(iri("http://example.org/context.jsonld"), context);
+		assert!(loader
+			.insert_remote(RemoteDocument::new_full(
+				Some(iri("http://example.org/data.json")),
+				Some("application/json".parse().unwrap()),
+				Some(iri("http://example.org/context.jsonld")),
+				Default::default(),
+				json_ld::syntax::Value::Null,
+			))
+			.unwrap()
+			.is_none());
+
+		let data = loader
+			.load(iri("http://example.org/data.json"))
+			.await
+			.unwrap();
+		assert_eq!(*data.content_type().unwrap(), "application/json");
+		assert_eq!(
+			data.context_url(),
+			Some(&iri("http://example.org/context.jsonld"))
+		);
+
+		let (json, _) = json_ld::syntax::Value::parse_str(
+			r#"{"@context": "http://example.org/context.jsonld", "name": "Alice"}"#,
+		)
+		.unwrap();
+		let input = RemoteDocument::new(None, None, json);
+		assert_eq!(input.expand(&mut loader).await.unwrap().len(), 1);
+
+		assert!(loader
+			.load(iri("http://example.org/unknown"))
+			.await
+			.is_err())
+	}
+
+	#[test]
+	fn insert_remote_without_url() {
+		let mut loader = MapLoader::new();
+		let document = RemoteDocument::new(None, None, json_ld::syntax::Value::Null);
+
+		let document = loader.insert_remote(document).unwrap_err();
+		assert!(document.url().is_none());
+		assert!(loader.is_empty());
+
+		loader.insert_remote_at(iri("http://example.org/data.json"), document);
+		assert_eq!(loader.len(), 1);
+	}
+}
+
+mod caching {
+	use super::common::iri;
+	use iref::IriBuf;
+	use json_ld::{future::FutureExt, CachingLoader, Loader, LoadingResult, RemoteDocument};
+	use std::time::Duration;
+
+	/// Loader counting the number of loaded documents.
+	#[derive(Default)]
+	struct CountingLoader(usize);
+
+	impl Loader<IriBuf> for CountingLoader {
+		type Error = ();
+
+		fn load_with<'a, V>(
+			&'a mut self,
+			_vocabulary: &'a mut V,
+			url: IriBuf,
+		) -> json_ld::future::BoxFuture<'a, LoadingResult<IriBuf, ()>>
+		where
+			V: rdf_types::IriVocabularyMut<Iri = IriBuf>,
+			V: Send + Sync,
+			IriBuf: 'a + Send,
+		{
+			self.0 += 1;
+			async move {
+				Ok(RemoteDocument::new(
+					Some(url),
+					None,
+					json_ld::syntax::Value::Null,
+				))
+			}
+			.boxed()
+		}
+	}
+
+	#[async_std::test]
+	async fn caching_loader_lru() {
+		let mut loader = CachingLoader::new(CountingLoader::default()).with_capacity(2);
+
+		for url in [
+			"http://a.org",
+			"http://b.org",
+			"http://a.org",
+			"http://c.org",
+		] {
+			loader.load(iri(url)).await.unwrap();
+		}
+
+		// `b` is the least recently used document.
+		assert!(loader.contains(&iri("http://a.org")));
+		assert!(!loader.contains(&iri("http://b.org")));
+		assert!(loader.contains(&iri("http://c.org")));
+
+		let stats = loader.stats();
+		assert_eq!(stats.hits, 1);
+		assert_eq!(stats.misses, 3);
+		assert_eq!(stats.evictions, 1);
+		assert_eq!(loader.inner().0, 3)
+	}
+
+	#[async_std::test]
+	async fn caching_loader_ttl() {
+		let mut loader = CachingLoader::new(CountingLoader::default()).with_ttl(Duration::ZERO);
+
+		loader.load(iri("http://a.org")).await.unwrap();
+		loader.load(iri("http://a.org")).await.unwrap();
+
+		let stats = loader.stats();
+		assert_eq!(stats.hits, 0);
+		assert_eq!(stats.expirations, 1);
+		assert_eq!(loader.inner().0, 2)
+	}
+}
+
+mod fallback {
+	use super::common::iri;
+	use contextual::WithContext;
+	use iref::IriBuf;
+	use json_ld::{syntax::Parse, FallbackLoader, Loader, MapLoader, NoLoader};
+	use rdf_types::{IndexVocabulary, IriVocabularyMut};
+	use static_iref::iri;
+
+	fn map_loader(url: &str) -> MapLoader<IriBuf> {
+		let mut loader = MapLoader::new();
+		let (context, _) = json_ld::syntax::Value::parse_str(r#"{"@context": {}}"#).unwrap();
+		loader.insert(iri(url), context);
+		loader
+	}
+
+	#[async_std::test]
+	async fn tuple() {
+		let mut loader = FallbackLoader::new((
+			NoLoader,
+			map_loader("https://example.org/a.jsonld"),
+			map_loader("https://example.org/b.jsonld"),
+		));
+
+		assert!(loader
+			.load(iri("https://example.org/b.jsonld"))
+			.await
+			.is_ok());
+
+		let error = loader
+			.load(iri("https://example.org/c.jsonld"))
+			.await
+			.unwrap_err();
+
+		let attempts = error.attempts();
+		assert!(attempts.0.loader.ends_with("NoLoader"));
+		assert_eq!(attempts.2.index, 2);
+		assert!(error.to_string().starts_with("all loaders failed: [0]"));
+	}
+
+	#[async_std::test]
+	async fn vec() {
+		let mut loader = FallbackLoader::new(vec![
+			map_loader("https://example.org/a.jsonld"),
+			map_loader("https://example.org/b.jsonld"),
+		]);
+
+		assert!(loader
+			.load(iri("https://example.org/b.jsonld"))
+			.await
+			.is_ok());
+
+		let error = loader
+			.load(iri("https://example.org/c.jsonld"))
+			.await
+			.unwrap_err();
+		assert_eq!(error.attempts().len(), 2);
+	}
+
+	#[async_std::test]
+	async fn iri_index() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("https://example.org/a.jsonld"));
+		let c = vocabulary.insert(iri!("https://example.org/c.jsonld"));
+
+		let mut map = MapLoader::new();
+		let (context, _) = json_ld::syntax::Value::parse_str(r#"{"@context": {}}"#).unwrap();
+		map.insert(a, context);
+
+		let mut loader = FallbackLoader::new((NoLoader, map));
+		assert!(loader.load_with(&mut vocabulary, a).await.is_ok());
+
+		let error = loader.load_with(&mut vocabulary, c).await.unwrap_err();
+		let message = error.with(&vocabulary).to_string();
+		assert!(message.contains("cannot load `https://example.org/c.jsonld`"));
+		assert!(message.contains("no document registered for `https://example.org/c.jsonld`"));
+	}
+}
+
+mod dynamic {
+	use super::common::{iri, CONTEXT};
+	use iref::IriBuf;
+	use json_ld::{
+		map::NotFound, syntax::Parse, BoxedLoader, JsonLdProcessor, Loader, MapLoader, NoLoader,
+		RemoteDocument,
+	};
+
+	/// Selects a loader from a configuration value.
+	fn loader(config: &str) -> BoxedLoader<IriBuf> {
+		match config {
+			"map" => {
+				let mut loader = MapLoader::new();
+				let (context, _) = json_ld::syntax::Value::parse_str(CONTEXT).unwrap();
+				loader.insert(iri("https://example.org/context.jsonld"), context);
+				BoxedLoader::new(loader)
+			}
+			_ => BoxedLoader::new(NoLoader),
+		}
+	}
+
+	#[async_std::test]
+	async fn runtime_selection() {
+		let (json, _) = json_ld::syntax::Value::parse_str(
+			r#"{"@context": "https://example.org/context.jsonld", "name": "Alice"}"#,
+		)
+		.unwrap();
+		let input: RemoteDocument<IriBuf> = RemoteDocument::new(None, None, json);
+
+		let mut map_loader = loader("map");
+		let expanded = input.expand(&mut map_loader).await.unwrap();
+		assert_eq!(expanded.len(), 1);
+
+		assert!(input.expand(&mut loader("none")).await.is_err());
+
+		let error = map_loader
+			.load(iri("https://example.org/other.jsonld"))
+			.await
+			.unwrap_err();
+		assert!(error.downcast_ref::<NotFound<IriBuf>>().is_some());
+	}
+}
+
+mod policy {
+	use super::common::iri;
+	use iref::IriBuf;
+	use json_ld::{
+		policy::{Error, IpRange, Policy, Refusal},
+		syntax::Parse,
+		JsonLdProcessor, Loader, MapLoader, PolicyLoader, RemoteDocument,
+	};
+
+	fn loader(policy: Policy) -> PolicyLoader<MapLoader<IriBuf>, IriBuf> {
+		let mut inner = MapLoader::new();
+		for url in [
+			"https://example.org/context.jsonld",
+			"https://example.org/other.jsonld",
+			"http://169.254.169.254/latest",
+			"http://127.1/context.jsonld",
+			"file:///etc/context.jsonld",
+		] {
+			let (context, _) = json_ld::syntax::Value::parse_str(r#"{"@context": {}}"#).unwrap();
+			inner.insert(iri(url), context);
+		}
+
+		PolicyLoader::new(inner, policy)
+	}
+
+	#[async_std::test]
+	async fn web_policy() {
+		let mut loader = loader(Policy::web());
+
+		assert!(loader
+			.load(iri("https://example.org/context.jsonld"))
+			.await
+			.is_ok());
+
+		assert!(matches!(
+			loader.load(iri("file:///etc/context.jsonld")).await,
+			Err(Error::Refused(_, Refusal::Scheme(scheme))) if scheme == "file"
+		));
+
+		assert!(matches!(
+			loader.load(iri("http://169.254.169.254/latest")).await,
+			Err(Error::Refused(_, Refusal::Address(_)))
+		));
+
+		assert!(matches!(
+			loader.load(iri("http://127.1/context.jsonld")).await,
+			Err(Error::Refused(_, Refusal::Address(_)))
+		));
+	}
+
+	/// Checks that the given URL is refused because of its address.
+	fn refused_address(policy: &Policy, url: &str) -> bool {
+		matches!(policy.check(&iri(url)), Err(Refusal::Address(_)))
+	}
+
+	#[test]
+	fn percent_encoded_host() {
+		let policy = Policy::web();
+		assert!(refused_address(&policy, "http://%31%32%37.0.0.1/"));
+		assert!(refused_address(&policy, "http://%31%30.0.0.1/"));
+		assert!(matches!(
+			policy.check(&iri("http://%6C%6Fcalhost/")),
+			Err(Refusal::Host(host)) if host == "localhost"
+		));
+
+		let policy = Policy::new().allow_host("example.org");
+		assert!(policy.check(&iri("https://%65xample.org/")).is_ok());
+	}
+
+	#[test]
+	fn nat64_address() {
+		let policy = Policy::web();
+		assert!(refused_address(&policy, "http://[64:ff9b::7f00:1]/"));
+		assert!(refused_address(&policy, "http://[64:ff9b::5db8:d822]/"));
+
+		let policy = Policy::new().deny_ip_range("10.0.0.0/8".parse().unwrap());
+		assert!(refused_address(&policy, "http://[64:ff9b::a00:1]/"));
+		assert!(policy.check(&iri("http://[64:ff9b::5db8:d822]/")).is_ok());
+	}
+
+	#[test]
+	fn ipv4_compatible_address() {
+		let policy = Policy::web();
+		assert!(refused_address(&policy, "http://[::7f00:1]/"));
+		assert!(refused_address(&policy, "http://[::1]/"));
+
+		let policy = Policy::new().deny_ip_range("127.0.0.0/8".parse().unwrap());
+		assert!(refused_address(&policy, "http://[::7f00:1]/"));
+	}
+
+	#[test]
+	fn six_to_four_address() {
+		let policy = Policy::web();
+		assert!(refused_address(&policy, "http://[2002:c0a8:101::1]/"));
+
+		let policy = Policy::new().deny_ip_range("192.168.0.0/16".parse().unwrap());
+		assert!(refused_address(&policy, "http://[2002:c0a8:101::1]/"));
+		assert!(policy.check(&iri("http://[2002:5db8:d822::1]/")).is_ok());
+	}
+
+	#[test]
+	fn multicast_address() {
+		let policy = Policy::web();
+		assert!(refused_address(&policy, "http://224.0.0.1/"));
+		assert!(refused_address(&policy, "http://239.255.255.250/"));
+		assert!(refused_address(&policy, "http://[ff02::1]/"));
+	}
+
+	#[test]
+	fn broadcast_address() {
+		let policy = Policy::web();
+		assert!(refused_address(&policy, "http://255.255.255.255/"));
+		assert!(policy.check(&iri("http://93.184.216.34/")).is_ok());
+	}
+
+	#[async_std::test]
+	async fn host_allowlist() {
+		let mut loader = loader(
+			Policy::new()
+				.allow_host("*.example.com")
+				.allow_host("example.org")
+				.allow_ip_range("169.254.0.0/16".parse::<IpRange>().unwrap()),
+		);
+
+		assert!(loader
+			.load(iri("https://example.org/context.jsonld"))
+			.await
+			.is_ok());
+
+		assert!(matches!(
+			loader.load(iri("http://127.1/context.jsonld")).await,
+			Err(Error::Refused(_, Refusal::Host(_)))
+		));
+
+		assert!(matches!(
+			loader.load(iri("file:///etc/context.jsonld")).await,
+			Err(Error::Refused(..))
+		));
+	}
+
+	#[async_std::test]
+	async fn max_loads() {
+		let mut loader = loader(Policy::new().with_max_loads(1));
+
+		let (json, _) = json_ld::syntax::Value::parse_str(
+			r#"{
+				"@context": [
+					"https://example.org/context.jsonld",
+					"https://example.org/context.jsonld",
+					"https://example.org/other.jsonld"
+				]
+			}"#,
+		)
+		.unwrap();
+
+		let input: RemoteDocument<IriBuf> = RemoteDocument::new(None, None, json);
+		assert!(input.expand(&mut loader).await.is_err());
+		assert_eq!(loader.load_count(), 1);
+
+		loader.reset();
+		assert!(loader
+			.load(iri("https://example.org/other.jsonld"))
+			.await
+			.is_ok());
+	}
+}
+
+mod context {
+	use super::common::{iri, CONTEXT};
+	use iref::IriBuf;
+	use json_ld::{
+		future::{BoxFuture, FutureExt},
+		policy::Policy,
+		syntax::{context, Parse, TryFromJson},
+		BoxedLoader, CachingLoader, ChainLoader, ContextLoadError, FallbackLoader, Id,
+		JsonLdProcessor, Loader, LoadingResult, MapLoader, NoLoader, PolicyLoader, Process,
+		RemoteContext, RemoteDocument, Term,
+	};
+	use rdf_types::IriVocabularyMut;
+
+	fn input() -> RemoteDocument<IriBuf> {
+		let (json, _) = json_ld::syntax::Value::parse_str(
+			r#"{"@context": "https://example.org/context.jsonld", "name": "Alice"}"#,
+		)
+		.unwrap();
+		RemoteDocument::new(None, None, json)
+	}
+
+	/// Loader only providing an already-parsed context.
+	struct ParsedContextLoader {
+		context: context::Context,
+		loads: usize,
+	}
+
+	impl ParsedContextLoader {
+		fn new() -> Self {
+			let (json, _) =
+				json_ld::syntax::Value::parse_str(r#"{"name": "http://schema.org/name"}"#).unwrap();
+			Self {
+				context: context::Context::try_from_json(json).unwrap(),
+				loads: 0,
+			}
+		}
+	}
+
+	impl Loader<IriBuf> for ParsedContextLoader {
+		type Error = std::convert::Infallible;
+
+		fn load_with<'a, V>(
+			&'a mut self,
+			_vocabulary: &'a mut V,
+			_url: IriBuf,
+		) -> BoxFuture<'a, LoadingResult<IriBuf, Self::Error>>
+		where
+			V: IriVocabularyMut<Iri = IriBuf>,
+			V: Send + Sync,
+		{
+			unreachable!("documents are never loaded")
+		}
+
+		fn load_context_with<'a, V>(
+			&'a mut self,
+			_vocabulary: &'a mut V,
+			url: IriBuf,
+		) -> BoxFuture<'a, Result<RemoteContext<IriBuf>, ContextLoadError<Self::Error>>>
+		where
+			V: IriVocabularyMut<Iri = IriBuf>,
+			V: Send + Sync,
+		{
+			self.loads += 1;
+			let context = self.context.clone();
+			async move { Ok(RemoteDocument::new(Some(url), None, context)) }.boxed()
+		}
+	}
+
+	#[async_std::test]
+	async fn parsed_context() {
+		let mut loader = ParsedContextLoader::new();
+		let expanded = input().expand(&mut loader).await.unwrap();
+		assert_eq!(expanded.len(), 1);
+	}
+
+	#[async_std::test]
+	async fn processed_context() {
+		let mut loader = ParsedContextLoader::new();
+		let (json, _) = json_ld::syntax::Value::parse_str(
+			r#"["https://example.org/context.jsonld", {"fullName": "name"}]"#,
+		)
+		.unwrap();
+
+		let context = context::Context::try_from_json(json)
+			.unwrap()
+			.process(&mut (), &mut loader, None)
+			.await
+			.unwrap()
+			.into_processed();
+
+		assert_eq!(loader.loads, 1);
+		let name = context.resolve(&mut (), "fullName");
+		assert!(name.value == Term::Id(Id::iri(iri("http://schema.org/name"))));
+	}
+
+	async fn expand<L>(mut loader: L)
+	where
+		L: Loader<IriBuf> + Send + Sync,
+		L::Error: std::fmt::Debug + Send,
+	{
+		let expanded = input().expand(&mut loader).await.unwrap();
+		assert_eq!(expanded.len(), 1);
+	}
+
+	/// Wrapping loaders forward context loading to the inner loaders.
+	#[async_std::test]
+	async fn forwarded_context() {
+		expand(ChainLoader::new(NoLoader, ParsedContextLoader::new())).await;
+		expand(FallbackLoader::new((NoLoader, ParsedContextLoader::new()))).await;
+		expand(FallbackLoader::new(vec![ParsedContextLoader::new()])).await;
+		expand(PolicyLoader::new(ParsedContextLoader::new(), Policy::new())).await;
+		expand(BoxedLoader::<IriBuf>::new(ParsedContextLoader::new())).await;
+
+		let mut loader = CachingLoader::new(ParsedContextLoader::new());
+		for _ in 0..2 {
+			let expanded = input().expand(&mut loader).await.unwrap();
+			assert_eq!(expanded.len(), 1);
+		}
+		assert_eq!(loader.stats().misses, 1);
+		assert_eq!(loader.stats().hits, 1);
+	}
+
+	#[async_std::test]
+	async fn cached_context() {
+		let mut inner = MapLoader::new();
+		let (json, _) = json_ld::syntax::Value::parse_str(CONTEXT).unwrap();
+		inner.insert(iri("https://example.org/context.jsonld"), json);
+
+		let mut loader = CachingLoader::new(inner);
+		for _ in 0..2 {
+			let expanded = input().expand(&mut loader).await.unwrap();
+			assert_eq!(expanded.len(), 1);
+		}
+
+		assert_eq!(loader.stats().misses, 1);
+		assert_eq!(loader.stats().hits, 1);
+
+		let context = loader
+			.load_context(iri("https://example.org/context.jsonld"))
+			.await
+			.unwrap();
+		assert!(matches!(context.document(), context::Context::One(_)));
+	}
+}
@@ -0,0 +1,67 @@
+use json_ld::{syntax::Parse, JsonLdProcessor, Options, RemoteDocument};
+
+#[async_std::test]
+async fn write_nquads_escaping() {
+	let (json, _) = json_ld::syntax::Value::parse_str(
+		r#"
+		{
+			"@id": "http://example.org/a",
+			"http://example.org/text": {"@value": "line\nbreak \"quoted\"", "@language": "en"}
+		}
+		"#,
+	)
+	.unwrap();
+
+	let mut generator = rdf_types::generator::Blank::new();
+	let input = RemoteDocument::new(None, None, json);
+	let mut rdf = input
+		.to_rdf(&mut generator, &mut json_ld::NoLoader)
+		.await
+		.unwrap();
+
+	assert_eq!(
+		rdf.to_nquads_string(),
+		"<http://example.org/a> <http://example.org/text> \"line\\nbreak \\\"quoted\\\"\"@en .\n"
+	)
+}
+
+#[async_std::test]
+async fn read_nquads() {
+	let output = json_ld::from_nquads(
+		r#"
+		# comment
+		<http://example.org/a> <http://example.org/name> "Alice!" <http://example.org/g> .
+		<http://example.org/a> <http://example.org/label> "Hello"@en--rtl <http://example.org/g> .
+		"#,
+		Options {
+			rdf_direction: Some(json_ld::rdf::RdfDirection::I18nDatatype),
+			..Options::default()
+		},
+	)
+	.unwrap();
+
+	let (expected, _) = json_ld::syntax::Value::parse_str(
+		r#"
+		{
+			"@id": "http://example.org/g",
+			"@graph": {
+				"@id": "http://example.org/a",
+				"http://example.org/name": "Alice!",
+				"http://example.org/label": {
+					"@value": "Hello",
+					"@language": "en",
+					"@direction": "rtl"
+				}
+			}
+		}
+		"#,
+	)
+	.unwrap();
+
+	let expected = RemoteDocument::new(None, None, expected)
+		.expand(&mut json_ld::NoLoader)
+		.await
+		.unwrap();
+
+	assert_eq!(output, expected)
+}
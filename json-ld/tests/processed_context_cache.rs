@@ -0,0 +1,100 @@
+mod common;
+
+use common::{iri, json, CONTEXT};
+use iref::IriBuf;
+use json_ld::{
+	context::ProcessedContextCache, Context, Expand, JsonLdProcessor, MapLoader, Options,
+	RemoteContextReference, RemoteDocument,
+};
+use rdf_types::BlankIdBuf;
+
+fn loader() -> MapLoader {
+	let mut loader = MapLoader::new();
+	loader.insert(
+		iri("https://example.org/context.jsonld"),
+		json(
+			r#"{"@context": ["https://example.org/base.jsonld", {"knows": "http://schema.org/knows"}]}"#,
+		),
+	);
+	loader.insert(iri("https://example.org/base.jsonld"), json(CONTEXT));
+	loader
+}
+
+fn documents() -> [json_ld::syntax::Value; 2] {
+	[
+		json(r#"{"@context": "https://example.org/context.jsonld", "name": "Alice"}"#),
+		json(r#"{"@context": "https://example.org/context.jsonld", "knows": "Bob"}"#),
+	]
+}
+
+async fn expand_all(cache: &ProcessedContextCache<IriBuf, BlankIdBuf>) {
+	let mut loader = loader();
+	for document in &documents() {
+		let context = Context::new(None).with_processed_context_cache(Some(cache.clone()));
+		let expanded = document
+			.expand_full(
+				&mut (),
+				context,
+				None,
+				&mut loader,
+				json_ld::expansion::Options::default(),
+				(),
+			)
+			.await
+			.unwrap();
+		assert_eq!(expanded.len(), 1);
+	}
+}
+
+#[async_std::test]
+async fn shared_remote_contexts() {
+	let cache = ProcessedContextCache::new();
+	expand_all(&cache).await;
+
+	assert_eq!(cache.len(), 2);
+	assert_eq!(cache.stats().misses, 2);
+	assert_eq!(cache.stats().hits, 1);
+	assert_eq!(cache.stats().evictions, 0);
+}
+
+#[async_std::test]
+async fn eviction() {
+	let cache = ProcessedContextCache::with_capacity(1);
+	expand_all(&cache).await;
+
+	assert_eq!(cache.len(), 1);
+	assert_eq!(cache.stats().misses, 2);
+	assert_eq!(cache.stats().hits, 1);
+	assert_eq!(cache.stats().evictions, 1);
+}
+
+#[async_std::test]
+async fn processor_options() {
+	let cache: ProcessedContextCache<IriBuf, BlankIdBuf> = ProcessedContextCache::new();
+	let options = Options {
+		processed_context_cache: Some(cache.clone().into()),
+		..Options::default()
+	};
+
+	let mut loader = loader();
+	let [alice, bob] = documents();
+	let alice = RemoteDocument::new(None, None, alice);
+	let bob = RemoteDocument::new(None, None, bob);
+
+	bob.expand_using(&mut loader, options.clone())
+		.await
+		.unwrap();
+	assert_eq!(cache.stats().misses, 2);
+
+	// Expansion and compaction both reuse the processed contexts.
+	alice
+		.compact_using(
+			RemoteContextReference::iri(iri("https://example.org/context.jsonld")),
+			&mut loader,
+			options,
+		)
+		.await
+		.unwrap();
+	assert_eq!(cache.stats().misses, 2);
+	assert_eq!(cache.stats().hits, 2);
+}
@@ -0,0 +1,449 @@
+#![cfg(feature = "reqwest")]
+mod common;
+
+mod blocking {
+	use super::common::{iri, ok, serve, CONTEXT};
+	use iref::IriBuf;
+	use json_ld::{syntax::Parse, BlockingReqwestLoader, JsonLdProcessor, RemoteDocument};
+
+	/// Stand-in HTTP server redirecting `/old.jsonld` to `/context.jsonld`,
+	/// answering `connections` connections.
+	fn serve_redirect(connections: usize) -> (String, std::thread::JoinHandle<Vec<String>>) {
+		serve(connections, |base, request| {
+			if request.starts_with("get /old.jsonld") {
+				Some(format!(
+					"HTTP/1.1 301 Moved Permanently\r\n\
+					Location: {base}/context.jsonld\r\n\
+					Content-Length: 0\r\n\
+					Connection: close\r\n\r\n"
+				))
+			} else {
+				Some(ok(CONTEXT))
+			}
+		})
+	}
+
+	#[test]
+	fn load_blocking() {
+		let (base, server) = serve_redirect(2);
+
+		let mut loader = BlockingReqwestLoader::new();
+		let document = loader
+			.load_blocking(iri(&format!("{base}/old.jsonld")))
+			.unwrap();
+
+		assert!(document.document().is_object());
+
+		server.join().unwrap();
+	}
+
+	/// Expands a document referencing `/old.jsonld` with a
+	/// [`BlockingReqwestLoader`].
+	async fn expand(base: &str) {
+		let (json, _) = json_ld::syntax::Value::parse_str(&format!(
+			r#"{{"@context": "{base}/old.jsonld", "name": "Alice"}}"#
+		))
+		.unwrap();
+
+		let input: RemoteDocument<IriBuf> = RemoteDocument::new(None, None, json);
+		let mut loader = BlockingReqwestLoader::new();
+		let expanded = input.expand(&mut loader).await.unwrap();
+		assert_eq!(expanded.len(), 1);
+	}
+
+	#[async_std::test]
+	async fn async_std_expansion() {
+		let (base, server) = serve_redirect(2);
+		expand(&base).await;
+		server.join().unwrap();
+	}
+
+	#[tokio::test]
+	async fn tokio_expansion() {
+		let (base, server) = serve_redirect(2);
+		expand(&base).await;
+		server.join().unwrap();
+	}
+}
+
+mod cache {
+	use super::common::{iri, serve, CONTEXT};
+	use json_ld::{reqwest, Loader, ReqwestLoader};
+
+	#[tokio::test]
+	async fn http_cache_revalidation() {
+		let mut responses = vec![
+			format!(
+				"HTTP/1.1 200 OK\r\n\
+				Content-Type: application/ld+json\r\n\
+				Cache-Control: max-age=0\r\n\
+				ETag: \"v1\"\r\n\
+				Content-Length: {}\r\n\
+				Connection: close\r\n\r\n{CONTEXT}",
+				CONTEXT.len()
+			),
+			"HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n".to_string(),
+		]
+		.into_iter();
+
+		let (base, server) = serve(2, move |_, _| responses.next());
+		let url = iri(&format!("{base}/context.jsonld"));
+
+		let cache_directory =
+			std::env::temp_dir().join(format!("json-ld-http-cache-{}", std::process::id()));
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			cache_directory: Some(cache_directory.clone()),
+			..Default::default()
+		});
+
+		// Stored on first load, then revalidated.
+		let first = loader.load(url.clone()).await.unwrap();
+		let second = loader.load(url.clone()).await.unwrap();
+		assert_eq!(first.document(), second.document());
+
+		let requests = server.join().unwrap();
+		assert!(!requests[0].contains("if-none-match"));
+		assert!(requests[1].contains("if-none-match: \"v1\""));
+
+		// The server is gone: the stale entry is used.
+		let third = loader.load(url).await.unwrap();
+		assert_eq!(first.document(), third.document());
+
+		std::fs::remove_dir_all(cache_directory).unwrap()
+	}
+}
+
+mod headers {
+	use super::common::{iri, ok, serve, CONTEXT};
+	use json_ld::{
+		reqwest::{self, header, Credentials},
+		Loader, ReqwestLoader,
+	};
+	use std::sync::Arc;
+
+	#[tokio::test]
+	async fn headers_and_credentials() {
+		let (base, server) = serve(1, |_, _| Some(ok(CONTEXT)));
+
+		let mut headers = header::HeaderMap::new();
+		headers.insert("x-api-key", header::HeaderValue::from_static("secret"));
+
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			headers,
+			credentials: Some(Arc::new(|url: &iref::Iri| {
+				(url.authority()?.host().as_str() == "127.0.0.1")
+					.then(|| Credentials::Bearer("token".to_string()))
+			})),
+			..Default::default()
+		});
+
+		loader
+			.load(iri(&format!("{base}/context.jsonld")))
+			.await
+			.unwrap();
+
+		let request = &server.join().unwrap()[0];
+		assert!(request.contains("x-api-key: secret"));
+		assert!(request.contains("authorization: bearer token"));
+		assert!(request.contains("accept: application/ld+json"));
+	}
+
+	#[tokio::test]
+	async fn proxy() {
+		let (base, server) = serve(1, |_, _| Some(ok(CONTEXT)));
+
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			proxies: vec![reqwest::Proxy::http(base).unwrap()],
+			..Default::default()
+		});
+
+		loader
+			.load(iri("http://example.invalid/context.jsonld"))
+			.await
+			.unwrap();
+
+		let request = &server.join().unwrap()[0];
+		assert!(request.starts_with("get http://example.invalid/context.jsonld http/1.1"));
+	}
+}
+
+mod limits {
+	use super::common::{iri, ok, serve, CONTEXT};
+	use iref::IriBuf;
+	use json_ld::{reqwest, Loader, ReqwestLoader};
+	use std::time::Duration;
+
+	/// Stand-in HTTP server answering the given responses, one per
+	/// connection.
+	fn serve_responses(
+		responses: Vec<Option<String>>,
+	) -> (IriBuf, std::thread::JoinHandle<Vec<String>>) {
+		let connections = responses.len();
+		let mut responses = responses.into_iter();
+		let (base, handle) = serve(connections, move |_, _| responses.next().unwrap());
+		(iri(&format!("{base}/context.jsonld")), handle)
+	}
+
+	fn unavailable() -> Option<String> {
+		Some(
+			"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+				.to_string(),
+		)
+	}
+
+	#[tokio::test]
+	async fn max_body_size() {
+		let (url, server) = serve_responses(vec![Some(ok(CONTEXT))]);
+
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			max_body_size: Some(16),
+			..Default::default()
+		});
+
+		assert!(matches!(
+			loader.load(url).await,
+			Err(reqwest::Error::BodyTooLarge(16))
+		));
+
+		server.join().unwrap();
+	}
+
+	#[tokio::test]
+	async fn read_timeout() {
+		let (url, server) = serve_responses(vec![None]);
+
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			read_timeout: Some(Duration::from_millis(100)),
+			..Default::default()
+		});
+
+		assert!(matches!(
+			loader.load(url).await,
+			Err(reqwest::Error::ReadTimeout)
+		));
+
+		server.join().unwrap();
+	}
+
+	#[tokio::test]
+	async fn retry() {
+		let retry = reqwest::RetryPolicy {
+			initial_backoff: Duration::from_millis(10),
+			..reqwest::RetryPolicy::new(1)
+		};
+
+		let (url, server) = serve_responses(vec![unavailable(), Some(ok(CONTEXT))]);
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			retry,
+			..Default::default()
+		});
+		assert!(loader.load(url).await.is_ok());
+		server.join().unwrap();
+
+		let (url, server) = serve_responses(vec![unavailable(), unavailable()]);
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			retry,
+			..Default::default()
+		});
+		match loader.load(url).await {
+			Err(reqwest::Error::RetriesExhausted(2, e)) => {
+				assert!(matches!(*e, reqwest::Error::QueryFailed(status) if status == 503))
+			}
+			_ => panic!("expected exhausted retries"),
+		}
+		server.join().unwrap();
+	}
+}
+
+mod policy {
+	use super::common::{iri, ok, serve, CONTEXT};
+	use json_ld::{
+		policy::{Policy, Refusal},
+		reqwest, Loader, ReqwestLoader,
+	};
+
+	#[tokio::test]
+	async fn refused_redirection() {
+		let (base, server) = serve(1, |_, _| {
+			Some(
+				"HTTP/1.1 302 Found\r\n\
+				Location: http://10.0.0.1/context.jsonld\r\n\
+				Content-Length: 0\r\n\
+				Connection: close\r\n\r\n"
+					.to_string(),
+			)
+		});
+
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			policy: Some(Policy::new().deny_ip_range("10.0.0.0/8".parse().unwrap())),
+			..Default::default()
+		});
+
+		assert!(matches!(
+			loader.load(iri(&format!("{base}/context.jsonld"))).await,
+			Err(reqwest::Error::Refused(_, Refusal::Address(_)))
+		));
+
+		server.join().unwrap();
+	}
+
+	#[tokio::test]
+	async fn resolved_private_address() {
+		let (base, server) = serve(1, |_, _| Some(ok(CONTEXT)));
+		let port = base.rsplit(':').next().unwrap();
+
+		// `localhost` is not an IP address literal, only its resolved
+		// addresses are refused.
+		let policy = Policy::new()
+			.deny_ip_range("127.0.0.0/8".parse().unwrap())
+			.deny_ip_range("::1".parse().unwrap());
+		assert!(policy
+			.check(&iri(&format!("http://localhost:{port}/context.jsonld")))
+			.is_ok());
+
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			policy: Some(policy),
+			..Default::default()
+		});
+		assert!(matches!(
+			loader
+				.load(iri(&format!("http://localhost:{port}/context.jsonld")))
+				.await,
+			Err(reqwest::Error::Reqwest(_))
+		));
+
+		// The server is reachable without the policy.
+		let mut loader = ReqwestLoader::new();
+		assert!(loader
+			.load(iri(&format!("{base}/context.jsonld")))
+			.await
+			.is_ok());
+
+		assert_eq!(server.join().unwrap().len(), 1);
+	}
+}
+
+mod tls {
+	use super::common::{iri, ok, read_request_head, CONTEXT};
+	use iref::IriBuf;
+	use json_ld::{reqwest, Loader, ReqwestLoader};
+	use std::io::Write;
+	use std::net::TcpListener;
+	use std::sync::Arc;
+
+	/// Private certificate authority, and a `localhost` certificate it signed.
+	struct Pki {
+		ca: Vec<u8>,
+		certificate: Vec<u8>,
+		key: Vec<u8>,
+	}
+
+	impl Pki {
+		fn new() -> Self {
+			let mut ca_params = rcgen::CertificateParams::new(Vec::new());
+			ca_params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+			let ca = rcgen::Certificate::from_params(ca_params).unwrap();
+
+			let certificate = rcgen::Certificate::from_params(rcgen::CertificateParams::new(vec![
+				"localhost".to_string(),
+			]))
+			.unwrap();
+
+			Self {
+				ca: ca.serialize_der().unwrap(),
+				certificate: certificate.serialize_der_with_signer(&ca).unwrap(),
+				key: certificate.serialize_private_key_der(),
+			}
+		}
+	}
+
+	/// Stand-in HTTPS server answering `connections` connections.
+	fn serve(pki: &Pki, connections: usize) -> (IriBuf, std::thread::JoinHandle<()>) {
+		let config = rustls::ServerConfig::builder()
+			.with_safe_defaults()
+			.with_no_client_auth()
+			.with_single_cert(
+				vec![rustls::Certificate(pki.certificate.clone())],
+				rustls::PrivateKey(pki.key.clone()),
+			)
+			.unwrap();
+		let config = Arc::new(config);
+
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let url = iri(&format!(
+			"https://localhost:{}/context.jsonld",
+			listener.local_addr().unwrap().port()
+		));
+
+		let handle = std::thread::spawn(move || {
+			for _ in 0..connections {
+				let (stream, _) = listener.accept().unwrap();
+				let connection = rustls::ServerConnection::new(config.clone()).unwrap();
+				let mut stream = rustls::StreamOwned::new(connection, stream);
+
+				// Handshake failures are expected when the client rejects the
+				// certificate.
+				if read_request_head(&mut stream).is_some() {
+					stream.write_all(ok(CONTEXT).as_bytes()).unwrap();
+					stream.conn.send_close_notify();
+					stream.flush().unwrap();
+				}
+			}
+		});
+
+		(url, handle)
+	}
+
+	#[tokio::test]
+	async fn private_root_certificate() {
+		let pki = Pki::new();
+		let (url, server) = serve(&pki, 2);
+
+		// The private CA is unknown.
+		let mut loader = ReqwestLoader::new();
+		assert!(matches!(
+			loader.load(url.clone()).await,
+			Err(reqwest::Error::Reqwest(_))
+		));
+
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			root_certificates: vec![reqwest::Certificate::from_der(&pki.ca).unwrap()],
+			..Default::default()
+		});
+		let document = loader.load(url).await.unwrap();
+		assert!(document.document().is_object());
+
+		server.join().unwrap()
+	}
+
+	#[tokio::test]
+	async fn preconfigured_tls() {
+		let pki = Pki::new();
+		let (url, server) = serve(&pki, 2);
+
+		let mut roots = rustls::RootCertStore::empty();
+		roots.add(&rustls::Certificate(pki.ca.clone())).unwrap();
+		let tls_config = rustls::ClientConfig::builder()
+			.with_safe_defaults()
+			.with_root_certificates(roots)
+			.with_no_client_auth();
+
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			tls_config: Some(tls_config.clone()),
+			..Default::default()
+		});
+		assert!(loader.load(url.clone()).await.is_ok());
+
+		let client = reqwest::Client::builder()
+			.use_preconfigured_tls(tls_config)
+			.build()
+			.unwrap();
+		let mut loader = ReqwestLoader::new_using(reqwest::Options {
+			client: Some(client),
+			..Default::default()
+		});
+		assert!(loader.load(url).await.is_ok());
+
+		server.join().unwrap()
+	}
+}
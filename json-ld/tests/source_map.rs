@@ -0,0 +1,74 @@
+use iref::IriBuf;
+use json_ld::{
+	expansion::CodeMapped,
+	syntax::{ErrorCode, Parse},
+	NoLoader,
+};
+use static_iref::iri;
+
+const SOURCE: &str = r#"{
+	"@context": {
+		"knows": "http://schema.org/knows"
+	},
+	"@id": "http://example.org/alice",
+	"knows": [
+		{ "@id": "http://example.org/bob" },
+		{ "@id": 1 }
+	]
+}"#;
+
+#[async_std::test]
+async fn error_span() {
+	let (json, code_map) = json_ld::syntax::Value::parse_str(SOURCE).unwrap();
+
+	let e = CodeMapped::new(&json, &code_map)
+		.expand::<IriBuf, _>(&mut NoLoader)
+		.await
+		.unwrap_err();
+
+	assert_eq!(e.code(), ErrorCode::InvalidIdValue);
+
+	let position = e.position().unwrap();
+	assert_eq!(
+		&SOURCE[position.span(&code_map).unwrap()],
+		r#"{ "@id": 1 }"#
+	);
+	assert_eq!(&SOURCE[position.key_span(&code_map).unwrap()], r#""knows""#);
+}
+
+#[async_std::test]
+async fn positions() {
+	let source = SOURCE.replace('1', "\"_:b\"");
+	let (json, code_map) = json_ld::syntax::Value::parse_str(&source).unwrap();
+
+	let expanded = CodeMapped::new(&json, &code_map)
+		.expand::<IriBuf, _>(&mut NoLoader)
+		.await
+		.unwrap();
+
+	let alice = expanded.iter().next().unwrap();
+	let position = alice.position().unwrap();
+	assert_eq!(&source[position.span(&code_map).unwrap()], source);
+	assert_eq!(position.key_span(&code_map), None);
+
+	let spans: Vec<_> = alice
+		.as_node()
+		.unwrap()
+		.get(&iri!("http://schema.org/knows"))
+		.map(|o| {
+			let position = o.position().unwrap();
+			(
+				&source[position.key_span(&code_map).unwrap()],
+				&source[position.span(&code_map).unwrap()],
+			)
+		})
+		.collect();
+
+	assert_eq!(
+		spans,
+		[
+			(r#""knows""#, r#"{ "@id": "http://example.org/bob" }"#),
+			(r#""knows""#, r#"{ "@id": "_:b" }"#),
+		]
+	)
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::CONTEXT;
+use iref::IriBuf;
+use json_ld::{
+	syntax::Parse, ChainLoader, JsonLdProcessor, NoLoader, RemoteDocument, StaticLoader,
+};
+
+#[async_std::test]
+async fn static_loader_context() {
+	let mut loader = ChainLoader::new(
+		[("http://example.org/context.jsonld", CONTEXT)]
+			.into_iter()
+			.collect::<StaticLoader>(),
+		NoLoader,
+	);
+
+	let (json, _) = json_ld::syntax::Value::parse_str(
+		r#"{"@context": "http://example.org/context.jsonld", "name": "Alice"}"#,
+	)
+	.unwrap();
+
+	let input: RemoteDocument<IriBuf> = RemoteDocument::new(None, None, json);
+	let expanded = input.expand(&mut loader).await.unwrap();
+	assert_eq!(expanded.len(), 1);
+
+	let (json, _) = json_ld::syntax::Value::parse_str(
+		r#"{"@context": "http://example.org/unknown.jsonld", "name": "Alice"}"#,
+	)
+	.unwrap();
+
+	let input: RemoteDocument<IriBuf> = RemoteDocument::new(None, None, json);
+	assert!(input.expand(&mut loader).await.is_err())
+}
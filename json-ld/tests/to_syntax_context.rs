@@ -0,0 +1,84 @@
+mod common;
+
+use common::{iri, json, CONTEXT};
+use iref::IriBuf;
+use json_ld::{
+	syntax::{context, ContextEntry, TryFromJson},
+	MapLoader, NoLoader, Process,
+};
+
+fn scoped_context_loader() -> MapLoader<IriBuf> {
+	let mut loader = MapLoader::new();
+	loader.insert(
+		iri("https://example.org/scoped.jsonld"),
+		json(
+			r#"{"@context": {"@import": "imported.jsonld", "tagName": "http://example.org/tagName"}}"#,
+		),
+	);
+	loader.insert(
+		iri("https://example.org/imported.jsonld"),
+		json(r#"{"@context": {"label": "http://example.org/label"}}"#),
+	);
+	loader
+}
+
+#[async_std::test]
+async fn self_contained() {
+	let mut loader = scoped_context_loader();
+	loader.insert(iri("https://example.org/context.jsonld"), json(CONTEXT));
+
+	let local_context = context::Context::try_from_json(json(
+		r#"[
+			"https://example.org/context.jsonld",
+			{
+				"@vocab": "http://example.org/vocab#",
+				"tag": {"@id": "http://example.org/tag", "@context": "scoped.jsonld"}
+			}
+		]"#,
+	))
+	.unwrap();
+
+	let processed = local_context
+		.process(
+			&mut (),
+			&mut loader,
+			Some(iri("https://example.org/document.jsonld")),
+		)
+		.await
+		.unwrap()
+		.into_processed();
+
+	let snapshot = processed
+		.to_syntax_context(&mut (), &mut loader)
+		.await
+		.unwrap();
+
+	// No remote context is left to load.
+	let reprocessed = snapshot
+		.process(&mut (), &mut NoLoader, None)
+		.await
+		.unwrap()
+		.into_processed();
+
+	assert!(reprocessed.get_normal("name") == processed.get_normal("name"));
+	assert!(reprocessed.vocabulary() == processed.vocabulary());
+
+	let scoped = reprocessed
+		.get_normal("tag")
+		.unwrap()
+		.context
+		.clone()
+		.unwrap();
+	assert!(matches!(
+		scoped.as_slice(),
+		[ContextEntry::Definition(d)] if d.import.is_none()
+	));
+
+	let scoped = scoped
+		.process(&mut (), &mut NoLoader, None)
+		.await
+		.unwrap()
+		.into_processed();
+	assert!(scoped.get_normal("tagName").is_some());
+	assert!(scoped.get_normal("label").is_some());
+}
@@ -66,6 +66,12 @@ pub enum ErrorCode {
 	/// The value of the default language is not a string or null and thus invalid.
 	InvalidDefaultLanguage,
 
+	/// An invalid value for `@embed` has been found in a frame.
+	InvalidEmbedValue,
+
+	/// The frame is invalid.
+	InvalidFrame,
+
 	/// A local context contains a term that has an invalid or missing IRI mapping.
 	InvalidIriMapping,
 
@@ -199,6 +205,8 @@ impl ErrorCode {
 			InvalidContextEntry => "invalid context entry",
 			InvalidContextNullification => "invalid context nullification",
 			InvalidDefaultLanguage => "invalid default language",
+			InvalidEmbedValue => "invalid @embed value",
+			InvalidFrame => "invalid frame",
 			InvalidIriMapping => "invalid IRI mapping",
 			InvalidJsonLiteral => "invalid JSON literal",
 			InvalidKeywordAlias => "invalid keyword alias",
@@ -260,6 +268,8 @@ impl<'a> TryFrom<&'a str> for ErrorCode {
 			"invalid context entry" => Ok(InvalidContextEntry),
 			"invalid context nullification" => Ok(InvalidContextNullification),
 			"invalid default language" => Ok(InvalidDefaultLanguage),
+			"invalid @embed value" => Ok(InvalidEmbedValue),
+			"invalid frame" => Ok(InvalidFrame),
 			"invalid IRI mapping" => Ok(InvalidIriMapping),
 			"invalid JSON literal" => Ok(InvalidJsonLiteral),
 			"invalid keyword alias" => Ok(InvalidKeywordAlias),
@@ -10,7 +10,9 @@
 //! ```
 //!
 //! This will clone the [W3C JSON-LD API repository](https://github.com/w3c/json-ld-api)
-//! containing the official test suite,
+//! the [W3C JSON-LD Framing repository](https://github.com/w3c/json-ld-framing)
+//! and the [W3C RDF Canonicalization repository](https://github.com/w3c/rdf-canon)
+//! containing the official test suites,
 //! generate the associated Rust tests using the procedural macros provided by the
 //! [`json-ld-testing` crate]() and run the tests.
 //!
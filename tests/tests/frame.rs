@@ -0,0 +1,180 @@
+use contextual::WithContext;
+use json_ld::{JsonLdProcessor, Loader, Print, RemoteDocument, RemoteDocumentReference};
+use rdf_types::{vocabulary::IriIndex, IndexVocabulary, IriVocabularyMut};
+use static_iref::iri;
+
+#[json_ld_testing::test_suite("https://w3c.github.io/json-ld-framing/tests/frame-manifest.jsonld")]
+#[mount("https://w3c.github.io/json-ld-framing", "tests/json-ld-framing")]
+#[iri_prefix("rdf" = "http://www.w3.org/1999/02/22-rdf-syntax-ns#")]
+#[iri_prefix("rdfs" = "http://www.w3.org/2000/01/rdf-schema#")]
+#[iri_prefix("manifest" = "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#")]
+#[iri_prefix("test" = "https://w3c.github.io/json-ld-api/tests/vocab#")]
+mod frame {
+	use iref::Iri;
+
+	#[iri("test:FrameTest")]
+	pub struct Test {
+		#[iri("rdfs:comment")]
+		pub comments: &'static [&'static str],
+
+		#[iri("manifest:action")]
+		pub input: &'static Iri,
+
+		#[iri("manifest:name")]
+		pub name: &'static str,
+
+		#[iri("test:option")]
+		pub options: Options,
+
+		#[iri("test:frame")]
+		pub frame: &'static Iri,
+
+		#[iri("rdf:type")]
+		pub desc: Description,
+	}
+
+	pub enum Description {
+		#[iri("test:PositiveEvaluationTest")]
+		Positive {
+			#[iri("manifest:result")]
+			expect: &'static Iri,
+		},
+		#[iri("test:NegativeEvaluationTest")]
+		Negative {
+			#[iri("manifest:result")]
+			expected_error_code: &'static str,
+		},
+	}
+
+	#[derive(Default)]
+	pub struct Options {
+		#[iri("test:base")]
+		pub base: Option<&'static Iri>,
+
+		#[iri("test:processingMode")]
+		pub processing_mode: Option<json_ld::ProcessingMode>,
+
+		#[iri("test:specVersion")]
+		pub spec_version: Option<&'static str>,
+
+		#[iri("test:normative")]
+		pub normative: Option<bool>,
+
+		#[iri("test:omitGraph")]
+		pub omit_graph: Option<bool>,
+	}
+}
+
+impl frame::Test {
+	fn run(self) {
+		let child = std::thread::Builder::new()
+			.spawn(|| async_std::task::block_on(self.async_run()))
+			.unwrap();
+
+		child.join().unwrap()
+	}
+
+	async fn async_run(self) {
+		if !self.options.normative.unwrap_or(true) {
+			log::warn!("ignoring test `{}` (non normative)", self.name);
+			return;
+		}
+
+		if self.options.spec_version == Some("json-ld-1.0") {
+			log::warn!("ignoring test `{}` (unsupported spec version)", self.name);
+			return;
+		}
+
+		for comment in self.comments {
+			println!("{}", comment)
+		}
+
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let mut loader: json_ld::FsLoader = json_ld::FsLoader::default();
+		loader.mount(
+			vocabulary.insert(iri!("https://w3c.github.io/json-ld-framing")),
+			"json-ld-framing",
+		);
+
+		let mut options: json_ld::Options<IriIndex> = json_ld::Options::default();
+		if let Some(p) = self.options.processing_mode {
+			options.processing_mode = p
+		}
+
+		options.base = self.options.base.map(|iri| vocabulary.insert(iri));
+		options.omit_graph = self.options.omit_graph;
+
+		let input = vocabulary.insert(self.input);
+		let frame = RemoteDocumentReference::Iri(vocabulary.insert(self.frame));
+
+		match self.desc {
+			frame::Description::Positive { expect } => {
+				let json_ld = loader.load_with(&mut vocabulary, input).await.unwrap();
+				let mut generator = rdf_types::generator::Blank::new_with_prefix("b".to_string());
+				let framed = json_ld
+					.frame_full(
+						&mut vocabulary,
+						&mut generator,
+						frame,
+						&mut loader,
+						options,
+						(),
+					)
+					.await
+					.unwrap();
+				let framed = RemoteDocument::new(Some(input), None, framed);
+
+				let expect = vocabulary.insert(expect);
+				let mut expect = loader.load_with(&mut vocabulary, expect).await.unwrap();
+				expect.set_url(Some(input));
+
+				let expand_options: json_ld::Options<IriIndex> = json_ld::Options::default();
+				let success = framed
+					.compare_full(&expect, &mut vocabulary, &mut loader, expand_options, ())
+					.await
+					.unwrap();
+
+				if !success {
+					eprintln!("test failed");
+					eprintln!("output=\n{}", framed.document().pretty_print());
+					eprintln!(
+						"expected=\n{}",
+						expect.document().with(&vocabulary).pretty_print()
+					);
+				}
+
+				assert!(success)
+			}
+			frame::Description::Negative {
+				expected_error_code,
+			} => match loader.load_with(&mut vocabulary, input).await {
+				Ok(json_ld) => {
+					let mut generator =
+						rdf_types::generator::Blank::new_with_prefix("b".to_string());
+					let result = json_ld
+						.frame_full(
+							&mut vocabulary,
+							&mut generator,
+							frame,
+							&mut loader,
+							options,
+							(),
+						)
+						.await;
+
+					match result {
+						Ok(framed) => {
+							eprintln!("output=\n{}", framed.pretty_print());
+							panic!(
+								"framing succeeded when it should have failed with `{}`",
+								expected_error_code
+							)
+						}
+						Err(e) => assert_eq!(e.code().as_str(), expected_error_code),
+					}
+				}
+				Err(_) => assert_eq!(expected_error_code, "loading document failed"),
+			},
+		}
+	}
+}
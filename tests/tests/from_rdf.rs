@@ -0,0 +1,146 @@
+use contextual::WithContext;
+use iref::IriBuf;
+use json_ld::{JsonLdProcessor, Loader, Print};
+
+#[json_ld_testing::test_suite("https://w3c.github.io/json-ld-api/tests/fromRdf-manifest.jsonld")]
+#[mount("https://w3c.github.io/json-ld-api", "tests/json-ld-api")]
+#[iri_prefix("rdf" = "http://www.w3.org/1999/02/22-rdf-syntax-ns#")]
+#[iri_prefix("rdfs" = "http://www.w3.org/2000/01/rdf-schema#")]
+#[iri_prefix("manifest" = "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#")]
+#[iri_prefix("jld" = "https://w3c.github.io/json-ld-api/tests/vocab#")]
+mod from_rdf {
+	use iref::Iri;
+	use json_ld::rdf::RdfDirection;
+
+	#[iri("jld:FromRDFTest")]
+	pub struct Test {
+		#[iri("rdfs:comment")]
+		pub comments: &'static [&'static str],
+
+		#[iri("manifest:action")]
+		pub input: &'static Iri,
+
+		#[iri("manifest:name")]
+		pub name: &'static str,
+
+		#[iri("jld:option")]
+		pub options: Options,
+
+		#[iri("rdf:type")]
+		pub desc: Description,
+	}
+
+	pub enum Description {
+		#[iri("jld:PositiveEvaluationTest")]
+		Positive {
+			#[iri("manifest:result")]
+			expect: &'static Iri,
+		},
+		#[iri("jld:NegativeEvaluationTest")]
+		Negative {
+			#[iri("manifest:result")]
+			expected_error_code: &'static str,
+		},
+	}
+
+	#[derive(Default)]
+	pub struct Options {
+		#[iri("jld:processingMode")]
+		pub processing_mode: Option<json_ld::ProcessingMode>,
+
+		#[iri("jld:specVersion")]
+		pub spec_version: Option<&'static str>,
+
+		#[iri("jld:normative")]
+		pub normative: Option<bool>,
+
+		#[iri("jld:useNativeTypes")]
+		pub use_native_types: bool,
+
+		#[iri("jld:useRdfType")]
+		pub use_rdf_type: bool,
+
+		#[iri("jld:rdfDirection")]
+		pub rdf_direction: Option<RdfDirection>,
+	}
+}
+
+impl from_rdf::Test {
+	fn run(self) {
+		let child = std::thread::Builder::new()
+			.spawn(|| async_std::task::block_on(self.async_run()))
+			.unwrap();
+
+		child.join().unwrap()
+	}
+
+	async fn async_run(self) {
+		if !self.options.normative.unwrap_or(true) {
+			log::warn!("ignoring test `{}` (non normative)", self.name);
+			return;
+		}
+
+		if self.options.spec_version == Some("json-ld-1.0") {
+			log::warn!("ignoring test `{}` (unsupported spec version)", self.name);
+			return;
+		}
+
+		for comment in self.comments {
+			println!("{}", comment)
+		}
+
+		let mut loader: json_ld::FsLoader<IriBuf> = json_ld::FsLoader::default();
+		loader.mount(
+			IriBuf::new("https://w3c.github.io/json-ld-api".to_string()).unwrap(),
+			"json-ld-api",
+		);
+
+		let mut options: json_ld::Options = json_ld::Options::default();
+		if let Some(p) = self.options.processing_mode {
+			options.processing_mode = p
+		}
+
+		options.use_native_types = self.options.use_native_types;
+		options.use_rdf_type = self.options.use_rdf_type;
+		options.rdf_direction = self.options.rdf_direction;
+
+		let input = self.input.to_owned();
+		let content = std::fs::read_to_string(loader.filepath(&(), &input).unwrap()).unwrap();
+		let result = json_ld::from_nquads(&content, options);
+
+		match self.desc {
+			from_rdf::Description::Positive { expect } => {
+				let output = result.unwrap();
+
+				let expected = loader
+					.load_with(&mut (), expect.to_owned())
+					.await
+					.unwrap()
+					.expand(&mut loader)
+					.await
+					.unwrap();
+
+				let success = output == expected;
+
+				if !success {
+					eprintln!("test failed");
+					eprintln!("output=\n{}", output.with(&()).pretty_print());
+					eprintln!("expected=\n{}", expected.with(&()).pretty_print());
+				}
+
+				assert!(success)
+			}
+			from_rdf::Description::Negative {
+				expected_error_code,
+			} => {
+				if let Ok(output) = result {
+					eprintln!("output=\n{}", output.with(&()).pretty_print());
+					panic!(
+						"serialization succeeded when it should have failed with `{}`",
+						expected_error_code
+					)
+				}
+			}
+		}
+	}
+}
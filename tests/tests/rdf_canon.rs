@@ -0,0 +1,164 @@
+use iref::{Iri, IriBuf};
+use json_ld::{
+	rdf::{self, CanonicalizationError, CanonicalizationOptions},
+	syntax::Parse,
+};
+use rdf_types::{vocabulary::no_vocabulary_mut, BlankIdBuf};
+
+#[json_ld_testing::test_suite("https://w3c.github.io/rdf-canon/tests/manifest.jsonld")]
+#[mount("https://w3c.github.io/rdf-canon", "tests/rdf-canon")]
+#[iri_prefix("rdfs" = "http://www.w3.org/2000/01/rdf-schema#")]
+#[iri_prefix("manifest" = "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#")]
+#[iri_prefix("rdfc" = "https://w3c.github.io/rdf-canon/tests/vocab#")]
+mod rdf_canon {
+	use iref::Iri;
+
+	#[iri("rdfc:RDFC10EvalTest")]
+	pub struct EvalTest {
+		#[iri("rdfs:comment")]
+		pub comments: &'static [&'static str],
+
+		#[iri("manifest:action")]
+		pub input: &'static Iri,
+
+		#[iri("manifest:result")]
+		pub expect: &'static Iri,
+
+		#[iri("manifest:name")]
+		pub name: &'static str,
+
+		#[iri("rdfc:hashAlgorithm")]
+		pub hash_algorithm: Option<&'static str>,
+	}
+
+	#[iri("rdfc:RDFC10MapTest")]
+	pub struct MapTest {
+		#[iri("rdfs:comment")]
+		pub comments: &'static [&'static str],
+
+		#[iri("manifest:action")]
+		pub input: &'static Iri,
+
+		#[iri("manifest:result")]
+		pub expect: &'static Iri,
+
+		#[iri("manifest:name")]
+		pub name: &'static str,
+
+		#[iri("rdfc:hashAlgorithm")]
+		pub hash_algorithm: Option<&'static str>,
+	}
+
+	#[iri("rdfc:RDFC10NegativeEvalTest")]
+	pub struct NegativeEvalTest {
+		#[iri("rdfs:comment")]
+		pub comments: &'static [&'static str],
+
+		#[iri("manifest:action")]
+		pub input: &'static Iri,
+
+		#[iri("manifest:name")]
+		pub name: &'static str,
+	}
+}
+
+/// Returns the content of the given test file.
+fn read(iri: &Iri) -> String {
+	let mut loader: json_ld::FsLoader<IriBuf> = json_ld::FsLoader::default();
+	loader.mount(
+		IriBuf::new("https://w3c.github.io/rdf-canon".to_string()).unwrap(),
+		"rdf-canon",
+	);
+
+	std::fs::read_to_string(loader.filepath(&(), &iri.to_owned()).unwrap()).unwrap()
+}
+
+/// Canonicalizes the given N-Quads test input.
+fn canonicalize(
+	input: &Iri,
+) -> Result<rdf::CanonicalDataset<IriBuf, BlankIdBuf, rdf_types::Literal>, CanonicalizationError> {
+	let quads = rdf::nquads::parse_str(&read(input)).unwrap();
+	rdf::canonicalize_with(
+		no_vocabulary_mut(),
+		quads,
+		CanonicalizationOptions::default(),
+	)
+}
+
+/// Checks if the given hash algorithm is supported.
+///
+/// Only SHA-256 is supported.
+fn is_supported(name: &str, hash_algorithm: Option<&str>) -> bool {
+	match hash_algorithm {
+		None | Some("SHA256") => true,
+		Some(algorithm) => {
+			log::warn!("ignoring test `{name}` (unsupported hash algorithm {algorithm})");
+			false
+		}
+	}
+}
+
+impl rdf_canon::EvalTest {
+	fn run(self) {
+		if !is_supported(self.name, self.hash_algorithm) {
+			return;
+		}
+
+		for comment in self.comments {
+			println!("{}", comment)
+		}
+
+		let dataset = canonicalize(self.input).unwrap();
+		let expected = read(self.expect);
+
+		if dataset.as_nquads() != expected {
+			eprintln!("test failed");
+			eprintln!("output=\n{}", dataset.as_nquads());
+			eprintln!("expected=\n{}", expected);
+		}
+
+		assert_eq!(dataset.as_nquads(), expected)
+	}
+}
+
+impl rdf_canon::MapTest {
+	fn run(self) {
+		if !is_supported(self.name, self.hash_algorithm) {
+			return;
+		}
+
+		for comment in self.comments {
+			println!("{}", comment)
+		}
+
+		let dataset = canonicalize(self.input).unwrap();
+		let (expected, _) = json_ld::syntax::Value::parse_str(&read(self.expect)).unwrap();
+		let expected = expected.as_object().unwrap();
+
+		assert_eq!(dataset.issued_identifiers().len(), expected.len());
+		for (id, canonical_id) in dataset.issued_identifiers() {
+			let expected_id = expected
+				.get_unique(&id.as_str()[2..])
+				.unwrap()
+				.and_then(json_ld::syntax::Value::as_str)
+				.unwrap();
+			assert_eq!(&canonical_id.as_str()[2..], expected_id)
+		}
+	}
+}
+
+impl rdf_canon::NegativeEvalTest {
+	fn run(self) {
+		for comment in self.comments {
+			println!("{}", comment)
+		}
+
+		match canonicalize(self.input) {
+			Ok(dataset) => {
+				eprintln!("output=\n{}", dataset.as_nquads());
+				panic!("canonicalization succeeded when it should have failed")
+			}
+			Err(CanonicalizationError::TooComplex) => (),
+		}
+	}
+}